@@ -0,0 +1,82 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    sync::{mpsc::Sender, Arc},
+};
+
+use crate::Asset;
+
+/// Identifies an asset of an [`crate::AssetServer`], ids are never reused by the same server.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct HandleId(pub(crate) u64);
+
+/// Shared by all clones of a [`Handle`], tells the server to free the asset once the last
+/// clone is dropped.
+pub(crate) struct HandleRef {
+    pub id: HandleId,
+    drop_sender: Sender<HandleId>,
+}
+
+impl HandleRef {
+    pub fn new(id: HandleId, drop_sender: Sender<HandleId>) -> Self {
+        Self { id, drop_sender }
+    }
+}
+
+impl Drop for HandleRef {
+    fn drop(&mut self) {
+        // Fails only if the server was dropped before the handle, freeing everything already
+        let _ = self.drop_sender.send(self.id);
+    }
+}
+
+/// A reference counted reference to an asset of type `T`, returned by
+/// [`crate::AssetServer::load`].
+///
+/// Cloning a handle is cheap. The asset stays loaded as long as at least one clone is alive
+/// and is freed by the next [`crate::AssetServer::update`] after the last one is dropped.
+pub struct Handle<T: Asset> {
+    inner: Arc<HandleRef>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Asset> Handle<T> {
+    pub(crate) fn new(inner: Arc<HandleRef>) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn id(&self) -> HandleId {
+        self.inner.id
+    }
+}
+
+impl<T: Asset> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.inner.clone())
+    }
+}
+
+impl<T: Asset> PartialEq for Handle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl<T: Asset> Eq for Handle<T> {}
+
+impl<T: Asset> Hash for Handle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
+}
+
+impl<T: Asset> fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Handle<{}>({})", std::any::type_name::<T>(), self.id().0)
+    }
+}
@@ -1,3 +1,23 @@
-pub struct Image {}
+use crate::{
+    server::{AssetError, LoadContext},
+    Asset,
+};
 
-impl Image {}
+/// A decoded image, e.g. a texture before it is uploaded to the GPU.
+pub struct Image {
+    pub image: ::image::DynamicImage,
+}
+
+impl Asset for Image {
+    fn load(context: &LoadContext) -> Result<Self, AssetError> {
+        let bytes = context.read()?;
+        let image = ::image::load_from_memory(&bytes)?;
+        Ok(Self { image })
+    }
+}
+
+impl From<::image::ImageError> for AssetError {
+    fn from(err: ::image::ImageError) -> Self {
+        Self::Decode(err.to_string())
+    }
+}
@@ -0,0 +1,438 @@
+//! A single file containing all assets of a shipped game.
+//!
+//! All numbers are little endian. The archive starts with a header:
+//!
+//! | Offset | Size | Field                                   |
+//! |--------|------|-----------------------------------------|
+//! | 0      | 8    | Magic, `VENTPACK`                       |
+//! | 8      | 4    | Format version                          |
+//! | 12     | 4    | Number of entries                       |
+//! | 16     | 8    | Offset of the table of contents         |
+//! | 24     | 8    | Size of the path strings after the TOC  |
+//!
+//! The file data follows, every file starts at a multiple of [`ENTRY_ALIGNMENT`] so it can be
+//! used straight from a memory map. The table of contents is sorted by path hash, every entry
+//! is [`TOC_ENTRY_SIZE`] bytes:
+//!
+//! | Offset | Size | Field                                          |
+//! |--------|------|------------------------------------------------|
+//! | 0      | 8    | FNV-1a hash of the path                        |
+//! | 8      | 8    | Offset of the data                             |
+//! | 16     | 8    | Size of the data                               |
+//! | 24     | 8    | Size of the file once decompressed, at most    |
+//! |        |      | [`MAX_COMPRESSION_RATIO`] times the data size  |
+//! | 32     | 4    | Offset of the path in the path strings         |
+//! | 36     | 2    | Length of the path                             |
+//! | 38     | 1    | [`Compression`] of the data                    |
+//! | 39     | 1    | Reserved                                       |
+//!
+//! The UTF-8 paths follow the table of contents, they use `/` as separator.
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{self, Write},
+    path::{Component, Path},
+};
+
+use memmap2::Mmap;
+
+use super::AssetIo;
+
+const MAGIC: &[u8; 8] = b"VENTPACK";
+const VERSION: u32 = 1;
+const HEADER_SIZE: usize = 32;
+pub const TOC_ENTRY_SIZE: usize = 40;
+/// The alignment of the data of every file.
+pub const ENTRY_ALIGNMENT: usize = 64;
+/// How many times larger than its data a file may be once decompressed. Limits what a broken
+/// archive can make the reader allocate, files which compress better are stored instead.
+pub const MAX_COMPRESSION_RATIO: usize = 1024;
+
+/// How the data of a file is stored in an archive.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Compression {
+    /// Stored as is.
+    #[default]
+    None,
+    /// zstd, the smallest archives.
+    Zstd,
+    /// LZ4 block format, the fastest to decompress.
+    Lz4,
+}
+
+impl Compression {
+    fn id(self) -> u8 {
+        match self {
+            Compression::None => 0,
+            Compression::Zstd => 1,
+            Compression::Lz4 => 2,
+        }
+    }
+
+    fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(Compression::None),
+            1 => Some(Compression::Zstd),
+            2 => Some(Compression::Lz4),
+            _ => None,
+        }
+    }
+
+    fn compress(self, data: &[u8]) -> io::Result<Vec<u8>> {
+        match self {
+            Compression::None => Ok(data.to_vec()),
+            Compression::Zstd => zstd::bulk::compress(data, zstd::DEFAULT_COMPRESSION_LEVEL),
+            Compression::Lz4 => Ok(lz4_flex::block::compress(data)),
+        }
+    }
+
+    fn decompress(self, data: &[u8], size: usize) -> io::Result<Vec<u8>> {
+        let decompressed = match self {
+            Compression::None => data.to_vec(),
+            Compression::Zstd => zstd::bulk::decompress(data, size)?,
+            Compression::Lz4 => lz4_flex::block::decompress(data, size)
+                .map_err(|err| invalid_data(&err.to_string()))?,
+        };
+        if decompressed.len() != size {
+            return Err(invalid_data("decompressed size does not match"));
+        }
+        Ok(decompressed)
+    }
+}
+
+/// Converts an asset path to the form stored in the archive, `None` if it leaves the root.
+fn archive_path(path: &Path) -> Option<String> {
+    let mut parts = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_str()?),
+            Component::CurDir => {}
+            _ => return None,
+        }
+    }
+    Some(parts.join("/"))
+}
+
+/// 64 bit FNV-1a, stable across platforms and versions unlike the std hasher.
+fn hash_path(path: &str) -> u64 {
+    path.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+fn align(offset: usize) -> usize {
+    offset.next_multiple_of(ENTRY_ALIGNMENT)
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Invalid archive: {}", message),
+    )
+}
+
+fn too_large(what: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("Archive too large: {}", what),
+    )
+}
+
+struct ArchiveEntry {
+    hash: u64,
+    path: String,
+    offset: usize,
+    size: usize,
+    uncompressed_size: usize,
+    compression: Compression,
+}
+
+/// Reads assets from an archive created with an [`ArchiveWriter`].
+pub struct ArchiveAssetIo {
+    data: Box<dyn AsRef<[u8]> + Send + Sync>,
+    /// Sorted by hash.
+    entries: Vec<ArchiveEntry>,
+}
+
+impl ArchiveAssetIo {
+    /// Maps the archive into memory.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: The archive must not be modified while the game is running, a truncated file
+        // would fault on access
+        let map = unsafe { Mmap::map(&file) }?;
+        Self::parse(Box::new(map))
+    }
+
+    pub fn from_bytes(bytes: Vec<u8>) -> io::Result<Self> {
+        Self::parse(Box::new(bytes))
+    }
+
+    fn parse(data: Box<dyn AsRef<[u8]> + Send + Sync>) -> io::Result<Self> {
+        let bytes = (*data).as_ref();
+        let read = |offset: usize, size: usize| {
+            offset
+                .checked_add(size)
+                .and_then(|end| bytes.get(offset..end))
+                .ok_or_else(|| invalid_data("unexpected end of file"))
+        };
+        let read_u64 =
+            |offset| Ok::<_, io::Error>(u64::from_le_bytes(read(offset, 8)?.try_into().unwrap()));
+
+        if read(0, 8)? != MAGIC {
+            return Err(invalid_data("not a vent archive"));
+        }
+        let version = u32::from_le_bytes(read(8, 4)?.try_into().unwrap());
+        if version != VERSION {
+            return Err(invalid_data(&format!("unsupported version {}", version)));
+        }
+        let count = u32::from_le_bytes(read(12, 4)?.try_into().unwrap()) as usize;
+        let toc_offset = read_u64(16)? as usize;
+        let strings_size = read_u64(24)? as usize;
+        let strings_offset = toc_offset
+            .checked_add(count * TOC_ENTRY_SIZE)
+            .ok_or_else(|| invalid_data("invalid table of contents offset"))?;
+        let strings = read(strings_offset, strings_size)?;
+
+        let mut entries = Vec::with_capacity(count);
+        for index in 0..count {
+            let entry = read(toc_offset + index * TOC_ENTRY_SIZE, TOC_ENTRY_SIZE)?;
+            let field =
+                |offset: usize| u64::from_le_bytes(entry[offset..offset + 8].try_into().unwrap());
+            let path_offset = u32::from_le_bytes(entry[32..36].try_into().unwrap()) as usize;
+            let path_len = u16::from_le_bytes(entry[36..38].try_into().unwrap()) as usize;
+            let compression = Compression::from_id(entry[38])
+                .ok_or_else(|| invalid_data("unknown compression"))?;
+            let path = strings
+                .get(path_offset..path_offset + path_len)
+                .and_then(|path| std::str::from_utf8(path).ok())
+                .ok_or_else(|| invalid_data("invalid path"))?;
+            let (offset, size, uncompressed_size) =
+                (field(8) as usize, field(16) as usize, field(24) as usize);
+            read(offset, size)?;
+            if compression == Compression::None && size != uncompressed_size {
+                return Err(invalid_data("stored file has a different size"));
+            }
+            if uncompressed_size > size.saturating_mul(MAX_COMPRESSION_RATIO) {
+                return Err(invalid_data("decompressed size is too large"));
+            }
+            entries.push(ArchiveEntry {
+                hash: field(0),
+                path: path.to_owned(),
+                offset,
+                size,
+                uncompressed_size,
+                compression,
+            });
+        }
+        if !entries.is_sorted_by_key(|entry| entry.hash) {
+            return Err(invalid_data("table of contents is not sorted"));
+        }
+        Ok(Self { data, entries })
+    }
+
+    fn find(&self, path: &Path) -> Option<&ArchiveEntry> {
+        let path = archive_path(path)?;
+        let hash = hash_path(&path);
+        let start = self.entries.partition_point(|entry| entry.hash < hash);
+        // Different paths can have the same hash
+        self.entries[start..]
+            .iter()
+            .take_while(|entry| entry.hash == hash)
+            .find(|entry| entry.path == path)
+    }
+
+    /// Returns the paths of all files in the archive.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|entry| entry.path.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl AssetIo for ArchiveAssetIo {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        let entry = self.find(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{} is not in the archive", path.display()),
+            )
+        })?;
+        let data = &(*self.data).as_ref()[entry.offset..entry.offset + entry.size];
+        entry.compression.decompress(data, entry.uncompressed_size)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.find(path).is_some()
+    }
+}
+
+struct ArchiveFile {
+    data: Vec<u8>,
+    uncompressed_size: usize,
+    compression: Compression,
+}
+
+/// Creates archives which are read by an [`ArchiveAssetIo`].
+#[derive(Default)]
+pub struct ArchiveWriter {
+    /// Keyed by the archive path, so adding a path again replaces the file.
+    files: BTreeMap<String, ArchiveFile>,
+    compression: Compression,
+}
+
+impl ArchiveWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Files added afterwards are compressed with `compression`.
+    pub fn set_compression(&mut self, compression: Compression) {
+        self.compression = compression;
+    }
+
+    /// Adds a file which is read with `path` later, replacing a file added with the same path.
+    ///
+    /// Fails if `path` is absolute, leaves the root or is not valid UTF-8.
+    pub fn add<P: AsRef<Path>>(&mut self, path: P, data: Vec<u8>) -> io::Result<()> {
+        self.add_with_compression(path, data, self.compression)
+    }
+
+    /// Like [`ArchiveWriter::add`], but with a different compression than the other files.
+    ///
+    /// Files which would not get smaller, like PNGs, are stored uncompressed instead. So are
+    /// files which would get smaller by more than [`MAX_COMPRESSION_RATIO`].
+    pub fn add_with_compression<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        data: Vec<u8>,
+        compression: Compression,
+    ) -> io::Result<()> {
+        let path = archive_path(path.as_ref())
+            .filter(|path| !path.is_empty() && path.len() <= u16::MAX as usize)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{} is not a valid asset path", path.as_ref().display()),
+                )
+            })?;
+        let uncompressed_size = data.len();
+        let (data, compression) = match compression.compress(&data)? {
+            compressed
+                if compressed.len() < uncompressed_size
+                    && uncompressed_size <= compressed.len() * MAX_COMPRESSION_RATIO =>
+            {
+                (compressed, compression)
+            }
+            _ => (data, Compression::None),
+        };
+        self.files.insert(
+            path,
+            ArchiveFile {
+                data,
+                uncompressed_size,
+                compression,
+            },
+        );
+        Ok(())
+    }
+
+    /// Adds all files in `dir` and its subdirectories, paths are relative to `dir`.
+    ///
+    /// Symbolic links are skipped, so only files below `dir` end up in the archive.
+    pub fn add_directory<P: AsRef<Path>>(&mut self, dir: P) -> io::Result<()> {
+        let dir = dir.as_ref();
+        let mut pending = vec![dir.to_path_buf()];
+        while let Some(current) = pending.pop() {
+            let mut entries = fs::read_dir(&current)?.collect::<io::Result<Vec<_>>>()?;
+            // Same input, same archive
+            entries.sort_by_key(|entry| entry.file_name());
+            for entry in entries {
+                let path = entry.path();
+                let file_type = entry.file_type()?;
+                if file_type.is_symlink() {
+                    log::warn!("Skipping symbolic link {}", path.display());
+                } else if file_type.is_dir() {
+                    pending.push(path);
+                } else {
+                    let data = fs::read(&path)?;
+                    self.add(path.strip_prefix(dir).unwrap(), data)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let mut files: Vec<(u64, &str, &ArchiveFile)> = self
+            .files
+            .iter()
+            .map(|(path, file)| (hash_path(path), path.as_str(), file))
+            .collect();
+        // The map is sorted by path and the sort is stable, so colliding hashes stay sorted by path
+        files.sort_by_key(|&(hash, _, _)| hash);
+        let file_count =
+            u32::try_from(files.len()).map_err(|_| too_large("more than u32::MAX files"))?;
+
+        // Lay out the data first, the header needs the offset of the table of contents
+        let mut offsets = Vec::with_capacity(files.len());
+        let mut path_offsets = Vec::with_capacity(files.len());
+        let mut end = align(HEADER_SIZE);
+        let mut strings_size = 0;
+        for (_, path, file) in &files {
+            offsets.push(end);
+            end = align(end + file.data.len());
+            path_offsets.push(
+                u32::try_from(strings_size)
+                    .map_err(|_| too_large("paths longer than u32::MAX bytes"))?,
+            );
+            strings_size += path.len();
+        }
+        let toc_offset = end;
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&file_count.to_le_bytes())?;
+        writer.write_all(&(toc_offset as u64).to_le_bytes())?;
+        writer.write_all(&(strings_size as u64).to_le_bytes())?;
+
+        let mut position = HEADER_SIZE;
+        for ((_, _, file), &offset) in files.iter().zip(&offsets) {
+            writer.write_all(&vec![0; offset - position])?;
+            writer.write_all(&file.data)?;
+            position = offset + file.data.len();
+        }
+        writer.write_all(&vec![0; toc_offset - position])?;
+
+        for (((hash, path, file), &offset), path_offset) in
+            files.iter().zip(&offsets).zip(&path_offsets)
+        {
+            writer.write_all(&hash.to_le_bytes())?;
+            writer.write_all(&(offset as u64).to_le_bytes())?;
+            writer.write_all(&(file.data.len() as u64).to_le_bytes())?;
+            writer.write_all(&(file.uncompressed_size as u64).to_le_bytes())?;
+            writer.write_all(&path_offset.to_le_bytes())?;
+            writer.write_all(&(path.len() as u16).to_le_bytes())?;
+            writer.write_all(&[file.compression.id(), 0])?;
+        }
+        for (_, path, _) in &files {
+            writer.write_all(path.as_bytes())?;
+        }
+        writer.flush()
+    }
+}
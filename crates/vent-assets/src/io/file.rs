@@ -1,8 +1,10 @@
 use std::{
-    env,
+    env, fs, io,
     path::{Path, PathBuf},
 };
 
+use super::{normalize_path, AssetIo};
+
 pub(crate) fn get_base_path() -> PathBuf {
     if let Ok(manifest_dir) = env::var("CARGO_MANIFEST_DIR") {
         PathBuf::from(manifest_dir)
@@ -13,9 +15,17 @@ pub(crate) fn get_base_path() -> PathBuf {
     }
 }
 
-/// I/O implementation for the local filesystem.
+/// Returns the asset root of [`FileAssetIo::default`], the `assets` directory next to the
+/// executable or in the crate directory during development.
+pub fn get_asset_root() -> PathBuf {
+    get_base_path().join("assets")
+}
+
+/// Resolves an asset path to a file below the crate directory during development or the
+/// directory of the executable otherwise.
 ///
-/// This asset I/O is fully featured but it's not available on `android` and `wasm` targets.
+/// Only for code which needs a path on disk, e.g. to create a pipeline from shader files, assets
+/// should be read through an [`AssetIo`] instead.
 #[allow(dead_code)]
 pub struct FileAsset {
     root_path: PathBuf,
@@ -31,3 +41,37 @@ impl FileAsset {
         &self.root_path
     }
 }
+
+/// Reads assets from the local filesystem, not available on `android` and `wasm` targets.
+///
+/// Paths leaving the root are rejected like in every other [`AssetIo`].
+pub struct FileAssetIo {
+    root: PathBuf,
+}
+
+impl FileAssetIo {
+    pub fn new<P: Into<PathBuf>>(root: P) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl Default for FileAssetIo {
+    /// Reads from [`get_asset_root`].
+    fn default() -> Self {
+        Self::new(get_asset_root())
+    }
+}
+
+impl AssetIo for FileAssetIo {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        fs::read(self.root.join(normalize_path(path)?))
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        normalize_path(path).is_ok_and(|path| self.root.join(path).is_file())
+    }
+
+    fn root(&self) -> Option<&Path> {
+        Some(&self.root)
+    }
+}
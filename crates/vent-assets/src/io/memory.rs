@@ -0,0 +1,52 @@
+use std::{
+    collections::HashMap,
+    io,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+use super::AssetIo;
+
+/// Keeps files in memory, mainly for tests.
+///
+/// Files can be changed while the backend is used by an [`crate::AssetServer`], keep an
+/// `Arc` to it and call [`crate::AssetServer::reload`] afterwards.
+#[derive(Default)]
+pub struct MemoryAssetIo {
+    files: RwLock<HashMap<PathBuf, Vec<u8>>>,
+}
+
+impl MemoryAssetIo {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds or replaces a file.
+    pub fn insert<P: Into<PathBuf>, D: Into<Vec<u8>>>(&self, path: P, data: D) {
+        self.files.write().unwrap().insert(path.into(), data.into());
+    }
+
+    pub fn remove<P: AsRef<Path>>(&self, path: P) -> Option<Vec<u8>> {
+        self.files.write().unwrap().remove(path.as_ref())
+    }
+}
+
+impl AssetIo for MemoryAssetIo {
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        self.files
+            .read()
+            .unwrap()
+            .get(path)
+            .cloned()
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("{} is not in memory", path.display()),
+                )
+            })
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.files.read().unwrap().contains_key(path)
+    }
+}
@@ -1,31 +1,82 @@
-use std::collections::HashMap;
+use std::{
+    io,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
 
-use crate::image::Image;
+use serde::{Deserialize, Serialize};
 
+use self::{archive::ArchiveAssetIo, file::FileAssetIo};
+
+pub mod archive;
 pub mod file;
+pub mod memory;
+
+/// Reads the files of assets, so the same asset path resolves from a directory during
+/// development, a packed archive in shipped builds and memory in tests.
+///
+/// Paths are relative to the asset root and use `/` as separator, e.g.
+/// `textures/skybox/top.jpg`. The root is the `assets` directory, so packing it with
+/// [`archive::ArchiveWriter::add_directory`] keeps every path valid.
+pub trait AssetIo: Send + Sync + 'static {
+    /// Reads the whole file.
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
+    fn exists(&self, path: &Path) -> bool;
+
+    /// Returns the directory containing the assets if they are plain files on disk.
+    ///
+    /// Required to watch for changes and by loaders which have to open files themselves.
+    fn root(&self) -> Option<&Path> {
+        None
+    }
+}
 
-pub struct AssetsLoader {
-    _image_cache: HashMap<String, Image>,
+/// Selects the [`AssetIo`] backend, e.g. from the project settings.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum AssetIoConfig {
+    /// Files in a directory, [`file::get_asset_root`] if `root` is `None`.
+    FileSystem { root: Option<PathBuf> },
+    /// A packed archive, a relative `path` is relative to the directory of the executable.
+    Archive { path: PathBuf },
 }
 
-impl Default for AssetsLoader {
+impl Default for AssetIoConfig {
     fn default() -> Self {
-        Self::new()
+        Self::FileSystem { root: None }
     }
 }
 
-impl AssetsLoader {
-    pub fn new() -> Self {
-        Self {
-            _image_cache: HashMap::new(),
-        }
+impl AssetIoConfig {
+    /// Creates the configured backend, fails if the archive can not be opened.
+    pub fn create(&self) -> io::Result<Arc<dyn AssetIo>> {
+        Ok(match self {
+            AssetIoConfig::FileSystem { root: None } => Arc::new(FileAssetIo::default()),
+            AssetIoConfig::FileSystem { root: Some(root) } => Arc::new(FileAssetIo::new(root)),
+            AssetIoConfig::Archive { path } => {
+                Arc::new(ArchiveAssetIo::open(file::get_base_path().join(path))?)
+            }
+        })
     }
+}
 
-    // pub fn get_image(&mut self, device: &ash::Device, path: String) -> vk::Sampler {
-    //     let vk = info.to_vk();
-    //     *self.sampler_cache.entry(info).or_insert({
-
-    //         unsafe { device.create_sampler(&vk, None) }.unwrap()
-    //     })
-    // }
+/// Normalizes a path relative to the asset root by dropping `.` components, so the same file
+/// is always referred to by the same path.
+///
+/// Absolute paths and `..` could leave the root and fail with [`io::ErrorKind::InvalidInput`].
+pub(crate) fn normalize_path(path: &Path) -> io::Result<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => normalized.push(part),
+            Component::CurDir => {}
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{} is outside of the asset root", path.display()),
+                ))
+            }
+        }
+    }
+    Ok(normalized)
 }
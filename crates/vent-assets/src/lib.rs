@@ -2,11 +2,24 @@ use ash::vk::{self};
 use modelz::AlphaMode;
 use vent_rendering::{image::VulkanImage, mesh::Mesh3D};
 
-mod image;
+mod handle;
+pub mod image;
 pub mod io;
 pub mod model;
-
-pub trait Asset: Send + Sync + 'static {}
+pub mod server;
+pub mod shader;
+mod watcher;
+
+pub use handle::{Handle, HandleId};
+pub use io::{AssetIo, AssetIoConfig};
+pub use server::{AssetError, AssetServer, LoadContext, LoadState};
+
+/// A type the [`AssetServer`] can load from a file, e.g. an [`image::Image`] or a
+/// [`shader::Shader`].
+pub trait Asset: Send + Sync + Sized + 'static {
+    /// Decodes the asset, usually from the bytes returned by [`LoadContext::read`].
+    fn load(context: &LoadContext) -> Result<Self, AssetError>;
+}
 
 /// A Full Model/Scene that can be Loaded from a 3D Model File
 /// This is done by Parsing all Essensial Informations like Vertices, Indices, Materials & More
@@ -1,12 +1,9 @@
-use std::{collections::HashMap, fs::File, path::Path};
+use std::collections::HashMap;
 
-use ash::{
-    util::read_spv,
-    vk::{self, PipelineShaderStageCreateInfo},
-};
+use ash::vk::{self, PipelineShaderStageCreateInfo};
 use vent_rendering::{
-    image::VulkanImage, instance::VulkanInstance, mesh::Mesh3D, vertex::Vertex3D,
-    MaterialPipelineInfo, DEFAULT_TEXTURE_FILTER,
+    image::VulkanImage, instance::VulkanInstance, mesh::Mesh3D, pipeline::PipelineError,
+    vertex::Vertex3D, MaterialPipelineInfo, DEFAULT_TEXTURE_FILTER,
 };
 
 use crate::{Material, Model3D, ModelPipeline};
@@ -14,36 +11,37 @@ use crate::{Material, Model3D, ModelPipeline};
 pub(crate) struct ModelLoader {}
 
 impl ModelLoader {
-    pub async fn load(
+    /// Creates the GPU resources of a parsed model.
+    ///
+    /// Fails if a shader is not valid SPIR-V or a pipeline can not be created, everything
+    /// created up to then is destroyed again.
+    pub fn load(
         instance: &mut VulkanInstance,
-        vertex_shader: &Path,
-        fragment_shader: &Path,
+        vertex_shader: &[u32],
+        fragment_shader: &[u32],
         pipeline_layout: vk::PipelineLayout,
         model: modelz::Model3D,
-    ) -> crate::Model3D {
-        // let mut matrix = None;
-
+    ) -> Result<crate::Model3D, PipelineError> {
         // Do not load for every node, So we load it here
-        let vertex_code =
-            read_spv(&mut File::open(vertex_shader).expect("Failed to open Vertex File")).unwrap();
-        let vertex_module_info = vk::ShaderModuleCreateInfo::default().code(&vertex_code);
-        let fragment_code =
-            read_spv(&mut File::open(fragment_shader).expect("Failed to open Fragment File"))
-                .unwrap();
-        let fragment_module_info = vk::ShaderModuleCreateInfo::default().code(&fragment_code);
+        let vertex_module_info = vk::ShaderModuleCreateInfo::default().code(vertex_shader);
+        let fragment_module_info = vk::ShaderModuleCreateInfo::default().code(fragment_shader);
 
         let vertex_module = unsafe {
             instance
                 .device
                 .create_shader_module(&vertex_module_info, None)
-        }
-        .unwrap();
-        let fragment_module = unsafe {
+        }?;
+        let fragment_module = match unsafe {
             instance
                 .device
                 .create_shader_module(&fragment_module_info, None)
-        }
-        .unwrap();
+        } {
+            Ok(module) => module,
+            Err(err) => {
+                unsafe { instance.device.destroy_shader_module(vertex_module, None) };
+                return Err(err.into());
+            }
+        };
 
         let shader_entry_name = unsafe { c"main" };
         let shader_stage_create_info = [
@@ -66,14 +64,27 @@ impl ModelLoader {
             materials.push(Self::load_material(instance, mat));
         }
 
-        let mut pipelines = Vec::new();
-        Self::load_meshes(
+        let descriptor_pool = Self::create_descriptor_pool(
+            materials.len() as u32,
+            instance.swapchain_images.len() as u32,
+            &instance.device,
+        );
+
+        let mut loaded = Model3D {
+            descriptor_pool,
+            materials,
+            pipelines: Vec::new(),
+            position: [0.0, 0.0, 0.0],
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            scale: [1.0, 1.0, 1.0],
+        };
+        let result = Self::load_meshes(
             instance,
             model.meshes,
             &shader_stage_create_info,
             pipeline_layout,
-            &materials,
-            &mut pipelines,
+            &loaded.materials,
+            &mut loaded.pipelines,
         );
 
         unsafe {
@@ -81,21 +92,12 @@ impl ModelLoader {
             instance.device.destroy_shader_module(fragment_module, None);
         }
 
-        // let matrix = matrix.unwrap_or_default();
-
-        let descriptor_pool = Self::create_descriptor_pool(
-            materials.len() as u32,
-            instance.swapchain_images.len() as u32,
-            &instance.device,
-        );
-
-        Model3D {
-            descriptor_pool,
-            materials,
-            pipelines,
-            position: [0.0, 0.0, 0.0], // TODO: matrix.0
-            rotation: [0.0, 0.0, 0.0, 1.0],
-            scale: [1.0, 1.0, 1.0],
+        match result {
+            Ok(()) => Ok(loaded),
+            Err(err) => {
+                loaded.destroy(&instance.device);
+                Err(err.into())
+            }
         }
     }
 
@@ -135,7 +137,7 @@ impl ModelLoader {
         pipeline_layout: vk::PipelineLayout,
         loaded_materials: &[Material],
         pipelines: &mut Vec<ModelPipeline>,
-    ) {
+    ) -> Result<(), vk::Result> {
         let surface_resolution = instance.surface_resolution;
 
         let binding = [Vertex3D::binding_description()];
@@ -162,32 +164,18 @@ impl ModelLoader {
         for mesh in meshes {
             log::debug!("      Loading Mesh {:?}", &mesh.name);
 
-            let mut all_meshes = vec![];
-
             let material_index = mesh.material_index.unwrap(); // TODO
             let material = &loaded_materials[material_index];
-            {
-                let loaded_mesh = Mesh3D::new(
-                    instance,
-                    &Self::convert_vertices(&mesh.vertices),
-                    Self::convert_indices(mesh.indices.unwrap()),
-                    mesh.name.as_deref(),
-                );
-                all_meshes.push(loaded_mesh);
-            }
             let pipeline_info = MaterialPipelineInfo {
                 mode: vk::PrimitiveTopology::TRIANGLE_LIST, // TODO
                 alpha_cut: Some(ordered_float::OrderedFloat(material.alpha_cut)),
                 double_sided: material.double_sided,
             };
 
-            let model_material = crate::ModelMaterial {
-                material_index,
-                meshes: all_meshes,
-            };
-
-            if let Some(pipeline_index) = cached_pipeline.get(&pipeline_info) {
-                pipelines[*pipeline_index].materials.push(model_material);
+            // The pipeline is created before the mesh, so nothing is left behind if it fails
+            let pipeline_index = if let Some(&pipeline_index) = cached_pipeline.get(&pipeline_info)
+            {
+                pipeline_index
             } else {
                 let vertex_input_assembly_state_info = vk::PipelineInputAssemblyStateCreateInfo {
                     topology: pipeline_info.mode,
@@ -205,60 +193,77 @@ impl ModelLoader {
                     ..Default::default()
                 };
 
-                {
-                    let multisample_state_info = vk::PipelineMultisampleStateCreateInfo {
-                        rasterization_samples: vk::SampleCountFlags::TYPE_1,
-                        ..Default::default()
-                    };
-
-                    let depth_state_info = vk::PipelineDepthStencilStateCreateInfo::default()
-                        .depth_test_enable(true)
-                        .depth_write_enable(true)
-                        .depth_compare_op(vk::CompareOp::LESS)
-                        .max_depth_bounds(1.0);
-                    let color_blend_attachment_states = [vk::PipelineColorBlendAttachmentState {
-                        color_write_mask: vk::ColorComponentFlags::RGBA,
-                        ..Default::default()
-                    }];
-                    let color_blend_state = vk::PipelineColorBlendStateCreateInfo::default()
-                        .logic_op(vk::LogicOp::COPY)
-                        .attachments(&color_blend_attachment_states);
-
-                    let dynamic_state = [vk::DynamicState::VIEWPORT, vk::DynamicState::SCISSOR]; // TODO
-                    let dynamic_state_info = vk::PipelineDynamicStateCreateInfo::default()
-                        .dynamic_states(&dynamic_state);
-
-                    let graphic_pipeline_info = vk::GraphicsPipelineCreateInfo::default()
-                        .stages(shader_stage_create_info)
-                        .vertex_input_state(&vertex_input_state_info)
-                        .input_assembly_state(&vertex_input_assembly_state_info)
-                        .viewport_state(&viewport_state_info)
-                        .rasterization_state(&rasterization_info)
-                        .multisample_state(&multisample_state_info)
-                        .depth_stencil_state(&depth_state_info)
-                        .color_blend_state(&color_blend_state)
-                        .dynamic_state(&dynamic_state_info)
-                        .layout(pipeline_layout)
-                        .render_pass(instance.render_pass);
-
-                    let graphics_pipelines = unsafe {
-                        instance.device.create_graphics_pipelines(
-                            vk::PipelineCache::null(),
-                            &[graphic_pipeline_info],
-                            None,
-                        )
-                    }
-                    .expect("Unable to create graphics pipeline");
-
-                    cached_pipeline.insert(pipeline_info, pipelines.len());
-
-                    pipelines.push(ModelPipeline {
-                        pipeline: graphics_pipelines[0],
-                        materials: vec![model_material], // TODO
-                    });
+                let multisample_state_info = vk::PipelineMultisampleStateCreateInfo {
+                    rasterization_samples: vk::SampleCountFlags::TYPE_1,
+                    ..Default::default()
+                };
+
+                let depth_state_info = vk::PipelineDepthStencilStateCreateInfo::default()
+                    .depth_test_enable(true)
+                    .depth_write_enable(true)
+                    .depth_compare_op(vk::CompareOp::LESS)
+                    .max_depth_bounds(1.0);
+                let color_blend_attachment_states = [vk::PipelineColorBlendAttachmentState {
+                    color_write_mask: vk::ColorComponentFlags::RGBA,
+                    ..Default::default()
+                }];
+                let color_blend_state = vk::PipelineColorBlendStateCreateInfo::default()
+                    .logic_op(vk::LogicOp::COPY)
+                    .attachments(&color_blend_attachment_states);
+
+                let dynamic_state = [vk::DynamicState::VIEWPORT, vk::DynamicState::SCISSOR]; // TODO
+                let dynamic_state_info =
+                    vk::PipelineDynamicStateCreateInfo::default().dynamic_states(&dynamic_state);
+
+                let graphic_pipeline_info = vk::GraphicsPipelineCreateInfo::default()
+                    .stages(shader_stage_create_info)
+                    .vertex_input_state(&vertex_input_state_info)
+                    .input_assembly_state(&vertex_input_assembly_state_info)
+                    .viewport_state(&viewport_state_info)
+                    .rasterization_state(&rasterization_info)
+                    .multisample_state(&multisample_state_info)
+                    .depth_stencil_state(&depth_state_info)
+                    .color_blend_state(&color_blend_state)
+                    .dynamic_state(&dynamic_state_info)
+                    .layout(pipeline_layout)
+                    .render_pass(instance.render_pass);
+
+                let graphics_pipelines = unsafe {
+                    instance.device.create_graphics_pipelines(
+                        vk::PipelineCache::null(),
+                        &[graphic_pipeline_info],
+                        None,
+                    )
                 }
+                .map_err(|(_, err)| err)?;
+
+                cached_pipeline.insert(pipeline_info, pipelines.len());
+                pipelines.push(ModelPipeline {
+                    pipeline: graphics_pipelines[0],
+                    materials: Vec::new(),
+                });
+                pipelines.len() - 1
+            };
+
+            let mut all_meshes = vec![];
+            {
+                let loaded_mesh = Mesh3D::new(
+                    instance,
+                    &Self::convert_vertices(&mesh.vertices),
+                    Self::convert_indices(mesh.indices.unwrap()),
+                    mesh.name.as_deref(),
+                );
+                all_meshes.push(loaded_mesh);
             }
+
+            pipelines[pipeline_index]
+                .materials
+                .push(crate::ModelMaterial {
+                    material_index,
+                    meshes: all_meshes,
+                });
         }
+        Ok(())
     }
 
     /**
@@ -1,42 +1,101 @@
-use std::path::Path;
+use std::{io, path::Path};
 
 use ash::vk;
 use loader::ModelLoader;
-use vent_rendering::instance::VulkanInstance;
+use vent_rendering::{instance::VulkanInstance, pipeline::PipelineError};
 use vent_sdk::utils::stopwatch::Stopwatch;
 
-use crate::Model3D;
+use crate::{
+    server::{AssetError, LoadContext},
+    Asset, Model3D,
+};
 
 mod loader;
 mod optimizer;
 
+/// A parsed 3D model with decoded textures, before anything is uploaded to the GPU.
+///
+/// Load it through the [`crate::AssetServer`] to parse on a worker thread, then create the
+/// [`Model3D`] with [`Model3D::upload`] on the render thread.
+pub struct ModelData {
+    pub model: modelz::Model3D,
+}
+
+impl Asset for ModelData {
+    fn load(context: &LoadContext) -> Result<Self, AssetError> {
+        // The glTF loader resolves buffers and textures relative to the file itself
+        let path = context.full_path().ok_or_else(|| {
+            AssetError::Io(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "Models can only be loaded from a directory",
+            ))
+        })?;
+        let model =
+            modelz::Model3D::load(&path).map_err(|err| AssetError::Decode(format!("{:?}", err)))?;
+        Ok(Self { model })
+    }
+}
+
 impl Model3D {
+    /// Parses and uploads the model at `path` on the calling thread, `vertex_shader` and
+    /// `fragment_shader` are SPIR-V, e.g. loaded as [`crate::shader::Shader`].
+    ///
+    /// This blocks until the whole file is decoded, prefer loading [`ModelData`] through the
+    /// [`crate::AssetServer`] at runtime.
     #[inline]
-    pub async fn load<P: AsRef<Path>>(
+    pub fn load<P: AsRef<Path>>(
         instance: &mut VulkanInstance,
-        vertex_shader: P,
-        fragment_shader: P,
+        vertex_shader: &[u32],
+        fragment_shader: &[u32],
         pipeline_layout: vk::PipelineLayout,
         path: P,
     ) -> Self {
         let sw = Stopwatch::new_and_start();
         let model = modelz::Model3D::load(path.as_ref()).expect("Failed to Load 3D Model");
-        let model = ModelLoader::load(
+        let model = Self::upload(
             instance,
-            vertex_shader.as_ref(),
-            fragment_shader.as_ref(),
+            vertex_shader,
+            fragment_shader,
             pipeline_layout,
-            model,
+            ModelData { model },
         )
-        .await;
+        .expect("Failed to upload 3D Model");
         log::info!(
-            "Model {} took {}ms to Load, {} Pipelines, {} Materials",
+            "Model {} took {}ms to Load",
             path.as_ref().display(),
             sw.elapsed_ms(),
+        );
+        model
+    }
+
+    /// Creates the GPU resources of an already parsed model, must be called on the render
+    /// thread.
+    ///
+    /// Fails without leaking GPU resources if a shader is invalid or a pipeline can not be
+    /// created, so a previously uploaded version can be kept, e.g. after a broken shader was
+    /// saved.
+    pub fn upload(
+        instance: &mut VulkanInstance,
+        vertex_shader: &[u32],
+        fragment_shader: &[u32],
+        pipeline_layout: vk::PipelineLayout,
+        data: ModelData,
+    ) -> Result<Self, PipelineError> {
+        let sw = Stopwatch::new_and_start();
+        let model = ModelLoader::load(
+            instance,
+            vertex_shader,
+            fragment_shader,
+            pipeline_layout,
+            data.model,
+        )?;
+        log::debug!(
+            "Model upload took {}ms, {} Pipelines, {} Materials",
+            sw.elapsed_ms(),
             model.pipelines.len(),
             model.materials.len(),
         );
-        model
+        Ok(model)
     }
 
     /// So your ideal render loop would be
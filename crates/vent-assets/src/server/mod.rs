@@ -0,0 +1,539 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt, io,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc, Weak,
+    },
+};
+
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+use crate::{
+    handle::{Handle, HandleId, HandleRef},
+    io::{file::FileAssetIo, normalize_path, AssetIo},
+    watcher::FileWatcher,
+    Asset,
+};
+
+/// Errors returned by [`Asset::load`].
+#[derive(Debug)]
+pub enum AssetError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// The file was read but does not contain a valid asset of the requested type.
+    Decode(String),
+}
+
+impl fmt::Display for AssetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetError::Io(err) => write!(f, "Failed to read asset: {}", err),
+            AssetError::Decode(err) => write!(f, "Failed to decode asset: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for AssetError {}
+
+impl From<std::io::Error> for AssetError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// The state of the asset behind a [`Handle`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LoadState {
+    /// The asset is being decoded on a worker thread, it is stored by the first
+    /// [`AssetServer::update`] after it finished.
+    Loading,
+    Loaded,
+    /// The file changed and a new version is being decoded, the previous version stays
+    /// available until it is replaced. If loading the new version fails the previous one is
+    /// kept.
+    Reloading,
+    /// Loading the asset failed, the error was logged.
+    Failed,
+}
+
+/// Passed to [`Asset::load`], gives access to the file being loaded.
+pub struct LoadContext {
+    path: PathBuf,
+    io: Arc<dyn AssetIo>,
+}
+
+impl LoadContext {
+    /// Returns the path the asset was requested with, relative to the asset root.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the path of the file on disk, for loaders which need to open it themselves,
+    /// e.g. to resolve the buffers of a glTF file relative to it.
+    ///
+    /// `None` if the assets are not plain files, see [`AssetIo::root`].
+    pub fn full_path(&self) -> Option<PathBuf> {
+        self.io.root().map(|root| root.join(&self.path))
+    }
+
+    /// Reads the whole file.
+    pub fn read(&self) -> Result<Vec<u8>, AssetError> {
+        Ok(self.io.read(&self.path)?)
+    }
+}
+
+type BoxedAsset = Box<dyn Any + Send + Sync>;
+
+type LoadFn = fn(&LoadContext) -> Result<BoxedAsset, AssetError>;
+
+/// The file an asset was loaded from, kept to load it again when the file changes.
+struct AssetSource {
+    path: PathBuf,
+    load: LoadFn,
+}
+
+struct AssetEntry {
+    /// `None` for assets created through [`AssetServer::add`].
+    source: Option<AssetSource>,
+    type_id: TypeId,
+    state: LoadState,
+    asset: Option<BoxedAsset>,
+    handle: Weak<HandleRef>,
+    /// Incremented for every load, so a slow load of an old version can not replace a newer
+    /// one.
+    generation: u64,
+}
+
+/// The result of a load, sent from a worker thread back to the server.
+struct Completion {
+    id: HandleId,
+    generation: u64,
+    result: Result<BoxedAsset, AssetError>,
+}
+
+fn load_boxed<T: Asset>(context: &LoadContext) -> Result<BoxedAsset, AssetError> {
+    // A panicking loader must not take down the worker or leave the handle loading forever
+    match panic::catch_unwind(AssertUnwindSafe(|| T::load(context))) {
+        Ok(result) => Ok(Box::new(result?)),
+        Err(_) => Err(AssetError::Decode(format!(
+            "Loader of {} panicked",
+            std::any::type_name::<T>()
+        ))),
+    }
+}
+
+/// Loads assets through an [`AssetIo`] and hands out [`Handle`]s to them.
+///
+/// Loading the same path with the same type twice returns the same asset as long as a handle
+/// to it is alive. Files are read and decoded on a pool of worker threads, call
+/// [`AssetServer::update`] once per frame to store finished assets and free assets whose
+/// handles were all dropped. Steps which need the render thread, like uploading to the GPU,
+/// are done by the owner of the server for every handle returned by
+/// [`AssetServer::drain_loaded`].
+///
+/// With [`AssetServer::watch_for_changes`] assets are loaded again when their file changes.
+///
+/// ```ignore
+/// let texture: Handle<Image> = server.load("textures/skybox/top.jpg");
+/// // Every frame
+/// server.update();
+/// for image in server.drain_loaded::<Image>() {
+///     // Upload the image
+/// }
+/// ```
+pub struct AssetServer {
+    io: Arc<dyn AssetIo>,
+    next_id: u64,
+    entries: HashMap<HandleId, AssetEntry>,
+    /// The handles of loaded paths, used to deduplicate loads while a handle is alive.
+    paths: HashMap<(TypeId, PathBuf), Weak<HandleRef>>,
+    workers: ThreadPool,
+    completion_sender: Sender<Completion>,
+    completion_receiver: Receiver<Completion>,
+    /// Assets which finished loading and were not drained yet.
+    loaded: Vec<(TypeId, HandleId)>,
+    watcher: Option<FileWatcher>,
+    /// Changed files which were not drained yet.
+    changed: Vec<PathBuf>,
+    drop_sender: Sender<HandleId>,
+    drop_receiver: Receiver<HandleId>,
+}
+
+impl Default for AssetServer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AssetServer {
+    /// Creates a server loading from the `assets` directory, see [`FileAssetIo::default`].
+    pub fn new() -> Self {
+        Self::with_io(Arc::new(FileAssetIo::default()))
+    }
+
+    /// Creates a server loading files below `root`.
+    pub fn with_root<P: Into<PathBuf>>(root: P) -> Self {
+        Self::with_io(Arc::new(FileAssetIo::new(root)))
+    }
+
+    /// Creates a server loading through `io`, see [`crate::io::AssetIoConfig`] to select it
+    /// from the settings.
+    pub fn with_io(io: Arc<dyn AssetIo>) -> Self {
+        let workers = ThreadPoolBuilder::new()
+            .thread_name(|index| format!("Asset Worker {}", index))
+            .build()
+            .expect("Failed to create asset worker threads");
+        let (completion_sender, completion_receiver) = mpsc::channel();
+        let (drop_sender, drop_receiver) = mpsc::channel();
+        Self {
+            io,
+            next_id: 0,
+            entries: HashMap::new(),
+            paths: HashMap::new(),
+            workers,
+            completion_sender,
+            completion_receiver,
+            loaded: Vec::new(),
+            watcher: None,
+            changed: Vec::new(),
+            drop_sender,
+            drop_receiver,
+        }
+    }
+
+    /// Returns the directory containing the assets if they are plain files on disk.
+    pub fn root(&self) -> Option<&Path> {
+        self.io.root()
+    }
+
+    pub fn io(&self) -> &Arc<dyn AssetIo> {
+        &self.io
+    }
+
+    /// Starts loading the asset at `path`, relative to the root, on a worker thread and
+    /// returns a handle to it.
+    ///
+    /// Returns the existing handle if the path is already loaded or loading as `T`. Paths
+    /// leaving the root, e.g. absolute ones, fail to load.
+    pub fn load<T: Asset, P: AsRef<Path>>(&mut self, path: P) -> Handle<T> {
+        let path = path.as_ref();
+        let normalized = normalize_path(path);
+        let key = (
+            TypeId::of::<T>(),
+            normalized
+                .as_ref()
+                .map_or_else(|_| path.to_path_buf(), Clone::clone),
+        );
+        if let Some(inner) = self.paths.get(&key).and_then(Weak::upgrade) {
+            return Handle::new(inner);
+        }
+
+        let inner = self.new_handle_ref();
+        let id = inner.id;
+        match normalized {
+            Ok(_) => self.spawn_load(id, 0, &key.1, load_boxed::<T>),
+            // Reported like any other failed load
+            Err(err) => {
+                let _ = self.completion_sender.send(Completion {
+                    id,
+                    generation: 0,
+                    result: Err(err.into()),
+                });
+            }
+        }
+        self.entries.insert(
+            id,
+            AssetEntry {
+                source: Some(AssetSource {
+                    path: key.1.clone(),
+                    load: load_boxed::<T>,
+                }),
+                type_id: key.0,
+                state: LoadState::Loading,
+                asset: None,
+                handle: Arc::downgrade(&inner),
+                generation: 0,
+            },
+        );
+        self.paths.insert(key, Arc::downgrade(&inner));
+        Handle::new(inner)
+    }
+
+    /// Loads every asset which was loaded from `path` again in the background.
+    ///
+    /// Loaded assets keep their current version in [`LoadState::Reloading`] until the new
+    /// one is ready, it is then returned from [`AssetServer::drain_loaded`] again like a new
+    /// asset.
+    pub fn reload<P: AsRef<Path>>(&mut self, path: P) {
+        // Nothing is loaded from outside of the root
+        let Ok(path) = normalize_path(path.as_ref()) else {
+            return;
+        };
+        let mut reloads = Vec::new();
+        for (&id, entry) in self.entries.iter_mut() {
+            let Some(source) = entry.source.as_ref().filter(|source| source.path == path) else {
+                continue;
+            };
+            entry.generation += 1;
+            entry.state = match entry.state {
+                LoadState::Loaded | LoadState::Reloading => LoadState::Reloading,
+                LoadState::Loading | LoadState::Failed => LoadState::Loading,
+            };
+            reloads.push((id, entry.generation, source.load));
+        }
+        for (id, generation, load) in reloads {
+            self.spawn_load(id, generation, &path, load);
+        }
+    }
+
+    fn spawn_load(&self, id: HandleId, generation: u64, path: &Path, load: LoadFn) {
+        let context = LoadContext {
+            path: path.to_path_buf(),
+            io: self.io.clone(),
+        };
+        let sender = self.completion_sender.clone();
+        self.workers.spawn(move || {
+            let result = load(&context);
+            // The server may have been dropped in the meantime
+            let _ = sender.send(Completion {
+                id,
+                generation,
+                result,
+            });
+        });
+    }
+
+    /// Starts watching the root for changed files. Assets loaded from a changed file are
+    /// loaded again by [`AssetServer::update`], see [`AssetServer::reload`].
+    ///
+    /// Only supported on Linux for now and if the assets are plain files on disk.
+    pub fn watch_for_changes(&mut self) -> Result<(), AssetError> {
+        if self.watcher.is_none() {
+            let root = self.io.root().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    "Only assets in a directory can be watched for changes",
+                )
+            })?;
+            self.watcher = Some(FileWatcher::new(root)?);
+        }
+        Ok(())
+    }
+
+    /// Returns the files below the root which changed since the last call, relative to the
+    /// root.
+    ///
+    /// This includes files which are not loaded through the server, e.g. shaders read
+    /// directly by a pipeline. Requires [`AssetServer::watch_for_changes`].
+    pub fn drain_changed(&mut self) -> Vec<PathBuf> {
+        std::mem::take(&mut self.changed)
+    }
+
+    /// Stores an asset which was not loaded from a file, e.g. a generated texture.
+    pub fn add<T: Asset>(&mut self, asset: T) -> Handle<T> {
+        let inner = self.new_handle_ref();
+        self.entries.insert(
+            inner.id,
+            AssetEntry {
+                source: None,
+                type_id: TypeId::of::<T>(),
+                state: LoadState::Loaded,
+                asset: Some(Box::new(asset)),
+                handle: Arc::downgrade(&inner),
+                generation: 0,
+            },
+        );
+        Handle::new(inner)
+    }
+
+    fn new_handle_ref(&mut self) -> Arc<HandleRef> {
+        let id = HandleId(self.next_id);
+        self.next_id += 1;
+        Arc::new(HandleRef::new(id, self.drop_sender.clone()))
+    }
+
+    /// Returns the asset if it is loaded, or the previous version while it is reloading.
+    pub fn get<T: Asset>(&self, handle: &Handle<T>) -> Option<&T> {
+        self.entries
+            .get(&handle.id())?
+            .asset
+            .as_ref()?
+            .downcast_ref()
+    }
+
+    pub fn get_mut<T: Asset>(&mut self, handle: &Handle<T>) -> Option<&mut T> {
+        self.entries
+            .get_mut(&handle.id())?
+            .asset
+            .as_mut()?
+            .downcast_mut()
+    }
+
+    /// Moves the asset out of the server, e.g. after it was uploaded to the GPU and the CPU
+    /// copy is no longer needed.
+    ///
+    /// The asset stays [`LoadState::Loaded`] and loading its path again returns the same
+    /// handle without reading the file, but [`AssetServer::get`] returns `None` until it is
+    /// reloaded.
+    pub fn take<T: Asset>(&mut self, handle: &Handle<T>) -> Option<T> {
+        let entry = self.entries.get_mut(&handle.id())?;
+        if entry.type_id != TypeId::of::<T>() {
+            return None;
+        }
+        entry.asset.take().map(|asset| *asset.downcast().unwrap())
+    }
+
+    /// Returns the load state of the asset, handles of another server are reported as
+    /// [`LoadState::Failed`].
+    pub fn load_state<T: Asset>(&self, handle: &Handle<T>) -> LoadState {
+        match self.entries.get(&handle.id()) {
+            Some(entry) if entry.type_id == TypeId::of::<T>() => entry.state,
+            _ => LoadState::Failed,
+        }
+    }
+
+    /// Returns the path the asset was loaded from, `None` for assets created with
+    /// [`AssetServer::add`].
+    pub fn path<T: Asset>(&self, handle: &Handle<T>) -> Option<&Path> {
+        self.entries
+            .get(&handle.id())?
+            .source
+            .as_ref()
+            .map(|source| source.path.as_path())
+    }
+
+    /// Returns the number of assets which are loaded, loading or failed and still have handles.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Frees assets without handles, reloads changed files and stores all assets which
+    /// finished loading.
+    pub fn update(&mut self) {
+        self.free_unused();
+        if let Some(watcher) = &mut self.watcher {
+            let mut changed = watcher.poll();
+            // Some programs write a file several times when saving
+            changed.sort();
+            changed.dedup();
+            for path in changed {
+                self.reload(&path);
+                if !self.changed.contains(&path) {
+                    self.changed.push(path);
+                }
+            }
+        }
+        while let Ok(completion) = self.completion_receiver.try_recv() {
+            self.complete(completion);
+        }
+    }
+
+    /// Blocks until the asset finished loading or reloading and returns its new state.
+    ///
+    /// Other assets finishing in the meantime are stored as well.
+    pub fn wait_for<T: Asset>(&mut self, handle: &Handle<T>) -> LoadState {
+        while matches!(
+            self.load_state(handle),
+            LoadState::Loading | LoadState::Reloading
+        ) {
+            // The server holds a sender itself, so this can not fail
+            let completion = self.completion_receiver.recv().unwrap();
+            self.complete(completion);
+        }
+        self.load_state(handle)
+    }
+
+    /// Returns the handles of all assets of type `T` which finished loading or reloading
+    /// successfully since the last call, oldest first.
+    ///
+    /// This is the completion queue for work which has to happen on the thread owning the
+    /// server after an asset was decoded, e.g. uploading it to the GPU.
+    pub fn drain_loaded<T: Asset>(&mut self) -> Vec<Handle<T>> {
+        let type_id = TypeId::of::<T>();
+        let entries = &self.entries;
+        let mut handles = Vec::new();
+        self.loaded.retain(|&(loaded_type, id)| {
+            if loaded_type != type_id {
+                return true;
+            }
+            // Assets whose handles were dropped are skipped
+            if let Some(inner) = entries.get(&id).and_then(|entry| entry.handle.upgrade()) {
+                handles.push(Handle::new(inner));
+            }
+            false
+        });
+        handles
+    }
+
+    fn complete(&mut self, completion: Completion) {
+        // The handle was dropped while the asset was loading
+        let Some(entry) = self.entries.get_mut(&completion.id) else {
+            return;
+        };
+        // The file changed again while it was loading, the newer load is still running
+        if completion.generation != entry.generation {
+            return;
+        }
+        let path = &entry.source.as_ref().unwrap().path;
+        match completion.result {
+            Ok(asset) => {
+                if entry.state == LoadState::Reloading {
+                    log::info!("Reloaded asset {}", path.display());
+                } else {
+                    log::debug!("Loaded asset {}", path.display());
+                }
+                entry.asset = Some(asset);
+                entry.state = LoadState::Loaded;
+                let loaded = (entry.type_id, completion.id);
+                if !self.loaded.contains(&loaded) {
+                    self.loaded.push(loaded);
+                }
+            }
+            Err(err) if entry.state == LoadState::Reloading => {
+                log::error!(
+                    "Failed to reload asset {}, keeping the previous version: {}",
+                    path.display(),
+                    err
+                );
+                entry.state = LoadState::Loaded;
+            }
+            Err(err) => {
+                log::error!("Failed to load asset {}: {}", path.display(), err);
+                entry.state = LoadState::Failed;
+            }
+        }
+    }
+
+    fn free_unused(&mut self) {
+        let mut freed = false;
+        while let Ok(id) = self.drop_receiver.try_recv() {
+            let Some(entry) = self.entries.remove(&id) else {
+                continue;
+            };
+            freed = true;
+            if let Some(source) = entry.source {
+                let key = (entry.type_id, source.path);
+                // The path may have been loaded again with a new handle in the meantime
+                if self
+                    .paths
+                    .get(&key)
+                    .is_some_and(|weak| weak.strong_count() == 0)
+                {
+                    self.paths.remove(&key);
+                }
+            }
+        }
+        if freed {
+            let entries = &self.entries;
+            self.loaded.retain(|(_, id)| entries.contains_key(id));
+        }
+    }
+}
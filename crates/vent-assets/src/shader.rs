@@ -0,0 +1,20 @@
+use std::io::Cursor;
+
+use crate::{
+    server::{AssetError, LoadContext},
+    Asset,
+};
+
+/// A compiled SPIR-V shader.
+pub struct Shader {
+    pub code: Vec<u32>,
+}
+
+impl Asset for Shader {
+    fn load(context: &LoadContext) -> Result<Self, AssetError> {
+        let bytes = context.read()?;
+        let code = ash::util::read_spv(&mut Cursor::new(bytes))
+            .map_err(|err| AssetError::Decode(err.to_string()))?;
+        Ok(Self { code })
+    }
+}
@@ -0,0 +1,103 @@
+use std::{
+    collections::HashMap,
+    ffi::OsString,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use inotify::{EventMask, Inotify, WatchDescriptor, WatchMask};
+
+/// Watches a directory and all of its subdirectories for changed files using inotify.
+pub(crate) struct FileWatcher {
+    inotify: Inotify,
+    root: PathBuf,
+    /// The watched directories, relative to the root.
+    directories: HashMap<WatchDescriptor, PathBuf>,
+    buffer: Vec<u8>,
+}
+
+impl FileWatcher {
+    pub fn new(root: &Path) -> io::Result<Self> {
+        let mut watcher = Self {
+            inotify: Inotify::init()?,
+            root: root.to_path_buf(),
+            directories: HashMap::new(),
+            buffer: vec![0; 4096],
+        };
+        watcher.watch_directory(PathBuf::new(), None)?;
+        Ok(watcher)
+    }
+
+    /// inotify is not recursive, so every subdirectory gets its own watch.
+    ///
+    /// Files already in the directories are added to `existing`, for directories which were
+    /// just created: files written before their watch was added are never reported.
+    fn watch_directory(
+        &mut self,
+        relative: PathBuf,
+        mut existing: Option<&mut Vec<PathBuf>>,
+    ) -> io::Result<()> {
+        let path = self.root.join(&relative);
+        // Files are reported once they were written and closed, not on every write
+        let mask = WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO | WatchMask::CREATE;
+        let descriptor = self.inotify.watches().add(&path, mask)?;
+        self.directories.insert(descriptor, relative.clone());
+
+        for entry in fs::read_dir(path)? {
+            let entry = entry?;
+            let entry_path = relative.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                self.watch_directory(entry_path, existing.as_deref_mut())?;
+            } else if let Some(existing) = existing.as_deref_mut() {
+                existing.push(entry_path);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the files which changed since the last call, relative to the root. Does not
+    /// block.
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        loop {
+            let events: Vec<(WatchDescriptor, EventMask, Option<OsString>)> =
+                match self.inotify.read_events(&mut self.buffer) {
+                    Ok(events) => events
+                        .map(|event| (event.wd, event.mask, event.name.map(ToOwned::to_owned)))
+                        .collect(),
+                    Err(err) if err.kind() == io::ErrorKind::WouldBlock => break,
+                    Err(err) => {
+                        log::error!("Failed to read file changes: {}", err);
+                        break;
+                    }
+                };
+
+            for (descriptor, mask, name) in events {
+                if mask.contains(EventMask::Q_OVERFLOW) {
+                    log::warn!("Too many file changes at once, some were dropped");
+                    continue;
+                }
+                if mask.contains(EventMask::IGNORED) {
+                    // The directory was deleted
+                    self.directories.remove(&descriptor);
+                    continue;
+                }
+                let (Some(directory), Some(name)) = (self.directories.get(&descriptor), name)
+                else {
+                    continue;
+                };
+                let path = directory.join(name);
+                if mask.contains(EventMask::ISDIR) {
+                    if mask.intersects(EventMask::CREATE | EventMask::MOVED_TO) {
+                        if let Err(err) = self.watch_directory(path.clone(), Some(&mut changed)) {
+                            log::error!("Failed to watch {}: {}", path.display(), err);
+                        }
+                    }
+                } else if mask.intersects(EventMask::CLOSE_WRITE | EventMask::MOVED_TO) {
+                    changed.push(path);
+                }
+            }
+        }
+        changed
+    }
+}
@@ -0,0 +1,9 @@
+#[cfg(target_os = "linux")]
+#[path = "inotify.rs"]
+mod platform;
+
+#[cfg(not(target_os = "linux"))]
+#[path = "unsupported.rs"]
+mod platform;
+
+pub(crate) use self::platform::FileWatcher;
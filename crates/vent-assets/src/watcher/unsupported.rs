@@ -0,0 +1,20 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+/// File watching is not implemented on this platform, creating a watcher always fails.
+pub(crate) struct FileWatcher {}
+
+impl FileWatcher {
+    pub fn new(_root: &Path) -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "Watching for file changes is only supported on Linux",
+        ))
+    }
+
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        Vec::new()
+    }
+}
@@ -0,0 +1,296 @@
+mod common;
+
+use std::{fs, io::ErrorKind, path::Path, sync::Arc};
+
+use common::TempDir;
+use vent_assets::{
+    io::{
+        archive::{ArchiveAssetIo, ArchiveWriter, Compression, ENTRY_ALIGNMENT},
+        file::FileAssetIo,
+        memory::MemoryAssetIo,
+    },
+    Asset, AssetError, AssetIo, AssetIoConfig, AssetServer, Handle, LoadContext, LoadState,
+};
+
+struct Text(String);
+
+impl Asset for Text {
+    fn load(context: &LoadContext) -> Result<Self, AssetError> {
+        String::from_utf8(context.read()?)
+            .map(Self)
+            .map_err(|err| AssetError::Decode(err.to_string()))
+    }
+}
+
+fn test_archive() -> ArchiveWriter {
+    let mut writer = ArchiveWriter::new();
+    writer.add("text/a.txt", b"a".to_vec()).unwrap();
+    writer
+        .add("shaders/3D/shader.vert.spv", vec![7; 1000])
+        .unwrap();
+    writer.add("./empty", Vec::new()).unwrap();
+    writer
+}
+
+#[test]
+fn file_io() {
+    let root = TempDir::new("file_io");
+    fs::create_dir_all(root.join("text")).unwrap();
+    fs::write(root.join("text/a.txt"), "a").unwrap();
+
+    let io = FileAssetIo::new(&root);
+    assert_eq!(io.read(Path::new("text/a.txt")).unwrap(), b"a");
+    assert!(io.exists(Path::new("text/a.txt")));
+    // Directories are not files
+    assert!(!io.exists(Path::new("text")));
+    assert_eq!(
+        io.read(Path::new("text/missing.txt")).unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+    assert_eq!(io.root(), Some(&*root));
+
+    // Paths may not leave the root
+    fs::write(root.join("outside.txt"), "outside").unwrap();
+    let io = FileAssetIo::new(root.join("text"));
+    assert_eq!(io.read(Path::new("./a.txt")).unwrap(), b"a");
+    for path in [Path::new("../outside.txt"), &root.join("outside.txt")] {
+        assert_eq!(io.read(path).unwrap_err().kind(), ErrorKind::InvalidInput);
+        assert!(!io.exists(path));
+    }
+}
+
+#[test]
+fn memory_io() {
+    let io = Arc::new(MemoryAssetIo::new());
+    io.insert("text/a.txt", "a");
+    assert!(io.exists(Path::new("text/a.txt")));
+    assert_eq!(io.root(), None);
+
+    let mut server = AssetServer::with_io(io.clone());
+    let handle: Handle<Text> = server.load("text/a.txt");
+    let missing: Handle<Text> = server.load("text/missing.txt");
+    assert_eq!(server.wait_for(&handle), LoadState::Loaded);
+    assert_eq!(server.wait_for(&missing), LoadState::Failed);
+    assert_eq!(server.get(&handle).unwrap().0, "a");
+
+    // Changing a file which is in use
+    io.insert("text/a.txt", "b");
+    server.reload("text/a.txt");
+    server.wait_for(&handle);
+    assert_eq!(server.get(&handle).unwrap().0, "b");
+
+    assert_eq!(io.remove("text/a.txt").unwrap(), b"b");
+    assert!(!io.exists(Path::new("text/a.txt")));
+    // Only directories can be watched
+    assert!(server.watch_for_changes().is_err());
+}
+
+#[test]
+fn archive_io() {
+    let mut bytes = Vec::new();
+    test_archive().write(&mut bytes).unwrap();
+    let io = ArchiveAssetIo::from_bytes(bytes.clone()).unwrap();
+
+    assert_eq!(io.len(), 3);
+    assert_eq!(io.read(Path::new("text/a.txt")).unwrap(), b"a");
+    assert_eq!(
+        io.read(Path::new("shaders/3D/shader.vert.spv")).unwrap(),
+        vec![7; 1000]
+    );
+    assert_eq!(io.read(Path::new("empty")).unwrap(), b"");
+    // Paths are normalized
+    assert!(io.exists(Path::new("./text/a.txt")));
+    assert!(!io.exists(Path::new("text")));
+    assert!(!io.exists(Path::new("../text/a.txt")));
+    assert_eq!(
+        io.read(Path::new("text/missing.txt")).unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+    let mut paths: Vec<&str> = io.paths().collect();
+    paths.sort();
+    assert_eq!(paths, ["empty", "shaders/3D/shader.vert.spv", "text/a.txt"]);
+
+    // The data of every file is aligned
+    let offset = bytes
+        .windows(1000)
+        .position(|window| window.iter().all(|&byte| byte == 7))
+        .unwrap();
+    assert_eq!(offset % ENTRY_ALIGNMENT, 0);
+
+    // Loading through the server works the same as from a directory
+    let mut server = AssetServer::with_io(Arc::new(io));
+    let handle: Handle<Text> = server.load("text/a.txt");
+    assert_eq!(server.wait_for(&handle), LoadState::Loaded);
+    assert_eq!(server.get(&handle).unwrap().0, "a");
+}
+
+#[test]
+fn archive_writer() {
+    let mut writer = test_archive();
+    assert!(writer.add("/absolute", Vec::new()).is_err());
+    assert!(writer.add("../outside", Vec::new()).is_err());
+    assert!(writer.add("", Vec::new()).is_err());
+    // Adding a path again replaces the file
+    writer.add("text/a.txt", b"b".to_vec()).unwrap();
+    assert_eq!(writer.len(), 3);
+
+    let mut bytes = Vec::new();
+    writer.write(&mut bytes).unwrap();
+    let io = ArchiveAssetIo::from_bytes(bytes).unwrap();
+    assert_eq!(io.read(Path::new("text/a.txt")).unwrap(), b"b");
+
+    let mut bytes = Vec::new();
+    ArchiveWriter::new().write(&mut bytes).unwrap();
+    assert!(ArchiveAssetIo::from_bytes(bytes).unwrap().is_empty());
+}
+
+#[test]
+fn archive_compression() {
+    let compressible = b"vent ".repeat(1000);
+    // Does not get smaller and is stored instead
+    let random: Vec<u8> = (0..1000u32)
+        .map(|i| (i.wrapping_mul(2654435761) >> 13) as u8)
+        .collect();
+
+    for compression in [Compression::None, Compression::Zstd, Compression::Lz4] {
+        let mut writer = ArchiveWriter::new();
+        writer.set_compression(compression);
+        writer.add("text/vent.txt", compressible.clone()).unwrap();
+        writer.add("random", random.clone()).unwrap();
+        writer.add("empty", Vec::new()).unwrap();
+        writer
+            .add_with_compression("stored", compressible.clone(), Compression::None)
+            .unwrap();
+
+        let mut bytes = Vec::new();
+        writer.write(&mut bytes).unwrap();
+        if compression != Compression::None {
+            assert!(bytes.len() < 2 * compressible.len());
+        }
+        let io = ArchiveAssetIo::from_bytes(bytes).unwrap();
+        assert_eq!(io.read(Path::new("text/vent.txt")).unwrap(), compressible);
+        assert_eq!(io.read(Path::new("random")).unwrap(), random);
+        assert_eq!(io.read(Path::new("empty")).unwrap(), b"");
+        assert_eq!(io.read(Path::new("stored")).unwrap(), compressible);
+    }
+
+    // Compresses better than the reader allows and is stored instead
+    let zeros = vec![0; 1 << 20];
+    let mut writer = ArchiveWriter::new();
+    writer.set_compression(Compression::Zstd);
+    writer.add("zeros", zeros.clone()).unwrap();
+    let mut bytes = Vec::new();
+    writer.write(&mut bytes).unwrap();
+    assert!(bytes.len() > zeros.len());
+    let io = ArchiveAssetIo::from_bytes(bytes).unwrap();
+    assert_eq!(io.read(Path::new("zeros")).unwrap(), zeros);
+}
+
+#[test]
+fn archive_directory() {
+    let dir = TempDir::new("archive_directory");
+    let root = dir.join("assets");
+    fs::create_dir_all(root.join("shaders/3D")).unwrap();
+    fs::write(root.join("shaders/3D/shader.vert.spv"), [1, 2, 3]).unwrap();
+    fs::write(root.join("a.txt"), "a").unwrap();
+    // Links are skipped, they could point outside of the directory
+    #[cfg(unix)]
+    {
+        fs::write(dir.join("outside.txt"), "outside").unwrap();
+        std::os::unix::fs::symlink(dir.join("outside.txt"), root.join("link.txt")).unwrap();
+        std::os::unix::fs::symlink(&dir, root.join("linked_dir")).unwrap();
+    }
+
+    let mut writer = ArchiveWriter::new();
+    writer.set_compression(Compression::Zstd);
+    writer.add_directory(&root).unwrap();
+    assert!(writer.add_directory(root.join("missing")).is_err());
+    let archive = dir.join("assets.pack");
+    writer.write(fs::File::create(&archive).unwrap()).unwrap();
+
+    let io = ArchiveAssetIo::open(&archive).unwrap();
+    let mut paths: Vec<&str> = io.paths().collect();
+    paths.sort();
+    assert_eq!(paths, ["a.txt", "shaders/3D/shader.vert.spv"]);
+    assert_eq!(
+        io.read(Path::new("shaders/3D/shader.vert.spv")).unwrap(),
+        [1, 2, 3]
+    );
+
+    // Packing the same directory again gives the same archive
+    let mut writer = ArchiveWriter::new();
+    writer.set_compression(Compression::Zstd);
+    writer.add_directory(&root).unwrap();
+    let mut bytes = Vec::new();
+    writer.write(&mut bytes).unwrap();
+    assert_eq!(bytes, fs::read(&archive).unwrap());
+}
+
+#[test]
+fn invalid_archives() {
+    let mut bytes = Vec::new();
+    test_archive().write(&mut bytes).unwrap();
+    let toc_offset = u64::from_le_bytes(bytes[16..24].try_into().unwrap()) as usize;
+    let mut unknown_compression = bytes.clone();
+    unknown_compression[toc_offset + 38] = 99;
+
+    // Claims to decompress to far more than its data
+    let mut writer = ArchiveWriter::new();
+    writer.set_compression(Compression::Lz4);
+    writer.add("vent.txt", b"vent ".repeat(1000)).unwrap();
+    let mut too_large = Vec::new();
+    writer.write(&mut too_large).unwrap();
+    let toc_offset = u64::from_le_bytes(too_large[16..24].try_into().unwrap()) as usize;
+    too_large[toc_offset + 24..toc_offset + 32].copy_from_slice(&u64::MAX.to_le_bytes());
+
+    for invalid in [
+        unknown_compression,
+        too_large,
+        Vec::new(),
+        b"NOTAPACK".to_vec(),
+        bytes[..bytes.len() - 1].to_vec(),
+        [&bytes[..8], &99u32.to_le_bytes(), &bytes[12..]].concat(),
+    ] {
+        let err = ArchiveAssetIo::from_bytes(invalid).err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
+
+#[test]
+fn config() {
+    let dir = TempDir::new("config");
+    let archive = dir.join("assets.pack");
+    test_archive()
+        .write(fs::File::create(&archive).unwrap())
+        .unwrap();
+
+    let io = AssetIoConfig::Archive {
+        path: archive.clone(),
+    }
+    .create()
+    .unwrap();
+    assert_eq!(io.read(Path::new("text/a.txt")).unwrap(), b"a");
+    assert!(AssetIoConfig::Archive {
+        path: dir.join("missing.pack")
+    }
+    .create()
+    .is_err());
+
+    let io = AssetIoConfig::FileSystem {
+        root: Some(dir.to_path_buf()),
+    }
+    .create()
+    .unwrap();
+    assert!(io.exists(Path::new("assets.pack")));
+    assert!(matches!(
+        AssetIoConfig::default(),
+        AssetIoConfig::FileSystem { root: None }
+    ));
+
+    let config = AssetIoConfig::Archive { path: archive };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(
+        serde_json::from_str::<AssetIoConfig>(&json).unwrap(),
+        config
+    );
+}
@@ -0,0 +1,158 @@
+mod common;
+
+use std::{
+    fs,
+    path::Path,
+    slice, thread,
+    time::{Duration, Instant},
+};
+
+use common::TempDir;
+use vent_assets::{Asset, AssetError, AssetServer, Handle, LoadContext, LoadState};
+
+struct Text(String);
+
+impl Asset for Text {
+    fn load(context: &LoadContext) -> Result<Self, AssetError> {
+        String::from_utf8(context.read()?)
+            .map(Self)
+            .map_err(|err| AssetError::Decode(err.to_string()))
+    }
+}
+
+/// Creates an empty asset root for a single test.
+fn asset_root(test: &str) -> TempDir {
+    let root = TempDir::new(test);
+    fs::create_dir_all(root.join("text")).unwrap();
+    root
+}
+
+/// Calls [`AssetServer::update`] until `done` returns true, file events arrive asynchronously.
+fn update_until(server: &mut AssetServer, mut done: impl FnMut(&mut AssetServer) -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        server.update();
+        if done(server) {
+            return;
+        }
+        assert!(
+            Instant::now() < deadline,
+            "Timed out waiting for the asset server"
+        );
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn reload_replaces_asset() {
+    let root = asset_root("reload_replaces_asset");
+    fs::write(root.join("text/a.txt"), "a").unwrap();
+    let mut server = AssetServer::with_root(&root);
+
+    // The watcher reports normalized paths, which must match the path it was loaded with
+    let handle: Handle<Text> = server.load("./text/a.txt");
+    server.wait_for(&handle);
+    assert_eq!(server.drain_loaded::<Text>(), slice::from_ref(&handle));
+
+    fs::write(root.join("text/a.txt"), "b").unwrap();
+    server.reload("text/a.txt");
+    // The previous version stays available while the new one loads
+    assert_eq!(server.load_state(&handle), LoadState::Reloading);
+    assert_eq!(server.get(&handle).unwrap().0, "a");
+
+    assert_eq!(server.wait_for(&handle), LoadState::Loaded);
+    assert_eq!(server.get(&handle).unwrap().0, "b");
+    // Reloaded assets are queued again, so the GPU copy can be replaced
+    assert_eq!(server.drain_loaded::<Text>(), slice::from_ref(&handle));
+
+    // Unknown paths are ignored
+    server.reload("text/missing.txt");
+    assert_eq!(server.load_state(&handle), LoadState::Loaded);
+}
+
+#[test]
+fn failed_reload_keeps_previous_version() {
+    let root = asset_root("failed_reload_keeps_previous_version");
+    fs::write(root.join("text/a.txt"), "a").unwrap();
+    let mut server = AssetServer::with_root(&root);
+
+    let handle: Handle<Text> = server.load("text/a.txt");
+    server.wait_for(&handle);
+    server.drain_loaded::<Text>();
+
+    fs::write(root.join("text/a.txt"), [0xff, 0xfe]).unwrap();
+    server.reload("text/a.txt");
+    assert_eq!(server.wait_for(&handle), LoadState::Loaded);
+    assert_eq!(server.get(&handle).unwrap().0, "a");
+    assert!(server.drain_loaded::<Text>().is_empty());
+
+    // A failed asset is loaded from scratch once the file is fixed
+    let broken: Handle<Text> = server.load("text/a.txt");
+    assert_eq!(broken, handle);
+    drop((handle, broken));
+    server.update();
+    let broken: Handle<Text> = server.load("text/a.txt");
+    assert_eq!(server.wait_for(&broken), LoadState::Failed);
+    fs::write(root.join("text/a.txt"), "fixed").unwrap();
+    server.reload("text/a.txt");
+    assert_eq!(server.load_state(&broken), LoadState::Loading);
+    assert_eq!(server.wait_for(&broken), LoadState::Loaded);
+    assert_eq!(server.get(&broken).unwrap().0, "fixed");
+}
+
+#[test]
+fn reload_while_loading() {
+    let root = asset_root("reload_while_loading");
+    fs::write(root.join("text/a.txt"), "a").unwrap();
+    let mut server = AssetServer::with_root(&root);
+
+    let handle: Handle<Text> = server.load("text/a.txt");
+    fs::write(root.join("text/a.txt"), "b").unwrap();
+    server.reload("text/a.txt");
+    assert_eq!(server.wait_for(&handle), LoadState::Loaded);
+    // Only the newest load is stored, even if the first one finishes later
+    assert_eq!(server.get(&handle).unwrap().0, "b");
+    thread::sleep(Duration::from_millis(50));
+    server.update();
+    assert_eq!(server.get(&handle).unwrap().0, "b");
+    assert_eq!(server.drain_loaded::<Text>(), [handle]);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn watches_for_changes() {
+    let root = asset_root("watches_for_changes");
+    fs::write(root.join("text/a.txt"), "a").unwrap();
+    let mut server = AssetServer::with_root(&root);
+    server.watch_for_changes().unwrap();
+
+    let handle: Handle<Text> = server.load("text/a.txt");
+    server.wait_for(&handle);
+
+    fs::write(root.join("text/a.txt"), "b").unwrap();
+    update_until(&mut server, |server| {
+        server.get(&handle).is_some_and(|text| text.0 == "b")
+    });
+    assert_eq!(server.drain_changed(), [Path::new("text/a.txt")]);
+    assert!(server.drain_changed().is_empty());
+
+    // Directories created after the watch started are watched as well, including the files
+    // written before the watcher saw them being created
+    fs::create_dir_all(root.join("shaders/3D")).unwrap();
+    fs::write(root.join("shaders/3D/shader.frag.spv"), [0; 4]).unwrap();
+    let mut changed = Vec::new();
+    update_until(&mut server, |server| {
+        changed.extend(server.drain_changed());
+        !changed.is_empty()
+    });
+    assert_eq!(changed, [Path::new("shaders/3D/shader.frag.spv")]);
+
+    // Files written after the directory is watched are reported by their own events
+    fs::write(root.join("shaders/3D/shader.vert.spv"), [0; 4]).unwrap();
+    let mut changed = Vec::new();
+    update_until(&mut server, |server| {
+        changed.extend(server.drain_changed());
+        !changed.is_empty()
+    });
+    assert_eq!(changed, [Path::new("shaders/3D/shader.vert.spv")]);
+}
@@ -0,0 +1,260 @@
+mod common;
+
+use std::{fs, path::PathBuf, thread, thread::ThreadId};
+
+use common::TempDir;
+use vent_assets::{Asset, AssetError, AssetServer, Handle, LoadContext, LoadState};
+
+struct Text(String);
+
+impl Asset for Text {
+    fn load(context: &LoadContext) -> Result<Self, AssetError> {
+        String::from_utf8(context.read()?)
+            .map(Self)
+            .map_err(|err| AssetError::Decode(err.to_string()))
+    }
+}
+
+struct Length(usize);
+
+/// Records the thread it was loaded on.
+struct LoadThread(ThreadId);
+
+impl Asset for LoadThread {
+    fn load(_context: &LoadContext) -> Result<Self, AssetError> {
+        Ok(Self(thread::current().id()))
+    }
+}
+
+struct Panics;
+
+impl Asset for Panics {
+    fn load(_context: &LoadContext) -> Result<Self, AssetError> {
+        panic!("Failed to decode")
+    }
+}
+
+impl Asset for Length {
+    fn load(context: &LoadContext) -> Result<Self, AssetError> {
+        Ok(Self(context.read()?.len()))
+    }
+}
+
+/// Creates an empty asset root for a single test.
+fn asset_root(test: &str) -> TempDir {
+    let root = TempDir::new(test);
+    fs::create_dir_all(root.join("text")).unwrap();
+    root
+}
+
+#[test]
+fn load_states() {
+    let root = asset_root("load_states");
+    fs::write(root.join("text/hello.txt"), "Hello").unwrap();
+    fs::write(root.join("text/binary.txt"), [0xff, 0xfe]).unwrap();
+    let mut server = AssetServer::with_root(&root);
+
+    let hello: Handle<Text> = server.load("text/hello.txt");
+    assert_eq!(server.load_state(&hello), LoadState::Loading);
+    assert!(server.get(&hello).is_none());
+
+    let missing: Handle<Text> = server.load("text/missing.txt");
+    let binary: Handle<Text> = server.load("text/binary.txt");
+    assert_eq!(server.wait_for(&hello), LoadState::Loaded);
+    assert_eq!(server.wait_for(&missing), LoadState::Failed);
+    assert_eq!(server.wait_for(&binary), LoadState::Failed);
+
+    assert_eq!(server.load_state(&hello), LoadState::Loaded);
+    assert_eq!(server.get(&hello).unwrap().0, "Hello");
+    assert_eq!(
+        server.path(&hello),
+        Some(PathBuf::from("text/hello.txt").as_path())
+    );
+    assert_eq!(server.load_state(&missing), LoadState::Failed);
+    assert_eq!(server.load_state(&binary), LoadState::Failed);
+    assert!(server.get(&missing).is_none());
+
+    server.get_mut(&hello).unwrap().0.push_str(", World");
+    assert_eq!(server.get(&hello).unwrap().0, "Hello, World");
+}
+
+#[test]
+fn deduplicates_paths() {
+    let root = asset_root("deduplicates_paths");
+    fs::write(root.join("text/a.txt"), "a").unwrap();
+    let mut server = AssetServer::with_root(&root);
+
+    let first: Handle<Text> = server.load("text/a.txt");
+    let second: Handle<Text> = server.load("text/a.txt");
+    assert_eq!(first, second);
+    // Paths are normalized before they are compared
+    assert_eq!(server.load::<Text, _>("./text/a.txt"), first);
+    assert_eq!(server.load::<Text, _>("text/./a.txt"), first);
+    // The same file loaded as another asset type is a separate asset
+    let length: Handle<Length> = server.load("text/a.txt");
+    assert_eq!(server.len(), 2);
+
+    server.wait_for(&first);
+    server.wait_for(&length);
+    assert_eq!(server.get(&second).unwrap().0, "a");
+    assert_eq!(server.get(&length).unwrap().0, 1);
+    // Loaded assets are not read again
+    fs::write(root.join("text/a.txt"), "b").unwrap();
+    let third: Handle<Text> = server.load("text/a.txt");
+    assert_eq!(third, first);
+    assert_eq!(server.get(&third).unwrap().0, "a");
+}
+
+#[test]
+fn paths_outside_of_root_fail() {
+    let root = asset_root("paths_outside_of_root_fail");
+    fs::write(root.join("outside.txt"), "outside").unwrap();
+    let mut server = AssetServer::with_root(root.join("text"));
+
+    let parent: Handle<Text> = server.load("../outside.txt");
+    let absolute: Handle<Text> = server.load(root.join("outside.txt"));
+    assert_eq!(server.wait_for(&parent), LoadState::Failed);
+    assert_eq!(server.wait_for(&absolute), LoadState::Failed);
+}
+
+#[test]
+fn frees_unused_assets() {
+    let root = asset_root("frees_unused_assets");
+    fs::write(root.join("text/a.txt"), "a").unwrap();
+    let mut server = AssetServer::with_root(&root);
+
+    let handle: Handle<Text> = server.load("text/a.txt");
+    let clone = handle.clone();
+    server.wait_for(&handle);
+    let id = handle.id();
+
+    drop(handle);
+    server.update();
+    assert_eq!(server.get(&clone).unwrap().0, "a");
+
+    drop(clone);
+    assert_eq!(server.len(), 1);
+    server.update();
+    assert!(server.is_empty());
+
+    // Loading the path again reads the file again
+    fs::write(root.join("text/a.txt"), "b").unwrap();
+    let handle: Handle<Text> = server.load("text/a.txt");
+    assert_ne!(handle.id(), id);
+    server.wait_for(&handle);
+    assert_eq!(server.get(&handle).unwrap().0, "b");
+}
+
+#[test]
+fn reload_before_free() {
+    let root = asset_root("reload_before_free");
+    fs::write(root.join("text/a.txt"), "a").unwrap();
+    let mut server = AssetServer::with_root(&root);
+
+    let handle: Handle<Text> = server.load("text/a.txt");
+    server.wait_for(&handle);
+    drop(handle);
+    // The old asset is still stored until the next update, but has no handle to share
+    let handle: Handle<Text> = server.load("text/a.txt");
+    server.update();
+    assert_eq!(server.len(), 1);
+    assert_eq!(server.wait_for(&handle), LoadState::Loaded);
+    assert_eq!(server.get(&handle).unwrap().0, "a");
+
+    // Deduplication still works for the new handle
+    let again: Handle<Text> = server.load("text/a.txt");
+    assert_eq!(again, handle);
+}
+
+#[test]
+fn dropped_while_loading() {
+    let root = asset_root("dropped_while_loading");
+    fs::write(root.join("text/a.txt"), "a").unwrap();
+    let mut server = AssetServer::with_root(&root);
+
+    drop(server.load::<Text, _>("text/a.txt"));
+    server.update();
+    assert!(server.is_empty());
+}
+
+#[test]
+fn added_assets() {
+    let root = asset_root("added_assets");
+    let mut server = AssetServer::with_root(&root);
+    let handle = server.add(Text("generated".to_owned()));
+    assert_eq!(server.load_state(&handle), LoadState::Loaded);
+    assert_eq!(server.path(&handle), None);
+    assert_eq!(server.get(&handle).unwrap().0, "generated");
+
+    drop(handle);
+    server.update();
+    assert!(server.is_empty());
+}
+
+#[test]
+fn loads_on_worker_threads() {
+    let root = asset_root("loads_on_worker_threads");
+    let mut server = AssetServer::with_root(&root);
+    let handle: Handle<LoadThread> = server.load("anything");
+    assert_eq!(server.wait_for(&handle), LoadState::Loaded);
+    assert_ne!(server.get(&handle).unwrap().0, thread::current().id());
+}
+
+#[test]
+fn drain_loaded() {
+    let root = asset_root("drain_loaded");
+    fs::write(root.join("text/a.txt"), "a").unwrap();
+    fs::write(root.join("text/b.txt"), "bb").unwrap();
+    let mut server = AssetServer::with_root(&root);
+
+    let a: Handle<Text> = server.load("text/a.txt");
+    let b: Handle<Text> = server.load("text/b.txt");
+    let length: Handle<Length> = server.load("text/b.txt");
+    let missing: Handle<Text> = server.load("text/missing.txt");
+    let dropped: Handle<Text> = server.load("text/a.txt");
+    let dropped_length: Handle<Length> = server.load("text/a.txt");
+    for state in [
+        server.wait_for(&a),
+        server.wait_for(&b),
+        server.wait_for(&length),
+        server.wait_for(&dropped_length),
+    ] {
+        assert_eq!(state, LoadState::Loaded);
+    }
+    server.wait_for(&missing);
+    drop(dropped);
+    drop(dropped_length);
+    server.update();
+
+    // Only successfully loaded assets of the requested type, each once
+    let mut loaded = server.drain_loaded::<Text>();
+    loaded.sort_by_key(|handle| server.get(handle).unwrap().0.clone());
+    assert_eq!(loaded, [a.clone(), b]);
+    assert!(server.drain_loaded::<Text>().is_empty());
+    assert_eq!(server.drain_loaded::<Length>(), [length]);
+
+    // Taking an asset keeps the handle and its state
+    assert_eq!(server.take(&a).unwrap().0, "a");
+    assert!(server.take(&a).is_none());
+    assert!(server.get(&a).is_none());
+    assert_eq!(server.load_state(&a), LoadState::Loaded);
+    assert_eq!(server.load::<Text, _>("text/a.txt"), a);
+
+    // Added assets never go through the queue
+    server.add(Text("generated".to_owned()));
+    server.update();
+    assert!(server.drain_loaded::<Text>().is_empty());
+}
+
+#[test]
+fn panicking_loader() {
+    let root = asset_root("panicking_loader");
+    let mut server = AssetServer::with_root(&root);
+    let handle: Handle<Panics> = server.load("anything");
+    assert_eq!(server.wait_for(&handle), LoadState::Failed);
+    assert!(server.drain_loaded::<Panics>().is_empty());
+
+    // The workers keep running
+    let handle: Handle<LoadThread> = server.load("anything");
+    assert_eq!(server.wait_for(&handle), LoadState::Loaded);
+}
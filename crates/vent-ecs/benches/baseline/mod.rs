@@ -0,0 +1,171 @@
+//! The `World` of vent-ecs before archetypes were stored in columns, copied from the
+//! baseline commit so the benchmarks compare against the real previous design. Every
+//! component type lives in its own archetype of `Vec<Box<dyn Any>>` and entities are looked
+//! up with a linear search.
+
+use std::{any::Any, collections::HashMap};
+
+/// The `Component` trait represents a component in an ECS.
+pub trait Component: Any + 'static {}
+
+/// The `Entity` type represents an entity in an ECS.
+pub type Entity = u32;
+
+pub struct Archetype {
+    entities: Vec<Entity>,
+    component_data: HashMap<usize, Vec<Box<dyn Any>>>,
+}
+
+impl Archetype {
+    /// Creates a new empty archetype.
+    pub fn new() -> Self {
+        Archetype {
+            entities: Vec::new(),
+            component_data: HashMap::new(),
+        }
+    }
+
+    /// Adds an entity to the archetype.
+    pub fn add_entity(&mut self, entity: Entity) {
+        self.entities.push(entity);
+    }
+
+    /// Adds a component to the archetype.
+    pub fn add_component<T: Component>(&mut self, component_id: usize, component: T) {
+        let component_data = self.component_data.entry(component_id).or_default();
+        component_data.push(Box::new(component));
+    }
+
+    /// Retrieves a component from the archetype.
+    pub fn get_component<T: Component>(&self, component_id: usize, entity: Entity) -> Option<&T> {
+        if let Some(component_data) = self.component_data.get(&component_id) {
+            if let Some(index) = self.entities.iter().position(|&e| e == entity) {
+                if let Some(component) = component_data[index].downcast_ref::<T>() {
+                    return Some(component);
+                }
+            }
+        }
+        None
+    }
+
+    /// Retrieves a mutable component from the archetype.
+    pub fn get_component_mut<T: Component>(
+        &mut self,
+        component_id: usize,
+        entity: Entity,
+    ) -> Option<&mut T> {
+        if let Some(component_data) = self.component_data.get_mut(&component_id) {
+            if let Some(index) = self.entities.iter().position(|&e| e == entity) {
+                if let Some(component) = component_data[index].downcast_mut::<T>() {
+                    return Some(component);
+                }
+            }
+        }
+        None
+    }
+}
+
+pub struct World {
+    entities: Vec<Entity>,
+    next_entity: Entity,
+    archetypes: HashMap<Vec<usize>, Archetype>,
+    component_ids: HashMap<String, usize>,
+}
+
+impl World {
+    pub fn new() -> Self {
+        World {
+            entities: Vec::new(),
+            next_entity: 0,
+            archetypes: HashMap::new(),
+            component_ids: HashMap::new(),
+        }
+    }
+
+    /// Creates a new entity in the world and returns its entity ID.
+    pub fn create_entity(&mut self) -> Entity {
+        let entity = self.next_entity;
+        self.next_entity += 1;
+        self.entities.push(entity);
+        entity
+    }
+
+    /// Registers a component type in the world and returns its component ID.
+    pub fn register_component<T: Component + 'static>(&mut self) -> usize {
+        let component_name = std::any::type_name::<T>().to_owned();
+        let component_id = self.component_ids.len();
+        self.component_ids.insert(component_name, component_id);
+        component_id
+    }
+
+    /// Adds a component to an entity in the world.
+    pub fn add_component<T: Component + 'static>(
+        &mut self,
+        entity: Entity,
+        component: T,
+    ) -> Result<(), String> {
+        let component_id = self.component_ids.get(std::any::type_name::<T>());
+        if let Some(&component_id) = component_id {
+            let archetype_key = vec![component_id];
+            if let Some(archetype) = self.archetypes.get_mut(&archetype_key) {
+                archetype.add_entity(entity);
+                archetype.add_component(component_id, component);
+                return Ok(());
+            } else {
+                let mut archetype = Archetype::new();
+                archetype.add_entity(entity);
+                archetype.add_component(component_id, component);
+                self.archetypes.insert(archetype_key, archetype);
+                return Ok(());
+            }
+        }
+        Err(format!(
+            "Component type not registered: {}",
+            std::any::type_name::<T>()
+        ))
+    }
+
+    /// Retrieves a component by its component ID and entity ID.
+    pub fn get_component<T: Component + 'static>(&self, entity: Entity) -> Result<&T, String> {
+        let component_id = self.component_ids.get(std::any::type_name::<T>());
+        if let Some(&component_id) = component_id {
+            let archetype_key = vec![component_id];
+            if let Some(archetype) = self.archetypes.get(&archetype_key) {
+                if let Some(component) = archetype.get_component::<T>(component_id, entity) {
+                    return Ok(component);
+                }
+            }
+        }
+        Err(format!(
+            "Component not found for entity ID {}: {}",
+            entity,
+            std::any::type_name::<T>()
+        ))
+    }
+
+    /// Retrieves a mutable component by its component ID and entity ID.
+    pub fn get_component_mut<T: Component + 'static>(
+        &mut self,
+        entity: Entity,
+    ) -> Result<&mut T, String> {
+        let component_id = self.component_ids.get(std::any::type_name::<T>());
+        if let Some(&component_id) = component_id {
+            let archetype_key = vec![component_id];
+            if let Some(archetype) = self.archetypes.get_mut(&archetype_key) {
+                if let Some(component) = archetype.get_component_mut::<T>(component_id, entity) {
+                    return Ok(component);
+                }
+            }
+        }
+        Err(format!(
+            "Component not found for entity ID {}: {}",
+            entity,
+            std::any::type_name::<T>()
+        ))
+    }
+
+    /// Returns an iterator over the entities in the world.
+    pub fn iter_entities(&self) -> impl Iterator<Item = &Entity> {
+        self.entities.iter()
+    }
+}
@@ -0,0 +1,107 @@
+//! Iterates 100k entities through the column storage and compares it with the previous
+//! `World` from [`baseline`]. Run with `cargo bench -p vent-ecs`.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use vent_ecs::{component::Component, world::World};
+
+mod baseline;
+
+const ENTITIES: usize = 100_000;
+const RUNS: u32 = 100;
+/// The previous world looks up every component with a linear search, so iterating is
+/// quadratic and 100k entities would take minutes per run.
+const BASELINE_ENTITIES: usize = 10_000;
+const BASELINE_RUNS: u32 = 5;
+
+#[derive(Clone, Copy)]
+struct Position([f32; 3]);
+impl Component for Position {}
+impl baseline::Component for Position {}
+
+#[derive(Clone, Copy)]
+struct Velocity([f32; 3]);
+impl Component for Velocity {}
+impl baseline::Component for Velocity {}
+
+fn bench(name: &str, entities: usize, runs: u32, mut f: impl FnMut()) {
+    // Warm up
+    f();
+    let start = Instant::now();
+    for _ in 0..runs {
+        f();
+    }
+    let per_run: Duration = start.elapsed() / runs;
+    println!("{name:<32} {per_run:?} / {entities} entities");
+}
+
+fn integrate(position: &mut Position, velocity: &Velocity) {
+    for i in 0..3 {
+        position.0[i] += velocity.0[i] * 0.016;
+    }
+}
+
+fn columns(entities: usize) -> World {
+    let mut world = World::new();
+    world.register_component::<Position>();
+    world.register_component::<Velocity>();
+    for i in 0..entities {
+        let entity = world.create_entity();
+        world
+            .add_component(entity, Position([i as f32; 3]))
+            .unwrap();
+        world.add_component(entity, Velocity([1.0; 3])).unwrap();
+    }
+    world
+}
+
+fn iterate_columns(world: &World) {
+    let mut query = world.query::<(&mut Position, &Velocity)>().unwrap();
+    for (mut position, velocity) in query.iter_mut() {
+        integrate(&mut position, velocity);
+    }
+    black_box(&query);
+}
+
+fn main() {
+    let world = columns(ENTITIES);
+    bench("columns (query)", ENTITIES, RUNS, || {
+        iterate_columns(&world)
+    });
+
+    let world = columns(BASELINE_ENTITIES);
+    bench("columns (query)", BASELINE_ENTITIES, RUNS, || {
+        iterate_columns(&world)
+    });
+
+    let mut world = baseline::World::new();
+    world.register_component::<Position>();
+    world.register_component::<Velocity>();
+    for i in 0..BASELINE_ENTITIES {
+        let entity = world.create_entity();
+        world
+            .add_component(entity, Position([i as f32; 3]))
+            .unwrap();
+        world.add_component(entity, Velocity([1.0; 3])).unwrap();
+    }
+    // The previous world had no queries, every component is fetched per entity
+    let entities: Vec<_> = world.iter_entities().copied().collect();
+    bench(
+        "baseline World (previous)",
+        BASELINE_ENTITIES,
+        BASELINE_RUNS,
+        || {
+            for &entity in &entities {
+                let velocity = *world.get_component::<Velocity>(entity).unwrap();
+                integrate(
+                    &mut *world.get_component_mut::<Position>(entity).unwrap(),
+                    &velocity,
+                );
+            }
+            black_box(&world);
+        },
+    );
+}
@@ -0,0 +1,80 @@
+//! Steers 100k agents spread over several archetypes, once with a serial query and once
+//! with `World::par_for_each_mut`. Run with `cargo bench -p vent-ecs --bench parallel`.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use vent_ecs::{component::Component, world::World};
+
+const ENTITIES: usize = 100_000;
+const RUNS: u32 = 100;
+
+#[derive(Clone, Copy)]
+struct Agent {
+    position: [f32; 2],
+    velocity: [f32; 2],
+    target: [f32; 2],
+}
+impl Component for Agent {}
+
+// Markers splitting the agents into several archetypes
+struct Red;
+impl Component for Red {}
+
+struct Blue;
+impl Component for Blue {}
+
+fn bench(name: &str, mut f: impl FnMut()) {
+    // Warm up
+    f();
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        f();
+    }
+    let per_run: Duration = start.elapsed() / RUNS;
+    println!("{name:<32} {per_run:?} / {ENTITIES} entities");
+}
+
+/// Accelerates the agent towards its target, enough work per agent to be worth spreading.
+fn steer(agent: &mut Agent) {
+    for _ in 0..8 {
+        let dx = agent.target[0] - agent.position[0];
+        let dy = agent.target[1] - agent.position[1];
+        let distance = (dx * dx + dy * dy).sqrt().max(f32::EPSILON);
+        agent.velocity[0] = agent.velocity[0] * 0.9 + dx / distance * 0.1;
+        agent.velocity[1] = agent.velocity[1] * 0.9 + dy / distance * 0.1;
+        agent.position[0] += agent.velocity[0] * 0.002;
+        agent.position[1] += agent.velocity[1] * 0.002;
+    }
+}
+
+fn main() {
+    let mut world = World::new();
+    for i in 0..ENTITIES {
+        let agent = Agent {
+            position: [i as f32, 0.0],
+            velocity: [0.0; 2],
+            target: [0.0, i as f32],
+        };
+        match i % 3 {
+            0 => world.spawn(agent),
+            1 => world.spawn((agent, Red)),
+            _ => world.spawn((agent, Blue)),
+        };
+    }
+
+    bench("serial (query)", || {
+        let mut query = world.query::<&mut Agent>().unwrap();
+        for mut agent in query.iter_mut() {
+            steer(&mut agent);
+        }
+        black_box(&query);
+    });
+
+    bench("parallel (par_for_each_mut)", || {
+        world.par_for_each_mut::<Agent>(steer);
+        black_box(&world);
+    });
+}
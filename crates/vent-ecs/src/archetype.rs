@@ -1,79 +1,227 @@
-use std::{any::Any, collections::HashMap};
+use std::{cell::UnsafeCell, mem::ManuallyDrop};
 
-use crate::{component::Component, entity::Entity};
+use crate::{
+    change::{ComponentTicks, Mut},
+    column::Column,
+    component::{Component, ComponentInfo},
+    entity::Entity,
+};
 
+/// Storage for all entities sharing the exact same set of components.
+///
+/// Every component has its own contiguous [`Column`] with one item per entity, so an
+/// entity is identified inside an archetype by its row. Queries write into columns
+/// through a shared `World`, access is guarded by the world's borrow flags.
 pub struct Archetype {
+    components: Vec<usize>,
     entities: Vec<Entity>,
-    component_data: HashMap<usize, Vec<Box<dyn Any>>>,
+    /// One column per component, in the same order as `components`.
+    columns: Vec<Column>,
+    /// Bumped on every change through `&mut self`, lets snapshots reuse unchanged columns.
+    version: u64,
 }
 
 impl Archetype {
-    /// Creates a new empty archetype.
-    pub fn new() -> Self {
+    /// Creates a new empty archetype for the given sorted set of component IDs.
+    pub(crate) fn new(components: Vec<usize>, infos: &[ComponentInfo]) -> Self {
+        let columns = components
+            .iter()
+            .map(|&component_id| Column::new(&infos[component_id]))
+            .collect();
         Archetype {
+            components,
             entities: Vec::new(),
-            component_data: HashMap::new(),
+            columns,
+            version: 0,
         }
     }
 
-    /// Adds an entity to the archetype.
-    pub fn add_entity(&mut self, entity: Entity) {
-        self.entities.push(entity);
+    /// Returns the sorted component IDs of this archetype.
+    pub fn components(&self) -> &[usize] {
+        &self.components
+    }
+
+    /// Returns `true` if the archetype stores the given component.
+    pub fn has_component(&self, component_id: usize) -> bool {
+        self.column_index(component_id).is_some()
+    }
+
+    /// Returns `true` if the archetype has all `required` and none of the `excluded` components.
+    pub fn matches(&self, required: &[usize], excluded: &[usize]) -> bool {
+        required.iter().all(|&id| self.has_component(id))
+            && !excluded.iter().any(|&id| self.has_component(id))
     }
 
-    /// Removes an entity from the archetype.
-    pub fn remove_entity(&mut self, entity: Entity) {
-        if let Some(index) = self.entities.iter().position(|&e| e == entity) {
-            self.entities.swap_remove(index);
+    /// Clamps change ticks which are too old to be compared with `tick`.
+    pub(crate) fn check_change_ticks(&mut self, tick: u32) {
+        for column in &mut self.columns {
+            column.check_change_ticks(tick);
         }
     }
 
-    /// Adds a component to the archetype.
-    pub fn add_component<T: Component>(&mut self, component_id: usize, component: T) {
-        let component_data = self.component_data.entry(component_id).or_default();
-        component_data.push(Box::new(component));
+    /// Returns the number of entities in the archetype.
+    pub fn len(&self) -> usize {
+        self.entities.len()
     }
 
-    /// Removes a component from the archetype.
-    pub fn remove_component(&mut self, component_id: usize, entity: Entity) {
-        if let Some(component_data) = self.component_data.get_mut(&component_id) {
-            if let Some(index) = self.entities.iter().position(|&e| e == entity) {
-                component_data.swap_remove(index);
-            }
+    /// Appends an entity and returns its row.
+    ///
+    /// # Safety
+    /// Every column must receive exactly one component for the row through [`Archetype::write_component`].
+    pub(crate) unsafe fn push_entity(&mut self, entity: Entity) -> usize {
+        self.version += 1;
+        self.entities.push(entity);
+        self.entities.len() - 1
+    }
+
+    /// Moves a component into the row, replacing the current one if the row already has it.
+    ///
+    /// # Safety
+    /// `T` must be the type registered for `component_id`, which must be part of the archetype.
+    /// Either the column already contains `row` or `row` is the next row to push.
+    pub(crate) unsafe fn write_component<T: Component>(
+        &mut self,
+        component_id: usize,
+        row: usize,
+        component: T,
+        tick: u32,
+    ) {
+        self.version += 1;
+        let index = self.column_index(component_id).unwrap();
+        let column = &mut self.columns[index];
+        if row < column.len() {
+            *column.get_ptr(row).cast::<T>() = component;
+            column.get_ticks_mut(row).changed = tick;
+        } else {
+            let component = ManuallyDrop::new(component);
+            column.push((&*component as *const T).cast(), ComponentTicks::new(tick));
         }
     }
 
-    /// Retrieves a component from the archetype.
-    pub fn get_component<T: Component>(&self, component_id: usize, entity: Entity) -> Option<&T> {
-        if let Some(component_data) = self.component_data.get(&component_id) {
-            if let Some(index) = self.entities.iter().position(|&e| e == entity) {
-                if let Some(component) = component_data[index].downcast_ref::<T>() {
-                    return Some(component);
+    /// Removes the row and drops its components.
+    ///
+    /// The last row is moved into the freed slot, the entity that got moved is returned
+    /// so its location can be updated.
+    pub fn swap_remove(&mut self, row: usize) -> Option<Entity> {
+        self.version += 1;
+        for column in self.columns.iter_mut() {
+            unsafe { column.swap_remove_and_drop(row) };
+        }
+        self.entities.swap_remove(row);
+        self.entities.get(row).copied()
+    }
+
+    /// Moves the row into `target`, components missing in `target` are dropped.
+    ///
+    /// Returns the entity moved into the freed slot and the new row in `target`.
+    ///
+    /// # Safety
+    /// Every column of `target` which is not part of this archetype must receive a component
+    /// for the new row through [`Archetype::write_component`].
+    pub(crate) unsafe fn move_row(
+        &mut self,
+        row: usize,
+        target: &mut Archetype,
+    ) -> (Option<Entity>, usize) {
+        self.version += 1;
+        for (component_id, column) in self.components.iter().zip(self.columns.iter_mut()) {
+            match target.column_index(*component_id) {
+                Some(index) => {
+                    target.columns[index].push(column.get_ptr(row), column.get_ticks(row));
+                    column.swap_remove_and_forget(row);
                 }
+                None => column.swap_remove_and_drop(row),
             }
         }
-        None
+        let entity = self.entities.swap_remove(row);
+        let new_row = target.push_entity(entity);
+        (self.entities.get(row).copied(), new_row)
     }
 
-    /// Retrieves a mutable component from the archetype.
+    /// Retrieves a component from the archetype.
+    ///
+    /// # Safety
+    /// `T` must be the type registered for `component_id` and the component must not be borrowed mutably.
+    pub unsafe fn get_component<T: Component>(
+        &self,
+        component_id: usize,
+        row: usize,
+    ) -> Option<&T> {
+        let index = self.column_index(component_id)?;
+        (row < self.len()).then(|| &*self.columns[index].get_ptr(row).cast::<T>())
+    }
+
+    /// Retrieves a mutable component from the archetype, it is marked as changed at `tick` once
+    /// it is written.
     pub fn get_component_mut<T: Component>(
         &mut self,
         component_id: usize,
-        entity: Entity,
-    ) -> Option<&mut T> {
-        if let Some(component_data) = self.component_data.get_mut(&component_id) {
-            if let Some(index) = self.entities.iter().position(|&e| e == entity) {
-                if let Some(component) = component_data[index].downcast_mut::<T>() {
-                    return Some(component);
-                }
-            }
+        row: usize,
+        tick: u32,
+    ) -> Option<Mut<'_, T>> {
+        let index = self.column_index(component_id)?;
+        if row >= self.len() {
+            return None;
         }
-        None
+        // Snapshots can not tell whether the component is written, so it counts as changed
+        self.version += 1;
+        let column = &mut self.columns[index];
+        // SAFETY: the world only creates columns with the registered type of `component_id`,
+        // the component and its ticks are stored apart
+        let value = unsafe { &mut *column.get_ptr(row).cast::<T>() };
+        Some(Mut::new(value, column.get_ticks_mut(row), tick))
+    }
+
+    /// Returns a pointer to the first item of a component column.
+    ///
+    /// Reading or writing through the pointer requires holding the matching borrow of the component.
+    pub fn column_ptr(&self, component_id: usize) -> Option<*mut u8> {
+        self.column_index(component_id)
+            .map(|index| self.columns[index].as_ptr())
+    }
+
+    /// Returns a pointer to the change ticks of the first item of a component column.
+    ///
+    /// Reading or writing through the pointer requires holding the matching borrow of the component.
+    pub(crate) fn ticks_ptr(
+        &self,
+        component_id: usize,
+    ) -> Option<*const UnsafeCell<ComponentTicks>> {
+        self.column_index(component_id)
+            .map(|index| self.columns[index].ticks_ptr())
+    }
+
+    /// Returns the entities of the archetype, indexed by row.
+    pub fn entities(&self) -> &[Entity] {
+        &self.entities
     }
 
     /// Returns an iterator over the entities in the archetype.
-    #[allow(dead_code)]
     pub fn iter_entities(&self) -> impl Iterator<Item = &Entity> {
         self.entities.iter()
     }
+
+    /// Returns the number of changes made through `&mut self`, writes through queries are
+    /// not counted.
+    pub(crate) fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns the column of a component.
+    pub(crate) fn column(&self, component_id: usize) -> Option<&Column> {
+        self.column_index(component_id)
+            .map(|index| &self.columns[index])
+    }
+
+    /// Returns the column of a component for writing.
+    pub(crate) fn column_mut(&mut self, component_id: usize) -> Option<&mut Column> {
+        self.version += 1;
+        self.column_index(component_id)
+            .map(|index| &mut self.columns[index])
+    }
+
+    #[inline]
+    fn column_index(&self, component_id: usize) -> Option<usize> {
+        self.components.binary_search(&component_id).ok()
+    }
 }
@@ -0,0 +1,109 @@
+use std::{
+    ops::{Deref, DerefMut},
+    sync::atomic::{AtomicIsize, Ordering},
+};
+
+/// Runtime borrow state of a component type, shared by all archetypes.
+///
+/// A positive value counts shared borrows, `-1` marks an exclusive borrow.
+#[derive(Default)]
+pub(crate) struct BorrowFlag(AtomicIsize);
+
+impl BorrowFlag {
+    /// Tries to acquire a shared borrow, fails if the component is borrowed mutably.
+    pub fn borrow(&self) -> bool {
+        let mut current = self.0.load(Ordering::Acquire);
+        loop {
+            if current < 0 {
+                return false;
+            }
+            match self.0.compare_exchange_weak(
+                current,
+                current + 1,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return true,
+                Err(actual) => current = actual,
+            }
+        }
+    }
+
+    /// Tries to acquire an exclusive borrow, fails if the component is borrowed at all.
+    pub fn borrow_mut(&self) -> bool {
+        self.0
+            .compare_exchange(0, -1, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+    }
+
+    pub fn release(&self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+
+    pub fn release_mut(&self) {
+        self.0.store(0, Ordering::Release);
+    }
+}
+
+/// A shared borrow of a component or resource, released when dropped.
+pub struct Ref<'w, T> {
+    value: &'w T,
+    flag: &'w BorrowFlag,
+}
+
+impl<'w, T> Ref<'w, T> {
+    /// # Safety
+    /// `flag` must already be borrowed for `value`'s component type.
+    pub(crate) unsafe fn new(value: &'w T, flag: &'w BorrowFlag) -> Self {
+        Self { value, flag }
+    }
+}
+
+impl<T> Deref for Ref<'_, T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T> Drop for Ref<'_, T> {
+    fn drop(&mut self) {
+        self.flag.release();
+    }
+}
+
+/// An exclusive borrow, released when dropped.
+pub struct RefMut<'w, T> {
+    value: &'w mut T,
+    flag: &'w BorrowFlag,
+}
+
+impl<'w, T> RefMut<'w, T> {
+    /// # Safety
+    /// `flag` must already be borrowed mutably for `value`.
+    pub(crate) unsafe fn new(value: &'w mut T, flag: &'w BorrowFlag) -> Self {
+        Self { value, flag }
+    }
+}
+
+impl<T> Deref for RefMut<'_, T> {
+    type Target = T;
+    #[inline]
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T> DerefMut for RefMut<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+    }
+}
+
+impl<T> Drop for RefMut<'_, T> {
+    fn drop(&mut self) {
+        self.flag.release_mut();
+    }
+}
@@ -0,0 +1,81 @@
+use std::slice;
+
+use crate::{archetype::Archetype, component::Component, world::World};
+
+/// A set of components added to an entity in a single archetype move.
+///
+/// Implemented for every [`Component`] and tuples of bundles, e.g. `(Transform, Mesh, Name)`.
+///
+/// # Safety
+/// `write` must write exactly the components reported by `component_ids`, in the same order.
+pub unsafe trait Bundle: Send + Sync + 'static {
+    /// Registers the components of the bundle and pushes their IDs.
+    fn component_ids(world: &mut World, ids: &mut Vec<usize>);
+
+    /// Moves the components into the row, taking their IDs from `ids` in order. New components
+    /// are marked as added at `tick`, replaced ones as changed.
+    ///
+    /// # Safety
+    /// `ids` must yield the IDs pushed by [`Bundle::component_ids`] and the archetype must
+    /// contain all of them. Every column either already contains `row` or `row` is the next
+    /// row to push.
+    unsafe fn write(
+        self,
+        archetype: &mut Archetype,
+        row: usize,
+        ids: &mut slice::Iter<'_, usize>,
+        tick: u32,
+    );
+}
+
+unsafe impl<T: Component> Bundle for T {
+    fn component_ids(world: &mut World, ids: &mut Vec<usize>) {
+        ids.push(world.register_component::<T>());
+    }
+
+    unsafe fn write(
+        self,
+        archetype: &mut Archetype,
+        row: usize,
+        ids: &mut slice::Iter<'_, usize>,
+        tick: u32,
+    ) {
+        archetype.write_component(*ids.next().unwrap(), row, self, tick);
+    }
+}
+
+macro_rules! impl_bundle_tuple {
+    ($($name:ident),*) => {
+        #[allow(non_snake_case, unused_variables)]
+        unsafe impl<$($name: Bundle),*> Bundle for ($($name,)*) {
+            fn component_ids(world: &mut World, ids: &mut Vec<usize>) {
+                $($name::component_ids(world, ids);)*
+            }
+
+            unsafe fn write(
+                self,
+                archetype: &mut Archetype,
+                row: usize,
+                ids: &mut slice::Iter<'_, usize>,
+                tick: u32,
+            ) {
+                let ($($name,)*) = self;
+                $($name.write(archetype, row, ids, tick);)*
+            }
+        }
+    };
+}
+
+impl_bundle_tuple!();
+impl_bundle_tuple!(A);
+impl_bundle_tuple!(A, B);
+impl_bundle_tuple!(A, B, C);
+impl_bundle_tuple!(A, B, C, D);
+impl_bundle_tuple!(A, B, C, D, E);
+impl_bundle_tuple!(A, B, C, D, E, F);
+impl_bundle_tuple!(A, B, C, D, E, F, G);
+impl_bundle_tuple!(A, B, C, D, E, F, G, H);
+impl_bundle_tuple!(A, B, C, D, E, F, G, H, I);
+impl_bundle_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_bundle_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_bundle_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
@@ -0,0 +1,156 @@
+use std::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{component::Component, entity::Entity};
+
+/// How many ticks may pass before [`crate::world::World::check_change_ticks`] clamps old
+/// change ticks, well below the wrap around of `u32`.
+pub const CHECK_TICK_THRESHOLD: u32 = 518_400_000;
+
+/// The maximum age of a change tick, older ticks are clamped to this age so they are never
+/// mistaken for new ones after the world tick wrapped around.
+pub const MAX_CHANGE_AGE: u32 = u32::MAX - (2 * CHECK_TICK_THRESHOLD - 1);
+
+/// The world ticks at which a component was added and last changed.
+///
+/// The world tick advances every time a system runs and with every
+/// [`crate::world::World::clear_trackers`], a component was added or changed since a system
+/// last ran if its tick is newer than the system's [`SystemTicks::last_run`].
+#[derive(Clone, Copy)]
+pub struct ComponentTicks {
+    pub(crate) added: u32,
+    pub(crate) changed: u32,
+}
+
+impl ComponentTicks {
+    #[inline]
+    pub(crate) const fn new(tick: u32) -> Self {
+        Self {
+            added: tick,
+            changed: tick,
+        }
+    }
+
+    /// Clamps ticks older than [`MAX_CHANGE_AGE`] relative to `tick`.
+    pub(crate) fn check(&mut self, tick: u32) {
+        for component_tick in [&mut self.added, &mut self.changed] {
+            if tick.wrapping_sub(*component_tick) > MAX_CHANGE_AGE {
+                *component_tick = tick.wrapping_sub(MAX_CHANGE_AGE);
+            }
+        }
+    }
+}
+
+/// The tick a system last ran at and the tick of its current run, change filters report
+/// components whose ticks lie in between.
+///
+/// Queries made directly on the world use the tick of the last
+/// [`crate::world::World::clear_trackers`] as `last_run`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SystemTicks {
+    last_run: u32,
+    this_run: u32,
+}
+
+impl SystemTicks {
+    pub const fn new(last_run: u32, this_run: u32) -> Self {
+        Self { last_run, this_run }
+    }
+
+    pub const fn last_run(self) -> u32 {
+        self.last_run
+    }
+
+    pub const fn this_run(self) -> u32 {
+        self.this_run
+    }
+
+    /// Returns `true` if `tick` is newer than `last_run` and not newer than `this_run`.
+    ///
+    /// Ticks are compared by their distance to `this_run`, so the comparison keeps working
+    /// after the world tick wrapped around.
+    #[inline]
+    pub const fn is_newer(self, tick: u32) -> bool {
+        self.this_run.wrapping_sub(tick) < self.this_run.wrapping_sub(self.last_run)
+    }
+}
+
+/// A component fetched mutably by a query or [`crate::world::World::get_component_mut`],
+/// marked as changed only once it is written through [`DerefMut`].
+pub struct Mut<'w, T> {
+    value: &'w mut T,
+    ticks: &'w mut ComponentTicks,
+    tick: u32,
+}
+
+impl<'w, T> Mut<'w, T> {
+    #[inline]
+    pub(crate) fn new(value: &'w mut T, ticks: &'w mut ComponentTicks, tick: u32) -> Self {
+        Self { value, ticks, tick }
+    }
+
+    /// Returns the component without marking it as changed.
+    #[inline]
+    pub fn bypass_change_detection(&mut self) -> &mut T {
+        self.value
+    }
+
+    /// Marks the component as changed without writing it.
+    #[inline]
+    pub fn set_changed(&mut self) {
+        self.ticks.changed = self.tick;
+    }
+}
+
+impl<T> Deref for Mut<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T> DerefMut for Mut<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        self.set_changed();
+        self.value
+    }
+}
+
+/// Entities that lost the component `T` since the trackers were last cleared, either because
+/// it was removed or because the entity was deleted.
+///
+/// Unlike the change filters removals are not tracked per system, a system running before
+/// the removal in the same frame does not see it.
+pub struct RemovedComponents<'w, T: Component> {
+    entities: &'w [Entity],
+    _marker: PhantomData<T>,
+}
+
+impl<'w, T: Component> RemovedComponents<'w, T> {
+    pub(crate) fn new(entities: &'w [Entity]) -> Self {
+        Self {
+            entities,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the entities in the order the component was removed.
+    pub fn iter(&self) -> impl Iterator<Item = Entity> + 'w {
+        self.entities.iter().copied()
+    }
+
+    /// Returns the number of removals.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if the component was not removed from any entity.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+}
@@ -0,0 +1,227 @@
+use std::{
+    alloc::{self, Layout},
+    cell::UnsafeCell,
+    ptr::{self, NonNull},
+};
+
+use crate::{change::ComponentTicks, component::ComponentInfo};
+
+/// A type-erased, contiguous vector of components of a single type.
+///
+/// Items are stored back to back like a `Vec<T>`, the type is only known through the
+/// layout and drop function registered with the component.
+pub(crate) struct Column {
+    item_layout: Layout,
+    drop: Option<unsafe fn(*mut u8)>,
+    data: NonNull<u8>,
+    capacity: usize,
+    len: usize,
+    /// Change ticks of every item, written through shared references while the component
+    /// is borrowed mutably.
+    ticks: Vec<UnsafeCell<ComponentTicks>>,
+}
+
+// SAFETY: columns only store `Component`s, which are `Send + Sync`, shared access from
+// multiple threads is guarded by the world's borrow flags
+unsafe impl Send for Column {}
+unsafe impl Sync for Column {}
+
+impl Column {
+    pub fn new(info: &ComponentInfo) -> Self {
+        // Zero sized components never need an allocation
+        let capacity = if info.layout.size() == 0 {
+            usize::MAX
+        } else {
+            0
+        };
+        Self {
+            item_layout: info.layout,
+            drop: info.drop,
+            data: NonNull::new(ptr::without_provenance_mut(info.layout.align())).unwrap(),
+            capacity,
+            len: 0,
+            ticks: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns a pointer to the first item.
+    #[inline]
+    pub fn as_ptr(&self) -> *mut u8 {
+        self.data.as_ptr()
+    }
+
+    /// Returns a pointer to the item at `row`.
+    ///
+    /// # Safety
+    /// `row` must not be greater than the length.
+    #[inline]
+    pub unsafe fn get_ptr(&self, row: usize) -> *mut u8 {
+        self.data.as_ptr().add(row * self.item_layout.size())
+    }
+
+    /// Returns a pointer to the change ticks of the first item.
+    #[inline]
+    pub fn ticks_ptr(&self) -> *const UnsafeCell<ComponentTicks> {
+        self.ticks.as_ptr()
+    }
+
+    /// Returns the change ticks of the item at `row`.
+    ///
+    /// # Safety
+    /// `row` must be in bounds and the item must not be borrowed mutably.
+    #[inline]
+    pub unsafe fn get_ticks(&self, row: usize) -> ComponentTicks {
+        *self.ticks[row].get()
+    }
+
+    /// Returns the change ticks of the item at `row` for writing.
+    #[inline]
+    pub fn get_ticks_mut(&mut self, row: usize) -> &mut ComponentTicks {
+        self.ticks[row].get_mut()
+    }
+
+    /// Clamps change ticks which are too old to be compared with `tick`, see
+    /// [`ComponentTicks::check`].
+    pub fn check_change_ticks(&mut self, tick: u32) {
+        for ticks in &mut self.ticks {
+            ticks.get_mut().check(tick);
+        }
+    }
+
+    /// Returns all items together with their change ticks.
+    ///
+    /// # Safety
+    /// `T` must be the type of the column's items.
+    #[inline]
+    pub unsafe fn as_slice_mut<T>(&mut self) -> (&mut [T], &mut [ComponentTicks]) {
+        let items = std::slice::from_raw_parts_mut(self.data.as_ptr().cast::<T>(), self.len);
+        // `UnsafeCell<T>` has the same layout as `T`
+        let ticks = std::slice::from_raw_parts_mut(
+            self.ticks.as_mut_ptr().cast::<ComponentTicks>(),
+            self.ticks.len(),
+        );
+        (items, ticks)
+    }
+
+    /// Moves the item behind `value` into the column.
+    ///
+    /// # Safety
+    /// `value` must point to a valid item of the column's type, the caller must not use or drop it afterwards.
+    pub unsafe fn push(&mut self, value: *const u8, ticks: ComponentTicks) {
+        self.reserve_one();
+        ptr::copy_nonoverlapping(value, self.get_ptr(self.len), self.item_layout.size());
+        self.ticks.push(UnsafeCell::new(ticks));
+        self.len += 1;
+    }
+
+    /// Clones the item behind `value` into the column.
+    ///
+    /// # Safety
+    /// `value` must point to a valid item of the column's type and `clone` must clone that type.
+    pub unsafe fn push_clone(
+        &mut self,
+        value: *const u8,
+        ticks: ComponentTicks,
+        clone: unsafe fn(*const u8, *mut u8),
+    ) {
+        self.reserve_one();
+        clone(value, self.get_ptr(self.len));
+        self.ticks.push(UnsafeCell::new(ticks));
+        self.len += 1;
+    }
+
+    /// Returns a new column with clones of all items and their change ticks.
+    ///
+    /// # Safety
+    /// `clone` must clone items of the column's type and no item may be borrowed mutably.
+    pub unsafe fn clone_with(&self, clone: unsafe fn(*const u8, *mut u8)) -> Self {
+        let mut column = Self {
+            item_layout: self.item_layout,
+            drop: self.drop,
+            data: NonNull::new(ptr::without_provenance_mut(self.item_layout.align())).unwrap(),
+            capacity: if self.item_layout.size() == 0 {
+                usize::MAX
+            } else {
+                0
+            },
+            len: 0,
+            ticks: Vec::with_capacity(self.len),
+        };
+        for row in 0..self.len {
+            column.push_clone(self.get_ptr(row), self.get_ticks(row), clone);
+        }
+        column
+    }
+
+    /// Drops the item at `row` and moves the last item into its place.
+    ///
+    /// # Safety
+    /// `row` must be in bounds.
+    pub unsafe fn swap_remove_and_drop(&mut self, row: usize) {
+        if let Some(drop) = self.drop {
+            drop(self.get_ptr(row));
+        }
+        self.swap_remove_and_forget(row);
+    }
+
+    /// Moves the last item into `row` without dropping the item previously stored there.
+    ///
+    /// # Safety
+    /// `row` must be in bounds and its item must have been moved out or dropped already.
+    pub unsafe fn swap_remove_and_forget(&mut self, row: usize) {
+        let last = self.len - 1;
+        if row != last {
+            ptr::copy_nonoverlapping(
+                self.get_ptr(last),
+                self.get_ptr(row),
+                self.item_layout.size(),
+            );
+        }
+        self.ticks.swap_remove(row);
+        self.len = last;
+    }
+
+    fn array_layout(&self, capacity: usize) -> Layout {
+        Layout::from_size_align(self.item_layout.size() * capacity, self.item_layout.align())
+            .expect("Column capacity overflow")
+    }
+
+    fn reserve_one(&mut self) {
+        if self.len < self.capacity {
+            return;
+        }
+        let new_capacity = (self.capacity * 2).max(4);
+        let new_layout = self.array_layout(new_capacity);
+        let new_data = unsafe {
+            if self.capacity == 0 {
+                alloc::alloc(new_layout)
+            } else {
+                alloc::realloc(
+                    self.data.as_ptr(),
+                    self.array_layout(self.capacity),
+                    new_layout.size(),
+                )
+            }
+        };
+        self.data = NonNull::new(new_data).unwrap_or_else(|| alloc::handle_alloc_error(new_layout));
+        self.capacity = new_capacity;
+    }
+}
+
+impl Drop for Column {
+    fn drop(&mut self) {
+        if let Some(drop) = self.drop {
+            for row in 0..self.len {
+                unsafe { drop(self.get_ptr(row)) };
+            }
+        }
+        if self.item_layout.size() != 0 && self.capacity != 0 {
+            unsafe { alloc::dealloc(self.data.as_ptr(), self.array_layout(self.capacity)) };
+        }
+    }
+}
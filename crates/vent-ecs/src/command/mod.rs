@@ -0,0 +1,61 @@
+use crate::{bundle::Bundle, component::Component, entity::Entity, error::Error, world::World};
+
+/// A deferred change to the world, applied by [`World::flush_commands`].
+pub(crate) type Command = Box<dyn FnOnce(&mut World) -> Result<(), Error> + Send>;
+
+/// Records structural changes while the world is borrowed, e.g. during a query or
+/// inside a system.
+///
+/// ```ignore
+/// let commands = world.commands();
+/// for (entity, health) in world.query::<(Entity, &Health)>()?.iter() {
+///     if health.0 <= 0.0 {
+///         commands.despawn(entity);
+///     }
+/// }
+/// world.flush_commands()?;
+/// ```
+///
+/// Commands are applied in the order they were recorded.
+#[derive(Clone, Copy)]
+pub struct Commands<'w> {
+    world: &'w World,
+}
+
+impl<'w> Commands<'w> {
+    pub(crate) fn new(world: &'w World) -> Self {
+        Self { world }
+    }
+
+    /// Spawns an entity with all components of the bundle.
+    ///
+    /// The returned entity is reserved right away but only becomes alive once the commands are flushed.
+    pub fn spawn<B: Bundle>(&self, bundle: B) -> Entity {
+        let entity = self.world.reserve_entity();
+        self.add(move |world| {
+            world.spawn_at(entity, bundle);
+            Ok(())
+        });
+        entity
+    }
+
+    /// Deletes an entity.
+    pub fn despawn(&self, entity: Entity) {
+        self.add(move |world| world.delete_entity(entity));
+    }
+
+    /// Adds the components of the bundle to an entity, replacing components it already has.
+    pub fn insert<B: Bundle>(&self, entity: Entity, bundle: B) {
+        self.add(move |world| world.insert(entity, bundle));
+    }
+
+    /// Removes a component from an entity.
+    pub fn remove<T: Component>(&self, entity: Entity) {
+        self.add(move |world| world.remove_component::<T>(entity));
+    }
+
+    /// Queues a custom command.
+    pub fn add(&self, command: impl FnOnce(&mut World) -> Result<(), Error> + Send + 'static) {
+        self.world.push_command(Box::new(command));
+    }
+}
@@ -1,8 +1,58 @@
-use std::any::Any;
+use std::{alloc::Layout, any::Any};
+
+use crate::{entity::Entity, world::World};
 
 mod input_component;
 
 /// The `Component` trait represents a component in an ECS.
-pub trait Component: Any + 'static {}
+///
+/// Components have to be `Send + Sync` because systems access them from worker threads.
+pub trait Component: Any + Send + Sync + 'static {}
+
+/// Type-erased information about a registered component, used to store it in archetype columns.
+#[derive(Clone, Copy)]
+pub(crate) struct ComponentInfo {
+    pub name: &'static str,
+    pub layout: Layout,
+    pub drop: Option<unsafe fn(*mut u8)>,
+    /// Clones an item into uninitialized memory, only set for components registered
+    /// through [`World::register_snapshot`].
+    pub clone: Option<unsafe fn(*const u8, *mut u8)>,
+}
+
+impl ComponentInfo {
+    pub fn of<T: Component>() -> Self {
+        unsafe fn drop_ptr<T>(ptr: *mut u8) {
+            ptr.cast::<T>().drop_in_place();
+        }
+
+        Self {
+            name: std::any::type_name::<T>(),
+            layout: Layout::new::<T>(),
+            drop: std::mem::needs_drop::<T>().then_some(drop_ptr::<T> as unsafe fn(*mut u8)),
+            clone: None,
+        }
+    }
+
+    /// Returns the info of a component which can be cloned into snapshots.
+    pub fn of_clone<T: Component + Clone>() -> Self {
+        unsafe fn clone_ptr<T: Clone>(src: *const u8, dst: *mut u8) {
+            dst.cast::<T>().write((*src.cast::<T>()).clone());
+        }
+
+        Self {
+            clone: Some(clone_ptr::<T>),
+            ..Self::of::<T>()
+        }
+    }
+}
+
+/// Callback run when a component is added to or removed from an entity.
+pub(crate) type ComponentHook = Box<dyn Fn(&World, Entity) + Send + Sync>;
 
-// Soon ...
+/// Lifecycle hooks of a registered component.
+#[derive(Default)]
+pub(crate) struct ComponentHooks {
+    pub on_add: Vec<ComponentHook>,
+    pub on_remove: Vec<ComponentHook>,
+}
@@ -1,4 +1,79 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 /// The `Entity` type represents an entity in an ECS.
-pub type Entity = u32;
+///
+/// An entity is an index into the world plus a generation. Indices of deleted entities
+/// get reused, the generation is bumped every time so stale handles never alias a new entity.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Entity {
+    index: u32,
+    generation: u32,
+}
+
+impl Entity {
+    #[inline]
+    pub(crate) const fn new(index: u32, generation: u32) -> Self {
+        Self { index, generation }
+    }
+
+    /// The slot of the entity inside the world, shared with previously deleted entities.
+    #[inline]
+    pub const fn index(self) -> u32 {
+        self.index
+    }
+
+    /// How often the index has been reused.
+    #[inline]
+    pub const fn generation(self) -> u32 {
+        self.generation
+    }
+
+    /// Packs the entity into a single `u64`, generation in the upper bits.
+    #[inline]
+    pub const fn to_bits(self) -> u64 {
+        (self.generation as u64) << 32 | self.index as u64
+    }
+
+    /// Unpacks an entity previously packed with [`Entity::to_bits`].
+    #[inline]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self::new(bits as u32, (bits >> 32) as u32)
+    }
+}
+
+/// Entities are stored as their packed bits, see [`Entity::to_bits`].
+impl Serialize for Entity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.to_bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for Entity {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u64::deserialize(deserializer).map(Self::from_bits)
+    }
+}
+
+impl fmt::Display for Entity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}v{}", self.index, self.generation)
+    }
+}
+
+/// Where the components of an entity are stored: the archetype and the row inside it.
+#[derive(Clone, Copy)]
+pub(crate) struct EntityLocation {
+    pub archetype: usize,
+    pub row: usize,
+}
 
-// Soon ...
+/// Per index bookkeeping of the world.
+#[derive(Clone, Copy)]
+pub(crate) struct EntityMeta {
+    /// Generation of the entity currently or next living at this index.
+    pub generation: u32,
+    /// `None` while the index is free.
+    pub location: Option<EntityLocation>,
+}
@@ -0,0 +1,64 @@
+use std::fmt;
+
+use crate::entity::Entity;
+
+/// Errors returned by [`crate::world::World`] operations.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Error {
+    /// The entity was deleted or never existed.
+    EntityNotFound(Entity),
+    /// The entity is alive but does not have the component.
+    ComponentMissing {
+        entity: Entity,
+        component: &'static str,
+    },
+    /// The component or resource is already borrowed in a way that conflicts with the requested access.
+    AlreadyBorrowed(&'static str),
+    /// A query accesses the same component mutably more than once.
+    ConflictingAccess(&'static str),
+    /// No resource of the type has been inserted.
+    ResourceMissing(&'static str),
+    /// The `before`/`after` constraints of a stage form a cycle, contains one of the systems on it.
+    SystemCycle(&'static str),
+    /// The entity is alive but does not match the query.
+    QueryMismatch(Entity),
+    /// The parent is the entity itself or one of its descendants.
+    HierarchyCycle(Entity),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::EntityNotFound(entity) => write!(f, "Entity {} does not exist", entity),
+            Error::ComponentMissing { entity, component } => {
+                write!(
+                    f,
+                    "Component not found for entity {}: {}",
+                    entity, component
+                )
+            }
+            Error::AlreadyBorrowed(component) => {
+                write!(f, "Component already borrowed: {}", component)
+            }
+            Error::ConflictingAccess(component) => {
+                write!(
+                    f,
+                    "Component accessed mutably more than once: {}",
+                    component
+                )
+            }
+            Error::ResourceMissing(resource) => write!(f, "Resource not found: {}", resource),
+            Error::SystemCycle(system) => {
+                write!(f, "System ordering constraints form a cycle: {}", system)
+            }
+            Error::QueryMismatch(entity) => {
+                write!(f, "Entity {} does not match the query", entity)
+            }
+            Error::HierarchyCycle(entity) => {
+                write!(f, "Entity {} can not be its own ancestor", entity)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
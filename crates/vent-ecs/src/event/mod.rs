@@ -0,0 +1,237 @@
+use std::marker::PhantomData;
+
+use crate::{
+    change::SystemTicks,
+    error::Error,
+    query::Access,
+    resource::{Res, ResMut, Resource},
+    system::SystemParam,
+    world::World,
+};
+
+/// The `Event` trait represents a message sent between systems, e.g. a key press or a
+/// collision.
+pub trait Event: Send + Sync + 'static {}
+
+struct EventInstance<T> {
+    id: usize,
+    event: T,
+}
+
+/// A double buffered queue of events of one type, stored as a resource.
+///
+/// Events are kept for two [`Events::update`]s, so every reader running once per frame sees
+/// each event exactly once, no matter if it runs before or after the writer.
+pub struct Events<T: Event> {
+    /// Events sent before the last update.
+    events_a: Vec<EventInstance<T>>,
+    /// Events sent since the last update.
+    events_b: Vec<EventInstance<T>>,
+    a_start_event_count: usize,
+    b_start_event_count: usize,
+    event_count: usize,
+}
+
+impl<T: Event> Events<T> {
+    pub fn new() -> Self {
+        Self {
+            events_a: Vec::new(),
+            events_b: Vec::new(),
+            a_start_event_count: 0,
+            b_start_event_count: 0,
+            event_count: 0,
+        }
+    }
+
+    pub fn send(&mut self, event: T) {
+        self.events_b.push(EventInstance {
+            id: self.event_count,
+            event,
+        });
+        self.event_count += 1;
+    }
+
+    pub fn send_batch(&mut self, events: impl IntoIterator<Item = T>) {
+        for event in events {
+            self.send(event);
+        }
+    }
+
+    /// Swaps the buffers, dropping the events sent before the previous update.
+    ///
+    /// Called once per frame by [`World::update_events`].
+    pub fn update(&mut self) {
+        std::mem::swap(&mut self.events_a, &mut self.events_b);
+        self.events_b.clear();
+        self.a_start_event_count = self.b_start_event_count;
+        self.b_start_event_count = self.event_count;
+    }
+
+    /// Removes all events, readers will not see them anymore.
+    pub fn clear(&mut self) {
+        self.events_a.clear();
+        self.events_b.clear();
+        self.a_start_event_count = self.event_count;
+        self.b_start_event_count = self.event_count;
+    }
+
+    /// Removes all events and returns them in the order they were sent.
+    pub fn drain(&mut self) -> impl Iterator<Item = T> + '_ {
+        self.a_start_event_count = self.event_count;
+        self.b_start_event_count = self.event_count;
+        self.events_a
+            .drain(..)
+            .chain(self.events_b.drain(..))
+            .map(|instance| instance.event)
+    }
+
+    /// Returns the number of buffered events.
+    pub fn len(&self) -> usize {
+        self.events_a.len() + self.events_b.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T: Event> Default for Events<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Event> Resource for Events<T> {}
+
+/// Remembers which events of an [`Events`] queue were already read.
+///
+/// Every [`EventReader`] owns one, it can also be used directly outside of systems.
+pub struct EventCursor<T: Event> {
+    last_event_count: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Event> EventCursor<T> {
+    /// Returns the events sent since the last read, oldest first.
+    pub fn read<'a>(&mut self, events: &'a Events<T>) -> impl Iterator<Item = &'a T> + 'a {
+        let a_index = self
+            .last_event_count
+            .saturating_sub(events.a_start_event_count)
+            .min(events.events_a.len());
+        let b_index = self
+            .last_event_count
+            .saturating_sub(events.b_start_event_count)
+            .min(events.events_b.len());
+        self.last_event_count = events.event_count;
+        events.events_a[a_index..]
+            .iter()
+            .chain(&events.events_b[b_index..])
+            .map(|instance| &instance.event)
+    }
+
+    /// Returns the number of events that were not read yet.
+    pub fn len(&self, events: &Events<T>) -> usize {
+        events
+            .events_a
+            .iter()
+            .chain(&events.events_b)
+            .filter(|instance| instance.id >= self.last_event_count)
+            .count()
+    }
+
+    pub fn is_empty(&self, events: &Events<T>) -> bool {
+        self.len(events) == 0
+    }
+}
+
+impl<T: Event> Default for EventCursor<T> {
+    fn default() -> Self {
+        Self {
+            last_event_count: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Reads the events of type `T` sent since the system last ran.
+///
+/// ```ignore
+/// fn jump(mut events: EventReader<KeyPressed>, mut query: Query<&mut Velocity, With<Player>>) {
+///     for event in events.read() {
+///         // ...
+///     }
+/// }
+/// ```
+pub struct EventReader<'w, T: Event> {
+    cursor: &'w mut EventCursor<T>,
+    events: Res<'w, Events<T>>,
+}
+
+impl<T: Event> EventReader<'_, T> {
+    pub fn read(&mut self) -> impl Iterator<Item = &T> {
+        self.cursor.read(&self.events)
+    }
+
+    /// Returns the number of events that were not read yet.
+    pub fn len(&self) -> usize {
+        self.cursor.len(&self.events)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cursor.is_empty(&self.events)
+    }
+}
+
+impl<T: Event> SystemParam for EventReader<'_, T> {
+    type State = EventCursor<T>;
+    type Item<'w> = EventReader<'w, T>;
+
+    fn access(world: &World, access: &mut Access) {
+        <Res<'_, Events<T>>>::access(world, access);
+    }
+
+    fn fetch<'w>(
+        world: &'w World,
+        state: &'w mut Self::State,
+        _ticks: SystemTicks,
+    ) -> Result<Self::Item<'w>, Error> {
+        Ok(EventReader {
+            cursor: state,
+            events: world.resource::<Events<T>>()?,
+        })
+    }
+}
+
+/// Sends events of type `T`.
+pub struct EventWriter<'w, T: Event> {
+    events: ResMut<'w, Events<T>>,
+}
+
+impl<T: Event> EventWriter<'_, T> {
+    pub fn send(&mut self, event: T) {
+        self.events.send(event);
+    }
+
+    pub fn send_batch(&mut self, events: impl IntoIterator<Item = T>) {
+        self.events.send_batch(events);
+    }
+}
+
+impl<T: Event> SystemParam for EventWriter<'_, T> {
+    type State = ();
+    type Item<'w> = EventWriter<'w, T>;
+
+    fn access(world: &World, access: &mut Access) {
+        <ResMut<'_, Events<T>>>::access(world, access);
+    }
+
+    fn fetch<'w>(
+        world: &'w World,
+        _state: &'w mut Self::State,
+        _ticks: SystemTicks,
+    ) -> Result<Self::Item<'w>, Error> {
+        Ok(EventWriter {
+            events: world.resource_mut::<Events<T>>()?,
+        })
+    }
+}
@@ -0,0 +1,187 @@
+use std::ops::Deref;
+
+use serde::{Deserialize, Serialize};
+use vent_math::scalar::affine3a::Affine3A;
+
+use crate::{
+    component::Component,
+    entity::Entity,
+    error::Error,
+    scene::{EntityMap, MapEntities},
+    transform::Transform,
+    world::World,
+};
+
+/// The parent of an entity, e.g. the glTF node it belongs to.
+///
+/// Managed through [`World::set_parent`] and [`World::remove_parent`], which keep it in sync
+/// with the [`Children`] of the parent.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Parent(Entity);
+
+impl Parent {
+    #[inline]
+    pub const fn get(&self) -> Entity {
+        self.0
+    }
+}
+
+impl Component for Parent {}
+
+impl MapEntities for Parent {
+    fn map_entities(&mut self, map: &EntityMap) {
+        self.0 = map.get(self.0);
+    }
+}
+
+/// The children of an entity in the order they were attached.
+#[derive(Clone, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Children(Vec<Entity>);
+
+impl Deref for Children {
+    type Target = [Entity];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Component for Children {}
+
+impl MapEntities for Children {
+    fn map_entities(&mut self, map: &EntityMap) {
+        for entity in &mut self.0 {
+            *entity = map.get(*entity);
+        }
+    }
+}
+
+impl World {
+    /// Attaches `child` to `parent`, detaching it from its previous parent.
+    ///
+    /// The [`Transform`] of the child is rewritten relative to the new parent, so it stays
+    /// at the same position in the world. A parent scaled to zero can not be inverted, the
+    /// child keeps its [`Transform`] as the one relative to it then.
+    pub fn set_parent(&mut self, child: Entity, parent: Entity) -> Result<(), Error> {
+        self.location(child)?;
+        self.location(parent)?;
+        // Reattaching would move the child to the end of the children
+        if self.parent(child) == Some(parent) {
+            return Ok(());
+        }
+        // The child must not end up as its own ancestor
+        let mut ancestor = Some(parent);
+        while let Some(entity) = ancestor {
+            if entity == child {
+                return Err(Error::HierarchyCycle(child));
+            }
+            ancestor = self.parent(entity);
+        }
+
+        let world_matrix = self.world_matrix(child);
+        let parent_matrix = self.world_matrix(parent);
+        self.detach(child);
+        self.attach(child, parent)?;
+        if parent_matrix.matrix3.determinant() != 0.0 {
+            if let Ok(mut transform) = self.get_component_mut::<Transform>(child) {
+                *transform = Transform::from_affine(parent_matrix.inverse() * world_matrix);
+            }
+        }
+        Ok(())
+    }
+
+    /// Detaches an entity from its parent, making it a root that stays at the same position
+    /// in the world.
+    pub fn remove_parent(&mut self, child: Entity) -> Result<(), Error> {
+        self.location(child)?;
+        if self.parent(child).is_none() {
+            return Ok(());
+        }
+        let world_matrix = self.world_matrix(child);
+        self.detach(child);
+        self.remove_component::<Parent>(child)?;
+        if let Ok(mut transform) = self.get_component_mut::<Transform>(child) {
+            *transform = Transform::from_affine(world_matrix);
+        }
+        Ok(())
+    }
+
+    /// Deletes an entity together with all of its descendants.
+    pub fn despawn_recursive(&mut self, entity: Entity) -> Result<(), Error> {
+        self.location(entity)?;
+        let mut descendants = vec![entity];
+        let mut next = 0;
+        while let Some(&entity) = descendants.get(next) {
+            if let Ok(children) = self.get_component::<Children>(entity) {
+                descendants.extend(children.iter().copied());
+            }
+            next += 1;
+        }
+        // Leaves first, so no child is detached from a parent which is deleted anyway
+        for entity in descendants.into_iter().rev() {
+            // Children of loaded scenes may be stale handles
+            if self.is_alive(entity) {
+                self.delete_entity(entity)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes an entity about to be deleted from the [`Children`] of its parent and
+    /// detaches its children, see [`World::delete_entity`].
+    pub(crate) fn unlink_hierarchy(&mut self, entity: Entity) {
+        self.detach(entity);
+        let children = match self.get_component::<Children>(entity) {
+            Ok(children) => children.0.clone(),
+            Err(_) => return,
+        };
+        for child in children {
+            // Skips stale children and children whose parent points elsewhere, e.g. from an
+            // inconsistent scene
+            if self.parent(child) == Some(entity) {
+                let _ = self.remove_parent(child);
+            }
+        }
+    }
+
+    /// Sets the [`Parent`] of the entity and appends it to the [`Children`] of the parent,
+    /// leaving its [`Transform`] and the [`Children`] of a previous parent as they are.
+    pub(crate) fn attach(&mut self, child: Entity, parent: Entity) -> Result<(), Error> {
+        self.insert(child, Parent(parent))?;
+        match self.get_component_mut::<Children>(parent) {
+            Ok(mut children) => children.0.push(child),
+            Err(_) => self.insert(parent, Children(vec![child]))?,
+        }
+        Ok(())
+    }
+
+    fn parent(&self, entity: Entity) -> Option<Entity> {
+        self.get_component::<Parent>(entity)
+            .ok()
+            .map(|parent| parent.get())
+    }
+
+    /// Removes the entity from the [`Children`] of its parent, leaving its [`Parent`] in place.
+    pub(crate) fn detach(&mut self, child: Entity) {
+        let Some(parent) = self.parent(child) else {
+            return;
+        };
+        if let Ok(mut children) = self.get_component_mut::<Children>(parent) {
+            children.0.retain(|&entity| entity != child);
+        }
+    }
+
+    /// Computes the world transform from the current [`Transform`]s, independent of when the
+    /// [`crate::transform::GlobalTransform`]s were last propagated.
+    fn world_matrix(&self, entity: Entity) -> Affine3A {
+        let mut matrix = Affine3A::IDENTITY;
+        let mut current = Some(entity);
+        while let Some(entity) = current {
+            if let Ok(transform) = self.get_component::<Transform>(entity) {
+                matrix = transform.compute_affine() * matrix;
+            }
+            current = self.parent(entity);
+        }
+        matrix
+    }
+}
@@ -1,4 +1,21 @@
 mod archetype;
+pub mod borrow;
+pub mod bundle;
+pub mod change;
+mod column;
+pub mod command;
 pub mod component;
 pub mod entity;
+mod error;
+pub mod event;
+pub mod hierarchy;
+pub mod query;
+pub mod resource;
+pub mod scene;
+pub mod schedule;
+pub mod snapshot;
+pub mod system;
+pub mod transform;
 pub mod world;
+
+pub use error::Error;
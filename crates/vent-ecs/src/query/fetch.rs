@@ -0,0 +1,217 @@
+use std::cell::UnsafeCell;
+
+use crate::{
+    archetype::Archetype,
+    change::{ComponentTicks, Mut, SystemTicks},
+    component::Component,
+    entity::Entity,
+    world::World,
+};
+
+use super::Access;
+
+/// Types that can be fetched from an archetype row by a [`super::Query`].
+///
+/// Implemented for `&T`, `&mut T`, `Option<&T>`, `Option<&mut T>`, [`Entity`] and tuples of those.
+/// Components fetched mutably are returned as [`Mut`].
+///
+/// # Safety
+/// `access` must report every component `fetch` reads or writes.
+pub unsafe trait QueryData {
+    type Item<'w>;
+    /// Per archetype state, usually pointers to the component columns.
+    type Fetch;
+
+    /// Collects the components this query requires, reads and writes.
+    fn access(world: &World, access: &mut Access);
+
+    /// Prepares fetching from an archetype matched by [`QueryData::access`], components
+    /// written through a fetched [`Mut`] are marked as changed at `ticks.this_run()`.
+    ///
+    /// # Safety
+    /// The borrows reported by [`QueryData::access`] must be held.
+    unsafe fn prepare(world: &World, archetype: &Archetype, ticks: SystemTicks) -> Self::Fetch;
+
+    /// Fetches the item of a row.
+    ///
+    /// # Safety
+    /// `row` must be in bounds of the prepared archetype and the borrows must still be held.
+    unsafe fn fetch<'w>(fetch: &Self::Fetch, row: usize) -> Self::Item<'w>;
+}
+
+/// Marker for queries that never write, these can be iterated through a shared reference.
+pub trait ReadOnlyQueryData: QueryData {}
+
+/// Column pointers of a component fetched mutably.
+pub struct WriteFetch<T> {
+    components: *mut T,
+    ticks: *const UnsafeCell<ComponentTicks>,
+    tick: u32,
+}
+
+impl<T: Component> WriteFetch<T> {
+    /// # Safety
+    /// The archetype must contain the component and it must be borrowed mutably.
+    unsafe fn new(archetype: &Archetype, id: usize, ticks: SystemTicks) -> Option<Self> {
+        Some(Self {
+            components: archetype.column_ptr(id)?.cast::<T>(),
+            ticks: archetype.ticks_ptr(id)?,
+            tick: ticks.this_run(),
+        })
+    }
+
+    /// Wraps the component in a [`Mut`], which marks it as changed once it is written.
+    unsafe fn fetch<'w>(&self, row: usize) -> Mut<'w, T> {
+        Mut::new(
+            &mut *self.components.add(row),
+            &mut *(*self.ticks.add(row)).get(),
+            self.tick,
+        )
+    }
+}
+
+unsafe impl<T: Component> QueryData for &T {
+    type Item<'w> = &'w T;
+    type Fetch = *const T;
+
+    fn access(world: &World, access: &mut Access) {
+        match world.component_id::<T>() {
+            Some(id) => {
+                access.required.push(id);
+                access.reads.push(id);
+            }
+            None => access.unmatched = true,
+        }
+    }
+
+    unsafe fn prepare(world: &World, archetype: &Archetype, _ticks: SystemTicks) -> Self::Fetch {
+        let id = world.component_id::<T>().unwrap();
+        archetype.column_ptr(id).unwrap().cast_const().cast::<T>()
+    }
+
+    unsafe fn fetch<'w>(fetch: &Self::Fetch, row: usize) -> Self::Item<'w> {
+        &*fetch.add(row)
+    }
+}
+
+impl<T: Component> ReadOnlyQueryData for &T {}
+
+unsafe impl<T: Component> QueryData for &mut T {
+    type Item<'w> = Mut<'w, T>;
+    type Fetch = WriteFetch<T>;
+
+    fn access(world: &World, access: &mut Access) {
+        match world.component_id::<T>() {
+            Some(id) => {
+                access.required.push(id);
+                access.writes.push(id);
+            }
+            None => access.unmatched = true,
+        }
+    }
+
+    unsafe fn prepare(world: &World, archetype: &Archetype, ticks: SystemTicks) -> Self::Fetch {
+        let id = world.component_id::<T>().unwrap();
+        WriteFetch::new(archetype, id, ticks).unwrap()
+    }
+
+    unsafe fn fetch<'w>(fetch: &Self::Fetch, row: usize) -> Self::Item<'w> {
+        fetch.fetch(row)
+    }
+}
+
+unsafe impl<T: Component> QueryData for Option<&T> {
+    type Item<'w> = Option<&'w T>;
+    type Fetch = Option<*const T>;
+
+    fn access(world: &World, access: &mut Access) {
+        if let Some(id) = world.component_id::<T>() {
+            access.reads.push(id);
+        }
+    }
+
+    unsafe fn prepare(world: &World, archetype: &Archetype, _ticks: SystemTicks) -> Self::Fetch {
+        let id = world.component_id::<T>()?;
+        archetype
+            .column_ptr(id)
+            .map(|column| column.cast_const().cast::<T>())
+    }
+
+    unsafe fn fetch<'w>(fetch: &Self::Fetch, row: usize) -> Self::Item<'w> {
+        fetch.map(|column| &*column.add(row))
+    }
+}
+
+impl<T: Component> ReadOnlyQueryData for Option<&T> {}
+
+unsafe impl<T: Component> QueryData for Option<&mut T> {
+    type Item<'w> = Option<Mut<'w, T>>;
+    type Fetch = Option<WriteFetch<T>>;
+
+    fn access(world: &World, access: &mut Access) {
+        if let Some(id) = world.component_id::<T>() {
+            access.writes.push(id);
+        }
+    }
+
+    unsafe fn prepare(world: &World, archetype: &Archetype, ticks: SystemTicks) -> Self::Fetch {
+        let id = world.component_id::<T>()?;
+        WriteFetch::new(archetype, id, ticks)
+    }
+
+    unsafe fn fetch<'w>(fetch: &Self::Fetch, row: usize) -> Self::Item<'w> {
+        fetch.as_ref().map(|fetch| fetch.fetch(row))
+    }
+}
+
+unsafe impl QueryData for Entity {
+    type Item<'w> = Entity;
+    type Fetch = *const Entity;
+
+    fn access(_world: &World, _access: &mut Access) {}
+
+    unsafe fn prepare(_world: &World, archetype: &Archetype, _ticks: SystemTicks) -> Self::Fetch {
+        archetype.entities().as_ptr()
+    }
+
+    unsafe fn fetch<'w>(fetch: &Self::Fetch, row: usize) -> Self::Item<'w> {
+        *fetch.add(row)
+    }
+}
+
+impl ReadOnlyQueryData for Entity {}
+
+macro_rules! impl_query_data_tuple {
+    ($($name:ident),*) => {
+        #[allow(non_snake_case, unused_variables, clippy::unused_unit)]
+        unsafe impl<$($name: QueryData),*> QueryData for ($($name,)*) {
+            type Item<'w> = ($($name::Item<'w>,)*);
+            type Fetch = ($($name::Fetch,)*);
+
+            fn access(world: &World, access: &mut Access) {
+                $($name::access(world, access);)*
+            }
+
+            unsafe fn prepare(world: &World, archetype: &Archetype, ticks: SystemTicks) -> Self::Fetch {
+                ($($name::prepare(world, archetype, ticks),)*)
+            }
+
+            unsafe fn fetch<'w>(fetch: &Self::Fetch, row: usize) -> Self::Item<'w> {
+                let ($($name,)*) = fetch;
+                ($($name::fetch($name, row),)*)
+            }
+        }
+
+        impl<$($name: ReadOnlyQueryData),*> ReadOnlyQueryData for ($($name,)*) {}
+    };
+}
+
+impl_query_data_tuple!();
+impl_query_data_tuple!(A);
+impl_query_data_tuple!(A, B);
+impl_query_data_tuple!(A, B, C);
+impl_query_data_tuple!(A, B, C, D);
+impl_query_data_tuple!(A, B, C, D, E);
+impl_query_data_tuple!(A, B, C, D, E, F);
+impl_query_data_tuple!(A, B, C, D, E, F, G);
+impl_query_data_tuple!(A, B, C, D, E, F, G, H);
@@ -0,0 +1,185 @@
+use std::{cell::UnsafeCell, marker::PhantomData};
+
+use crate::{
+    archetype::Archetype,
+    change::{ComponentTicks, SystemTicks},
+    component::Component,
+    world::World,
+};
+
+use super::Access;
+
+/// Restricts which entities a [`super::Query`] visits without fetching any data.
+///
+/// # Safety
+/// `access` must report every component `matches` reads.
+pub unsafe trait QueryFilter {
+    /// Per archetype state of filters which test single rows.
+    type Fetch;
+
+    fn access(world: &World, access: &mut Access);
+
+    /// Prepares testing the rows of an archetype matched by [`QueryFilter::access`].
+    ///
+    /// # Safety
+    /// The borrows reported by [`QueryFilter::access`] must be held.
+    unsafe fn prepare(world: &World, archetype: &Archetype, ticks: SystemTicks) -> Self::Fetch;
+
+    /// Returns `true` if the row passes the filter.
+    ///
+    /// # Safety
+    /// `row` must be in bounds of the prepared archetype and the borrows must still be held.
+    unsafe fn matches(fetch: &Self::Fetch, row: usize) -> bool;
+}
+
+/// Only matches entities that have the component `T`.
+pub struct With<T>(PhantomData<T>);
+
+/// Only matches entities that do not have the component `T`.
+pub struct Without<T>(PhantomData<T>);
+
+/// Only matches entities whose component `T` was added since the system last ran, or for
+/// queries made directly on the world since the trackers were last cleared.
+pub struct Added<T>(PhantomData<T>);
+
+/// Only matches entities whose component `T` was added or written since the system last ran,
+/// or for queries made directly on the world since the trackers were last cleared.
+///
+/// Changes a system makes itself are not reported to it on its next run.
+pub struct Changed<T>(PhantomData<T>);
+
+unsafe impl<T: Component> QueryFilter for With<T> {
+    type Fetch = ();
+
+    fn access(world: &World, access: &mut Access) {
+        match world.component_id::<T>() {
+            Some(id) => access.required.push(id),
+            None => access.unmatched = true,
+        }
+    }
+
+    unsafe fn prepare(_world: &World, _archetype: &Archetype, _ticks: SystemTicks) -> Self::Fetch {}
+
+    #[inline]
+    unsafe fn matches(_fetch: &Self::Fetch, _row: usize) -> bool {
+        true
+    }
+}
+
+unsafe impl<T: Component> QueryFilter for Without<T> {
+    type Fetch = ();
+
+    fn access(world: &World, access: &mut Access) {
+        if let Some(id) = world.component_id::<T>() {
+            access.excluded.push(id);
+        }
+    }
+
+    unsafe fn prepare(_world: &World, _archetype: &Archetype, _ticks: SystemTicks) -> Self::Fetch {}
+
+    #[inline]
+    unsafe fn matches(_fetch: &Self::Fetch, _row: usize) -> bool {
+        true
+    }
+}
+
+/// Change ticks of a component column and the ticks of the running system.
+pub struct TicksFetch {
+    ticks: *const UnsafeCell<ComponentTicks>,
+    system_ticks: SystemTicks,
+}
+
+impl TicksFetch {
+    /// Requires the component and reads its ticks, unless the query already writes it.
+    fn access<T: Component>(world: &World, access: &mut Access) {
+        match world.component_id::<T>() {
+            Some(id) => {
+                access.required.push(id);
+                if !access.writes.contains(&id) {
+                    access.reads.push(id);
+                }
+            }
+            None => access.unmatched = true,
+        }
+    }
+
+    unsafe fn new<T: Component>(world: &World, archetype: &Archetype, ticks: SystemTicks) -> Self {
+        let id = world.component_id::<T>().unwrap();
+        Self {
+            ticks: archetype.ticks_ptr(id).unwrap(),
+            system_ticks: ticks,
+        }
+    }
+
+    #[inline]
+    unsafe fn get(&self, row: usize) -> ComponentTicks {
+        *(*self.ticks.add(row)).get()
+    }
+}
+
+unsafe impl<T: Component> QueryFilter for Added<T> {
+    type Fetch = TicksFetch;
+
+    fn access(world: &World, access: &mut Access) {
+        TicksFetch::access::<T>(world, access);
+    }
+
+    unsafe fn prepare(world: &World, archetype: &Archetype, ticks: SystemTicks) -> Self::Fetch {
+        TicksFetch::new::<T>(world, archetype, ticks)
+    }
+
+    #[inline]
+    unsafe fn matches(fetch: &Self::Fetch, row: usize) -> bool {
+        fetch.system_ticks.is_newer(fetch.get(row).added)
+    }
+}
+
+unsafe impl<T: Component> QueryFilter for Changed<T> {
+    type Fetch = TicksFetch;
+
+    fn access(world: &World, access: &mut Access) {
+        TicksFetch::access::<T>(world, access);
+    }
+
+    unsafe fn prepare(world: &World, archetype: &Archetype, ticks: SystemTicks) -> Self::Fetch {
+        TicksFetch::new::<T>(world, archetype, ticks)
+    }
+
+    #[inline]
+    unsafe fn matches(fetch: &Self::Fetch, row: usize) -> bool {
+        fetch.system_ticks.is_newer(fetch.get(row).changed)
+    }
+}
+
+macro_rules! impl_query_filter_tuple {
+    ($($name:ident),*) => {
+        #[allow(non_snake_case, unused_variables, clippy::unused_unit)]
+        unsafe impl<$($name: QueryFilter),*> QueryFilter for ($($name,)*) {
+            type Fetch = ($($name::Fetch,)*);
+
+            fn access(world: &World, access: &mut Access) {
+                $($name::access(world, access);)*
+            }
+
+            unsafe fn prepare(world: &World, archetype: &Archetype, ticks: SystemTicks) -> Self::Fetch {
+                ($($name::prepare(world, archetype, ticks),)*)
+            }
+
+            #[inline]
+            unsafe fn matches(fetch: &Self::Fetch, row: usize) -> bool {
+                let ($($name,)*) = fetch;
+                true $(&& $name::matches($name, row))*
+            }
+        }
+    };
+}
+
+impl_query_filter_tuple!();
+impl_query_filter_tuple!(A);
+impl_query_filter_tuple!(A, B);
+impl_query_filter_tuple!(A, B, C);
+impl_query_filter_tuple!(A, B, C, D);
+impl_query_filter_tuple!(A, B, C, D, E);
+impl_query_filter_tuple!(A, B, C, D, E, F);
+impl_query_filter_tuple!(A, B, C, D, E, F, G);
+impl_query_filter_tuple!(A, B, C, D, E, F, G, H);
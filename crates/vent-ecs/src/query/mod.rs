@@ -0,0 +1,228 @@
+use std::marker::PhantomData;
+
+use crate::{change::SystemTicks, entity::Entity, error::Error, world::World};
+
+mod fetch;
+mod filter;
+
+pub use fetch::{QueryData, ReadOnlyQueryData};
+pub use filter::{Added, Changed, QueryFilter, TicksFetch, With, Without};
+
+/// The components a query touches, collected before it runs.
+///
+/// `required` and `excluded` decide which archetypes match, `reads` and `writes`
+/// decide which borrows have to be taken. Systems also report the resources they use.
+#[derive(Default, Clone)]
+pub struct Access {
+    pub required: Vec<usize>,
+    pub excluded: Vec<usize>,
+    pub reads: Vec<usize>,
+    pub writes: Vec<usize>,
+    pub resource_reads: Vec<usize>,
+    pub resource_writes: Vec<usize>,
+    /// Set when a required component was never registered, so nothing can match.
+    pub unmatched: bool,
+}
+
+impl Access {
+    /// Returns the first component that is written while also being read or written elsewhere.
+    pub fn self_conflict(&self) -> Option<usize> {
+        self.writes.iter().enumerate().find_map(|(i, id)| {
+            (self.reads.contains(id) || self.writes[i + 1..].contains(id)).then_some(*id)
+        })
+    }
+
+    /// Returns `true` if both accesses can be held at the same time, i.e. neither writes
+    /// a component or resource the other one reads or writes.
+    pub fn is_compatible(&self, other: &Access) -> bool {
+        let conflicts = |writes: &[usize], reads: &[usize], other_writes: &[usize]| {
+            writes
+                .iter()
+                .any(|id| reads.contains(id) || other_writes.contains(id))
+        };
+        !conflicts(&self.writes, &other.reads, &other.writes)
+            && !conflicts(&other.writes, &self.reads, &self.writes)
+            && !conflicts(
+                &self.resource_writes,
+                &other.resource_reads,
+                &other.resource_writes,
+            )
+            && !conflicts(
+                &other.resource_writes,
+                &self.resource_reads,
+                &self.resource_writes,
+            )
+    }
+}
+
+/// Iterates all entities whose components match `Q` and the filter `F`.
+///
+/// ```ignore
+/// let mut query = world.query_filtered::<(&Transform, &mut Velocity), (With<Player>, Without<Frozen>)>()?;
+/// for (transform, mut velocity) in query.iter_mut() {
+///     // ...
+/// }
+/// ```
+///
+/// The query holds the borrows of all components it accesses until it is dropped.
+pub struct Query<'w, Q: QueryData, F: QueryFilter = ()> {
+    world: &'w World,
+    access: Access,
+    archetypes: Vec<usize>,
+    ticks: SystemTicks,
+    _marker: PhantomData<(Q, F)>,
+}
+
+impl<'w, Q: QueryData, F: QueryFilter> Query<'w, Q, F> {
+    pub(crate) fn new(
+        world: &'w World,
+        access: Access,
+        archetypes: Vec<usize>,
+        ticks: SystemTicks,
+    ) -> Self {
+        Self {
+            world,
+            access,
+            archetypes,
+            ticks,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator yielding the query items.
+    pub fn iter_mut(&mut self) -> QueryIter<'_, Q, F> {
+        QueryIter::new(self.world, &self.archetypes, self.ticks)
+    }
+
+    /// Returns an iterator yielding the query items, only available for read only queries.
+    pub fn iter(&self) -> QueryIter<'_, Q, F>
+    where
+        Q: ReadOnlyQueryData,
+    {
+        QueryIter::new(self.world, &self.archetypes, self.ticks)
+    }
+
+    /// Returns the query item of a single entity, only available for read only queries.
+    pub fn get(&self, entity: Entity) -> Result<Q::Item<'_>, Error>
+    where
+        Q: ReadOnlyQueryData,
+    {
+        // SAFETY: read only items can be shared
+        unsafe { self.get_unchecked(entity) }
+    }
+
+    /// Returns the query item of a single entity.
+    pub fn get_mut(&mut self, entity: Entity) -> Result<Q::Item<'_>, Error> {
+        // SAFETY: the item borrows the query mutably
+        unsafe { self.get_unchecked(entity) }
+    }
+
+    /// # Safety
+    /// Mutable items must not alias.
+    unsafe fn get_unchecked(&self, entity: Entity) -> Result<Q::Item<'_>, Error> {
+        let location = self.world.location(entity)?;
+        if !self.archetypes.contains(&location.archetype) {
+            return Err(Error::QueryMismatch(entity));
+        }
+        let archetype = self.world.archetype(location.archetype);
+        // SAFETY: the query holds the borrows and the row belongs to a matched archetype
+        let filter = F::prepare(self.world, archetype, self.ticks);
+        if !F::matches(&filter, location.row) {
+            return Err(Error::QueryMismatch(entity));
+        }
+        let fetch = Q::prepare(self.world, archetype, self.ticks);
+        Ok(Q::fetch(&fetch, location.row))
+    }
+
+    /// Returns the number of entities matching the query.
+    pub fn len(&self) -> usize {
+        self.archetypes
+            .iter()
+            .map(|&index| {
+                let archetype = self.world.archetype(index);
+                // SAFETY: the query holds the borrows and all rows are in bounds
+                unsafe {
+                    let filter = F::prepare(self.world, archetype, self.ticks);
+                    (0..archetype.len())
+                        .filter(|&row| F::matches(&filter, row))
+                        .count()
+                }
+            })
+            .sum()
+    }
+
+    /// Returns `true` if no entity matches the query.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<Q: QueryData, F: QueryFilter> Drop for Query<'_, Q, F> {
+    fn drop(&mut self) {
+        self.world.release_access(&self.access);
+    }
+}
+
+impl<'q, Q: QueryData, F: QueryFilter> IntoIterator for &'q mut Query<'_, Q, F> {
+    type Item = Q::Item<'q>;
+    type IntoIter = QueryIter<'q, Q, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Iterator over the items of a [`Query`], walking the matched archetypes row by row and
+/// skipping rows rejected by the filter.
+pub struct QueryIter<'q, Q: QueryData, F: QueryFilter = ()> {
+    world: &'q World,
+    archetypes: std::slice::Iter<'q, usize>,
+    ticks: SystemTicks,
+    fetch: Option<(Q::Fetch, F::Fetch)>,
+    row: usize,
+    len: usize,
+}
+
+impl<'q, Q: QueryData, F: QueryFilter> QueryIter<'q, Q, F> {
+    fn new(world: &'q World, archetypes: &'q [usize], ticks: SystemTicks) -> Self {
+        Self {
+            world,
+            archetypes: archetypes.iter(),
+            ticks,
+            fetch: None,
+            row: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<'q, Q: QueryData, F: QueryFilter> Iterator for QueryIter<'q, Q, F> {
+    type Item = Q::Item<'q>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((fetch, filter)) = &self.fetch {
+                while self.row < self.len {
+                    let row = self.row;
+                    self.row += 1;
+                    // SAFETY: the owning query holds the borrows and `row` is in bounds
+                    unsafe {
+                        if F::matches(filter, row) {
+                            return Some(Q::fetch(fetch, row));
+                        }
+                    }
+                }
+            }
+            let archetype = self.world.archetype(*self.archetypes.next()?);
+            // SAFETY: the owning query holds the borrows
+            self.fetch = Some(unsafe {
+                (
+                    Q::prepare(self.world, archetype, self.ticks),
+                    F::prepare(self.world, archetype, self.ticks),
+                )
+            });
+            self.row = 0;
+            self.len = archetype.len();
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use std::{any::Any, cell::UnsafeCell};
+
+use crate::borrow::{BorrowFlag, Ref, RefMut};
+
+/// The `Resource` trait represents a global singleton stored in the world,
+/// e.g. the delta time or the input state.
+pub trait Resource: Any + Send + Sync + 'static {}
+
+/// A shared borrow of a resource, usable as a system parameter.
+pub type Res<'w, T> = Ref<'w, T>;
+
+/// An exclusive borrow of a resource, usable as a system parameter.
+pub type ResMut<'w, T> = RefMut<'w, T>;
+
+/// Storage slot of a resource type, kept after removal so the resource ID stays valid.
+pub(crate) struct ResourceData {
+    pub name: &'static str,
+    pub value: Option<Box<UnsafeCell<dyn Any + Send + Sync>>>,
+    pub borrow: BorrowFlag,
+}
+
+// SAFETY: the value is only accessed through the borrow flag or with exclusive access to the world
+unsafe impl Sync for ResourceData {}
+
+impl ResourceData {
+    pub fn new<T: Resource>() -> Self {
+        Self {
+            name: std::any::type_name::<T>(),
+            value: None,
+            borrow: BorrowFlag::default(),
+        }
+    }
+
+    /// # Safety
+    /// The caller must hold a shared borrow of the resource.
+    pub unsafe fn get<T: Resource>(&self) -> Option<&T> {
+        (*self.value.as_ref()?.get()).downcast_ref::<T>()
+    }
+
+    /// # Safety
+    /// The caller must hold an exclusive borrow of the resource.
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn get_mut<T: Resource>(&self) -> Option<&mut T> {
+        (*self.value.as_ref()?.get()).downcast_mut::<T>()
+    }
+}
@@ -0,0 +1,387 @@
+use std::{
+    any::TypeId,
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    component::Component,
+    entity::Entity,
+    error::Error,
+    hierarchy::{Children, Parent},
+    transform::{GlobalTransform, Transform},
+    world::World,
+};
+
+/// Errors returned while saving or loading a [`DynamicScene`].
+#[derive(Debug)]
+pub enum SceneError {
+    /// The scene contains a component whose name was never registered.
+    UnknownComponent(String),
+    /// The scene contains the same entity more than once.
+    DuplicateEntity(Entity),
+    /// A component or the scene file could not be (de)serialized.
+    Json(serde_json::Error),
+    Io(std::io::Error),
+    World(Error),
+}
+
+impl fmt::Display for SceneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SceneError::UnknownComponent(name) => {
+                write!(f, "Component not registered for scenes: {}", name)
+            }
+            SceneError::DuplicateEntity(entity) => {
+                write!(f, "Entity {} is stored more than once", entity)
+            }
+            SceneError::Json(err) => write!(f, "Failed to (de)serialize scene: {}", err),
+            SceneError::Io(err) => write!(f, "Failed to access scene file: {}", err),
+            SceneError::World(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SceneError {}
+
+impl From<serde_json::Error> for SceneError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Json(err)
+    }
+}
+
+impl From<std::io::Error> for SceneError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<Error> for SceneError {
+    fn from(err: Error) -> Self {
+        Self::World(err)
+    }
+}
+
+/// Maps the entities stored in a scene to the entities they were loaded as.
+#[derive(Default)]
+pub struct EntityMap {
+    map: HashMap<Entity, Entity>,
+}
+
+impl EntityMap {
+    /// Returns the loaded entity, entities outside of the scene are kept as they are.
+    pub fn get(&self, entity: Entity) -> Entity {
+        self.map.get(&entity).copied().unwrap_or(entity)
+    }
+
+    pub fn insert(&mut self, from: Entity, to: Entity) {
+        self.map.insert(from, to);
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+/// Implemented by components which reference other entities, so the references can be
+/// remapped when a scene is loaded.
+pub trait MapEntities {
+    fn map_entities(&mut self, map: &EntityMap);
+}
+
+type SerializeFn = fn(&World, Entity) -> Result<Option<Value>, SceneError>;
+type DeserializeFn = fn(&mut World, Entity, Value, &EntityMap) -> Result<(), SceneError>;
+
+struct ComponentRegistration {
+    name: &'static str,
+    serialize: SerializeFn,
+    deserialize: DeserializeFn,
+}
+
+/// The components that are saved to and loaded from scenes, keyed by a name given when
+/// registering them.
+///
+/// The name is stored in the scene files, so it has to stay the same when the type is
+/// renamed or moved, unlike [`std::any::type_name`]. Components not registered here are
+/// skipped when saving.
+pub struct SceneRegistry {
+    components: Vec<ComponentRegistration>,
+    type_ids: HashMap<TypeId, usize>,
+    names: HashMap<&'static str, usize>,
+}
+
+impl SceneRegistry {
+    /// Creates an empty registry, see [`SceneRegistry::default`] for one with the
+    /// built-in hierarchy and transform components.
+    pub fn new() -> Self {
+        Self {
+            components: Vec::new(),
+            type_ids: HashMap::new(),
+            names: HashMap::new(),
+        }
+    }
+
+    /// Registers a component under `name`, e.g. `Health`.
+    ///
+    /// Panics if the name or the component is already registered.
+    pub fn register<T: Component + Serialize + DeserializeOwned>(
+        &mut self,
+        name: &'static str,
+    ) -> &mut Self {
+        self.add::<T>(name, deserialize::<T>)
+    }
+
+    /// Registers a component which references other entities.
+    ///
+    /// Panics if the name or the component is already registered.
+    pub fn register_mapped<T: Component + Serialize + DeserializeOwned + MapEntities>(
+        &mut self,
+        name: &'static str,
+    ) -> &mut Self {
+        self.add::<T>(name, deserialize_mapped::<T>)
+    }
+
+    fn add<T: Component + Serialize>(
+        &mut self,
+        name: &'static str,
+        deserialize: DeserializeFn,
+    ) -> &mut Self {
+        // Either would make loading depend on the registration order
+        assert!(
+            !self.names.contains_key(name),
+            "Scene component name {} is already registered",
+            name
+        );
+        assert!(
+            !self.type_ids.contains_key(&TypeId::of::<T>()),
+            "Scene component {} is already registered",
+            std::any::type_name::<T>()
+        );
+        let index = self.components.len();
+        self.components.push(ComponentRegistration {
+            name,
+            serialize: serialize::<T>,
+            deserialize,
+        });
+        self.type_ids.insert(TypeId::of::<T>(), index);
+        self.names.insert(name, index);
+        self
+    }
+}
+
+impl Default for SceneRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry
+            .register_mapped::<Parent>("Parent")
+            .register_mapped::<Children>("Children")
+            .register::<Transform>("Transform")
+            .register::<GlobalTransform>("GlobalTransform");
+        registry
+    }
+}
+
+fn serialize<T: Component + Serialize>(
+    world: &World,
+    entity: Entity,
+) -> Result<Option<Value>, SceneError> {
+    match world.get_component::<T>(entity) {
+        Ok(component) => Ok(Some(serde_json::to_value(&*component)?)),
+        Err(Error::ComponentMissing { .. }) => Ok(None),
+        // A borrowed component must not silently go missing from the saved scene
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn deserialize<T: Component + DeserializeOwned>(
+    world: &mut World,
+    entity: Entity,
+    value: Value,
+    _map: &EntityMap,
+) -> Result<(), SceneError> {
+    let component: T = serde_json::from_value(value)?;
+    world.insert(entity, component)?;
+    Ok(())
+}
+
+fn deserialize_mapped<T: Component + DeserializeOwned + MapEntities>(
+    world: &mut World,
+    entity: Entity,
+    value: Value,
+    map: &EntityMap,
+) -> Result<(), SceneError> {
+    let mut component: T = serde_json::from_value(value)?;
+    component.map_entities(map);
+    world.insert(entity, component)?;
+    Ok(())
+}
+
+/// A single entity of a [`DynamicScene`].
+#[derive(Serialize, Deserialize)]
+pub struct DynamicEntity {
+    /// The entity when the scene was saved, references to it are remapped on load.
+    pub entity: Entity,
+    /// Serialized components keyed by their registered name.
+    pub components: BTreeMap<String, Value>,
+}
+
+/// The registered components of a set of entities in a human readable form, e.g.
+///
+/// ```json
+/// {
+///   "entities": [
+///     {
+///       "entity": 0,
+///       "components": {
+///         "Transform": { "translation": [0.0, 1.0, 0.0], "rotation": [0.0, 0.0, 0.0, 1.0], "scale": [1.0, 1.0, 1.0] }
+///       }
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Default, Serialize, Deserialize)]
+pub struct DynamicScene {
+    pub entities: Vec<DynamicEntity>,
+}
+
+impl DynamicScene {
+    /// Captures all entities of the world, only registered components are stored.
+    pub fn from_world(world: &World, registry: &SceneRegistry) -> Result<Self, SceneError> {
+        let mut entities: Vec<Entity> = world.iter_entities().copied().collect();
+        entities.sort_unstable();
+        let entities = entities
+            .into_iter()
+            .map(|entity| {
+                let mut components = BTreeMap::new();
+                for registration in &registry.components {
+                    if let Some(value) = (registration.serialize)(world, entity)? {
+                        components.insert(registration.name.to_string(), value);
+                    }
+                }
+                Ok(DynamicEntity { entity, components })
+            })
+            .collect::<Result<_, SceneError>>()?;
+        Ok(Self { entities })
+    }
+
+    /// Spawns all entities of the scene into the world, remapping entity references to the
+    /// newly spawned entities.
+    ///
+    /// A [`Parent`] outside of the scene has to exist in the world, the entity is added to its
+    /// [`Children`], e.g. to load a scene below an entity of the world.
+    ///
+    /// Returns which entity every scene entity was spawned as. On error no entity of the
+    /// scene is left in the world.
+    pub fn write_to_world(
+        &self,
+        world: &mut World,
+        registry: &SceneRegistry,
+    ) -> Result<EntityMap, SceneError> {
+        if let Some(name) = self
+            .entities
+            .iter()
+            .flat_map(|scene_entity| scene_entity.components.keys())
+            .find(|name| !registry.names.contains_key(name.as_str()))
+        {
+            return Err(SceneError::UnknownComponent(name.clone()));
+        }
+        let mut scene_entities = HashSet::new();
+        if let Some(scene_entity) = self
+            .entities
+            .iter()
+            .find(|scene_entity| !scene_entities.insert(scene_entity.entity))
+        {
+            return Err(SceneError::DuplicateEntity(scene_entity.entity));
+        }
+        // Checked before spawning, a missing parent could share its ID with a spawned entity
+        if let Some(&index) = registry.type_ids.get(&TypeId::of::<Parent>()) {
+            let name = registry.components[index].name;
+            for value in self
+                .entities
+                .iter()
+                .filter_map(|scene_entity| scene_entity.components.get(name))
+            {
+                let parent = serde_json::from_value::<Parent>(value.clone())?.get();
+                if !scene_entities.contains(&parent) && !world.is_alive(parent) {
+                    return Err(Error::EntityNotFound(parent).into());
+                }
+            }
+        }
+
+        // Entities can reference each other in any order, so all of them are spawned first
+        let mut map = EntityMap::default();
+        for scene_entity in &self.entities {
+            map.insert(scene_entity.entity, world.create_entity());
+        }
+        if let Err(err) = self.write_components(world, registry, &map) {
+            for scene_entity in &self.entities {
+                // The error which made the load fail is the one to report
+                if let Err(delete_err) = world.delete_entity(map.get(scene_entity.entity)) {
+                    log::warn!(
+                        "Failed to remove entity of failed scene load: {}",
+                        delete_err
+                    );
+                }
+            }
+            return Err(err);
+        }
+        Ok(map)
+    }
+
+    fn write_components(
+        &self,
+        world: &mut World,
+        registry: &SceneRegistry,
+        map: &EntityMap,
+    ) -> Result<(), SceneError> {
+        for scene_entity in &self.entities {
+            let entity = map.get(scene_entity.entity);
+            for (name, value) in &scene_entity.components {
+                let registration = &registry.components[registry.names[name.as_str()]];
+                (registration.deserialize)(world, entity, value.clone(), map)?;
+            }
+        }
+
+        // Parents in the scene list their children themselves
+        let spawned: HashSet<Entity> = map.map.values().copied().collect();
+        // In scene order, which is the order of the children
+        for scene_entity in &self.entities {
+            let entity = map.get(scene_entity.entity);
+            let Ok(parent) = world
+                .get_component::<Parent>(entity)
+                .map(|parent| parent.get())
+            else {
+                continue;
+            };
+            if !spawned.contains(&parent) {
+                world.attach(entity, parent)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the scene as pretty printed JSON.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), SceneError> {
+        let file = File::create(path.as_ref())?;
+        log::debug!("Saving scene {}", path.as_ref().display());
+        serde_json::to_writer_pretty(BufWriter::new(file), self)?;
+        Ok(())
+    }
+
+    /// Reads a scene previously written with [`DynamicScene::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, SceneError> {
+        log::debug!("Loading scene {}", path.as_ref().display());
+        let file = File::open(path.as_ref())?;
+        Ok(serde_json::from_reader(BufReader::new(file))?)
+    }
+}
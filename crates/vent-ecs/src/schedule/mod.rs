@@ -0,0 +1,307 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
+};
+
+use crate::{
+    error::Error,
+    query::Access,
+    system::{IntoSystem, System},
+    world::World,
+};
+
+/// The stages of a frame, run one after another in this order.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Stage {
+    PreUpdate,
+    Update,
+    PostUpdate,
+    /// Extracts the data the renderer needs for the frame.
+    RenderPrep,
+}
+
+impl Stage {
+    /// All stages in the order they run.
+    pub const ALL: [Stage; 4] = [
+        Stage::PreUpdate,
+        Stage::Update,
+        Stage::PostUpdate,
+        Stage::RenderPrep,
+    ];
+}
+
+/// A system together with its ordering constraints.
+pub struct SystemConfig {
+    system: Box<dyn System>,
+    label: Option<&'static str>,
+    before: Vec<&'static str>,
+    after: Vec<&'static str>,
+}
+
+impl SystemConfig {
+    /// Returns `true` if the system has to run before `other`.
+    fn runs_before(&self, other: &SystemConfig) -> bool {
+        let has_label = |config: &SystemConfig, labels: &[&'static str]| {
+            config.label.is_some_and(|label| labels.contains(&label))
+        };
+        has_label(other, &self.before) || has_label(self, &other.after)
+    }
+}
+
+/// Conversion into a [`SystemConfig`], adds the ordering constraints to systems.
+///
+/// ```ignore
+/// schedule.add_system(Stage::Update, input.label("input"));
+/// schedule.add_system(Stage::Update, movement.label("movement").after("input"));
+/// ```
+pub trait IntoSystemConfig<Marker>: Sized {
+    fn into_config(self) -> SystemConfig;
+
+    /// Names the system so other systems can be ordered relative to it.
+    ///
+    /// Multiple systems may share a label, constraints then apply to all of them.
+    fn label(self, label: &'static str) -> SystemConfig {
+        let mut config = self.into_config();
+        config.label = Some(label);
+        config
+    }
+
+    /// Runs the system before all systems with the given label in the same stage.
+    fn before(self, label: &'static str) -> SystemConfig {
+        let mut config = self.into_config();
+        config.before.push(label);
+        config
+    }
+
+    /// Runs the system after all systems with the given label in the same stage.
+    fn after(self, label: &'static str) -> SystemConfig {
+        let mut config = self.into_config();
+        config.after.push(label);
+        config
+    }
+}
+
+impl<Marker, S: IntoSystem<Marker>> IntoSystemConfig<Marker> for S {
+    fn into_config(self) -> SystemConfig {
+        SystemConfig {
+            system: Box::new(self.into_system()),
+            label: None,
+            before: Vec::new(),
+            after: Vec::new(),
+        }
+    }
+}
+
+impl IntoSystemConfig<()> for SystemConfig {
+    fn into_config(self) -> SystemConfig {
+        self
+    }
+}
+
+/// The systems of a single stage.
+#[derive(Default)]
+struct StageSystems {
+    systems: Vec<SystemConfig>,
+    /// Topological order of the `before`/`after` constraints, `None` after a system was added.
+    order: Option<Vec<usize>>,
+}
+
+impl StageSystems {
+    /// Sorts the systems by their ordering constraints, ties are broken by insertion order
+    /// so the result is deterministic.
+    fn sort(&self) -> Result<Vec<usize>, Error> {
+        let len = self.systems.len();
+        let mut dependencies = vec![0usize; len];
+        for (i, system) in self.systems.iter().enumerate() {
+            for (j, other) in self.systems.iter().enumerate() {
+                if i != j && system.runs_before(other) {
+                    dependencies[j] += 1;
+                }
+            }
+        }
+        let mut order = Vec::with_capacity(len);
+        let mut done = vec![false; len];
+        while order.len() < len {
+            let next = (0..len)
+                .find(|&i| !done[i] && dependencies[i] == 0)
+                .ok_or_else(|| {
+                    let system = (0..len).find(|&i| !done[i]).unwrap();
+                    Error::SystemCycle(self.systems[system].system.name())
+                })?;
+            done[next] = true;
+            order.push(next);
+            for (j, other) in self.systems.iter().enumerate() {
+                if j != next && self.systems[next].runs_before(other) {
+                    dependencies[j] -= 1;
+                }
+            }
+        }
+        Ok(order)
+    }
+}
+
+/// Runs systems stage by stage.
+///
+/// Inside a stage, systems whose component accesses do not conflict run in parallel on the
+/// schedule's thread pool. Conflicting systems run in the order of their `before`/`after`
+/// constraints and otherwise in the order they were added.
+pub struct Schedule {
+    stages: Vec<StageSystems>,
+    thread_pool: rayon::ThreadPool,
+}
+
+/// Shared state of a stage while its systems run.
+struct StageRun<'a> {
+    world: &'a World,
+    systems: Vec<Mutex<&'a mut dyn System>>,
+    /// Systems that have to wait for each system.
+    dependents: Vec<Vec<usize>>,
+    /// Number of systems each system still waits for.
+    remaining: Vec<AtomicUsize>,
+    /// The failing system added first and its error.
+    error: Mutex<Option<(usize, Error)>>,
+}
+
+impl Schedule {
+    /// Creates an empty schedule with a thread pool sized to the available cores.
+    pub fn new() -> Self {
+        Self::with_threads(0)
+    }
+
+    /// Creates an empty schedule running on `threads` worker threads, `0` picks the number
+    /// of available cores.
+    pub fn with_threads(threads: usize) -> Self {
+        let thread_pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .thread_name(|index| format!("vent-ecs-system-{index}"))
+            .build()
+            .expect("Failed to create system thread pool");
+        Self {
+            stages: Stage::ALL.iter().map(|_| StageSystems::default()).collect(),
+            thread_pool,
+        }
+    }
+
+    /// Adds a system to a stage.
+    pub fn add_system<M>(&mut self, stage: Stage, system: impl IntoSystemConfig<M>) -> &mut Self {
+        let stage = &mut self.stages[stage as usize];
+        stage.systems.push(system.into_config());
+        stage.order = None;
+        self
+    }
+
+    /// Runs all stages once, commands recorded by the systems are applied after each stage.
+    ///
+    /// If systems fail, the remaining systems of the stage still run but later stages are
+    /// skipped. Systems run in parallel, so the error of the failing system that was added to
+    /// the stage first is returned regardless of which one finished first.
+    pub fn run(&mut self, world: &mut World) -> Result<(), Error> {
+        for stage in Stage::ALL {
+            self.run_stage(stage, world)?;
+        }
+        world.check_change_ticks();
+        let change_tick = world.change_tick();
+        for stage in &mut self.stages {
+            for config in &mut stage.systems {
+                config.system.check_change_ticks(change_tick);
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the systems of a single stage, then applies the commands they recorded.
+    pub fn run_stage(&mut self, stage: Stage, world: &mut World) -> Result<(), Error> {
+        let result = self.run_systems(stage, world);
+        let flushed = world.flush_commands();
+        result.and(flushed)
+    }
+
+    fn run_systems(&mut self, stage: Stage, world: &World) -> Result<(), Error> {
+        let stage = &mut self.stages[stage as usize];
+        if stage.systems.is_empty() {
+            return Ok(());
+        }
+        let order = match stage.order.take() {
+            Some(order) => order,
+            None => stage.sort()?,
+        };
+
+        // Accesses are collected on every run, components registered since the last run
+        // may add conflicts
+        let accesses: Vec<Access> = stage
+            .systems
+            .iter()
+            .map(|config| {
+                let mut access = Access::default();
+                config.system.access(world, &mut access);
+                access
+            })
+            .collect();
+
+        let len = stage.systems.len();
+        let mut dependents = vec![Vec::new(); len];
+        let mut remaining = vec![0; len];
+        for (position, &i) in order.iter().enumerate() {
+            for &j in &order[position + 1..] {
+                if stage.systems[i].runs_before(&stage.systems[j])
+                    || stage.systems[j].runs_before(&stage.systems[i])
+                    || !accesses[i].is_compatible(&accesses[j])
+                {
+                    dependents[i].push(j);
+                    remaining[j] += 1;
+                }
+            }
+        }
+
+        let roots: Vec<usize> = order
+            .iter()
+            .copied()
+            .filter(|&i| remaining[i] == 0)
+            .collect();
+        let run = StageRun {
+            world,
+            systems: stage
+                .systems
+                .iter_mut()
+                .map(|config| Mutex::new(&mut *config.system))
+                .collect(),
+            dependents,
+            remaining: remaining.into_iter().map(AtomicUsize::new).collect(),
+            error: Mutex::new(None),
+        };
+        self.thread_pool.scope(|scope| {
+            for root in roots {
+                let run = &run;
+                scope.spawn(move |scope| run.run_system(scope, root));
+            }
+        });
+        let error = run.error.into_inner().unwrap();
+
+        stage.order = Some(order);
+        error.map_or(Ok(()), |(_, err)| Err(err))
+    }
+}
+
+impl<'a> StageRun<'a> {
+    /// Runs a system and spawns every dependent system that has no other system left to wait for.
+    fn run_system<'s>(&'s self, scope: &rayon::Scope<'s>, index: usize) {
+        let result = self.systems[index].lock().unwrap().run(self.world);
+        if let Err(err) = result {
+            let mut error = self.error.lock().unwrap();
+            if error.is_none_or(|(first, _)| index < first) {
+                *error = Some((index, err));
+            }
+        }
+        for &dependent in &self.dependents[index] {
+            if self.remaining[dependent].fetch_sub(1, Ordering::AcqRel) == 1 {
+                scope.spawn(move |scope| self.run_system(scope, dependent));
+            }
+        }
+    }
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use crate::{
+    column::Column,
+    entity::{Entity, EntityMeta},
+};
+
+/// A copy of the world state at one point in time, created by [`crate::world::World::snapshot`]
+/// and applied with [`crate::world::World::restore`].
+///
+/// Only components registered through [`crate::world::World::register_snapshot`] are stored,
+/// resources are not part of a snapshot. Cloning a snapshot is cheap, the component data is
+/// shared.
+#[derive(Clone)]
+pub struct WorldSnapshot {
+    pub(crate) entities: Vec<EntityMeta>,
+    pub(crate) free_entities: Vec<u32>,
+    pub(crate) archetypes: Vec<ArchetypeSnapshot>,
+    pub(crate) change_tick: u32,
+}
+
+impl WorldSnapshot {
+    /// Returns the change tick of the world when the snapshot was taken.
+    pub fn change_tick(&self) -> u32 {
+        self.change_tick
+    }
+
+    /// Returns the number of entities in the snapshot.
+    pub fn len(&self) -> usize {
+        self.archetypes
+            .iter()
+            .map(|archetype| archetype.entities.len())
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The cloneable columns of an archetype, shared with later snapshots as long as the
+/// archetype does not change.
+#[derive(Clone)]
+pub(crate) struct ArchetypeSnapshot {
+    /// [`crate::archetype::Archetype::version`] when the snapshot was taken.
+    pub version: u64,
+    pub entities: Arc<[Entity]>,
+    pub columns: Vec<ColumnSnapshot>,
+}
+
+#[derive(Clone)]
+pub(crate) struct ColumnSnapshot {
+    pub component_id: usize,
+    /// How often the component was borrowed mutably by queries when the snapshot was taken.
+    pub write_version: u64,
+    pub clone: unsafe fn(*const u8, *mut u8),
+    pub column: Arc<Column>,
+}
@@ -0,0 +1,280 @@
+use std::marker::PhantomData;
+
+use crate::{
+    change::{RemovedComponents, SystemTicks, MAX_CHANGE_AGE},
+    command::Commands,
+    component::Component,
+    error::Error,
+    query::{Access, Query, QueryData, QueryFilter},
+    resource::{Res, ResMut, Resource},
+    world::World,
+};
+
+/// A unit of game logic run by a [`crate::schedule::Schedule`].
+///
+/// Usually created from a plain function through [`IntoSystem`]:
+///
+/// ```ignore
+/// fn movement(mut query: Query<(&mut Transform, &Velocity)>, time: Res<DeltaTime>) {
+///     for (transform, velocity) in query.iter_mut() {
+///         // ...
+///     }
+/// }
+/// ```
+pub trait System: Send + Sync + 'static {
+    /// Name of the system, used in error messages.
+    fn name(&self) -> &'static str;
+
+    /// Collects the components the system reads and writes.
+    ///
+    /// The schedule runs systems in parallel as long as their accesses are compatible.
+    fn access(&self, world: &World, access: &mut Access);
+
+    /// Runs the system once.
+    fn run(&mut self, world: &World) -> Result<(), Error>;
+
+    /// Clamps the tick the system last ran at if it is too old to be compared with
+    /// `change_tick`, see [`World::check_change_ticks`].
+    fn check_change_ticks(&mut self, _change_tick: u32) {}
+}
+
+/// A parameter of a function system, fetched from the world every time the system runs.
+pub trait SystemParam {
+    /// Data the system keeps between runs, e.g. the cursor of an [`EventReader`].
+    type State: Default + Send + Sync + 'static;
+    type Item<'w>;
+
+    /// Collects the components and resources the parameter reads and writes.
+    fn access(world: &World, access: &mut Access);
+
+    /// Fetches the parameter, failing if it is missing or borrowed in a conflicting way.
+    ///
+    /// `ticks` are the tick the system last ran at and the tick of this run, used for change
+    /// detection.
+    fn fetch<'w>(
+        world: &'w World,
+        state: &'w mut Self::State,
+        ticks: SystemTicks,
+    ) -> Result<Self::Item<'w>, Error>;
+}
+
+impl<Q: QueryData, F: QueryFilter> SystemParam for Query<'_, Q, F> {
+    type State = ();
+    type Item<'w> = Query<'w, Q, F>;
+
+    fn access(world: &World, access: &mut Access) {
+        Q::access(world, access);
+        F::access(world, access);
+    }
+
+    fn fetch<'w>(
+        world: &'w World,
+        _state: &'w mut Self::State,
+        ticks: SystemTicks,
+    ) -> Result<Self::Item<'w>, Error> {
+        world.query_with_ticks::<Q, F>(ticks)
+    }
+}
+
+impl<T: Resource> SystemParam for Res<'_, T> {
+    type State = ();
+    type Item<'w> = Res<'w, T>;
+
+    fn access(world: &World, access: &mut Access) {
+        if let Some(id) = world.resource_id::<T>() {
+            access.resource_reads.push(id);
+        }
+    }
+
+    fn fetch<'w>(
+        world: &'w World,
+        _state: &'w mut Self::State,
+        _ticks: SystemTicks,
+    ) -> Result<Self::Item<'w>, Error> {
+        world.resource::<T>()
+    }
+}
+
+impl<T: Resource> SystemParam for ResMut<'_, T> {
+    type State = ();
+    type Item<'w> = ResMut<'w, T>;
+
+    fn access(world: &World, access: &mut Access) {
+        if let Some(id) = world.resource_id::<T>() {
+            access.resource_writes.push(id);
+        }
+    }
+
+    fn fetch<'w>(
+        world: &'w World,
+        _state: &'w mut Self::State,
+        _ticks: SystemTicks,
+    ) -> Result<Self::Item<'w>, Error> {
+        world.resource_mut::<T>()
+    }
+}
+
+impl SystemParam for Commands<'_> {
+    type State = ();
+    type Item<'w> = Commands<'w>;
+
+    fn access(_world: &World, _access: &mut Access) {}
+
+    fn fetch<'w>(
+        world: &'w World,
+        _state: &'w mut Self::State,
+        _ticks: SystemTicks,
+    ) -> Result<Self::Item<'w>, Error> {
+        Ok(world.commands())
+    }
+}
+
+impl<T: Component> SystemParam for RemovedComponents<'_, T> {
+    type State = ();
+    type Item<'w> = RemovedComponents<'w, T>;
+
+    fn access(_world: &World, _access: &mut Access) {}
+
+    fn fetch<'w>(
+        world: &'w World,
+        _state: &'w mut Self::State,
+        _ticks: SystemTicks,
+    ) -> Result<Self::Item<'w>, Error> {
+        Ok(world.removed::<T>())
+    }
+}
+
+/// A function whose arguments are all [`SystemParam`]s.
+///
+/// `Marker` is the function signature, it only exists to keep the implementations for
+/// different argument counts apart.
+pub trait SystemParamFunction<Marker>: Send + Sync + 'static {
+    type Param: SystemParam;
+
+    fn run(&mut self, param: <Self::Param as SystemParam>::Item<'_>);
+}
+
+/// Conversion into a [`System`], implemented for all functions taking [`SystemParam`]s.
+pub trait IntoSystem<Marker> {
+    type System: System;
+
+    fn into_system(self) -> Self::System;
+}
+
+/// A [`System`] calling a function with its parameters fetched from the world.
+pub struct FunctionSystem<Marker, F: SystemParamFunction<Marker>> {
+    func: F,
+    state: <F::Param as SystemParam>::State,
+    /// The tick of the last successful run, `None` before the first one.
+    last_run: Option<u32>,
+    _marker: PhantomData<fn() -> Marker>,
+}
+
+impl<Marker: 'static, F: SystemParamFunction<Marker>> IntoSystem<Marker> for F {
+    type System = FunctionSystem<Marker, F>;
+
+    fn into_system(self) -> Self::System {
+        FunctionSystem {
+            func: self,
+            state: Default::default(),
+            last_run: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Marker: 'static, F: SystemParamFunction<Marker>> System for FunctionSystem<Marker, F> {
+    fn name(&self) -> &'static str {
+        std::any::type_name::<F>()
+    }
+
+    fn access(&self, world: &World, access: &mut Access) {
+        F::Param::access(world, access);
+    }
+
+    fn run(&mut self, world: &World) -> Result<(), Error> {
+        let this_run = world.increment_change_tick();
+        // On the first run everything already in the world counts as added
+        let last_run = self
+            .last_run
+            .unwrap_or(this_run.wrapping_sub(MAX_CHANGE_AGE));
+        let param = F::Param::fetch(world, &mut self.state, SystemTicks::new(last_run, this_run))?;
+        self.func.run(param);
+        self.last_run = Some(this_run);
+        Ok(())
+    }
+
+    fn check_change_ticks(&mut self, change_tick: u32) {
+        if let Some(last_run) = &mut self.last_run {
+            if change_tick.wrapping_sub(*last_run) > MAX_CHANGE_AGE {
+                *last_run = change_tick.wrapping_sub(MAX_CHANGE_AGE);
+            }
+        }
+    }
+}
+
+macro_rules! impl_system_param_tuple {
+    ($($name:ident),*) => {
+        #[allow(non_snake_case, unused_variables, clippy::unused_unit)]
+        impl<$($name: SystemParam),*> SystemParam for ($($name,)*) {
+            type State = ($($name::State,)*);
+            type Item<'w> = ($($name::Item<'w>,)*);
+
+            fn access(world: &World, access: &mut Access) {
+                $($name::access(world, access);)*
+            }
+
+            fn fetch<'w>(
+                world: &'w World,
+                state: &'w mut Self::State,
+                ticks: SystemTicks,
+            ) -> Result<Self::Item<'w>, Error> {
+                let ($($name,)*) = state;
+                Ok(($($name::fetch(world, $name, ticks)?,)*))
+            }
+        }
+    };
+}
+
+macro_rules! impl_system_param_function {
+    ($($name:ident),*) => {
+        #[allow(non_snake_case, clippy::too_many_arguments)]
+        impl<Func, $($name: SystemParam),*> SystemParamFunction<fn($($name,)*)> for Func
+        where
+            Func: Send + Sync + 'static,
+            // Naming both the parameter types and their items lets the compiler infer the
+            // parameters from the function signature
+            for<'a> &'a mut Func: FnMut($($name),*) + FnMut($($name::Item<'_>),*),
+        {
+            type Param = ($($name,)*);
+
+            fn run(&mut self, param: <Self::Param as SystemParam>::Item<'_>) {
+                fn call<$($name),*>(mut func: impl FnMut($($name),*), $($name: $name),*) {
+                    func($($name),*)
+                }
+                let ($($name,)*) = param;
+                call(self, $($name),*)
+            }
+        }
+    };
+}
+
+impl_system_param_tuple!();
+impl_system_param_tuple!(A);
+impl_system_param_tuple!(A, B);
+impl_system_param_tuple!(A, B, C);
+impl_system_param_tuple!(A, B, C, D);
+impl_system_param_tuple!(A, B, C, D, E);
+impl_system_param_tuple!(A, B, C, D, E, F);
+impl_system_param_tuple!(A, B, C, D, E, F, G);
+impl_system_param_tuple!(A, B, C, D, E, F, G, H);
+
+impl_system_param_function!();
+impl_system_param_function!(A);
+impl_system_param_function!(A, B);
+impl_system_param_function!(A, B, C);
+impl_system_param_function!(A, B, C, D);
+impl_system_param_function!(A, B, C, D, E);
+impl_system_param_function!(A, B, C, D, E, F);
+impl_system_param_function!(A, B, C, D, E, F, G);
+impl_system_param_function!(A, B, C, D, E, F, G, H);
@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use vent_math::{
+    scalar::{affine3a::Affine3A, mat4::Mat4, quat::Quat},
+    vec::vec3::Vec3,
+};
+
+use crate::{
+    change::RemovedComponents,
+    component::Component,
+    entity::Entity,
+    hierarchy::{Children, Parent},
+    query::{Changed, Query, Without},
+};
+
+/// Position, rotation and scale of an entity relative to its [`Parent`], or to the world
+/// for entities without a parent.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct Transform {
+    pub translation: Vec3,
+    pub rotation: Quat,
+    pub scale: Vec3,
+}
+
+impl Transform {
+    pub const IDENTITY: Self = Self {
+        translation: Vec3::ZERO,
+        rotation: Quat::IDENTITY,
+        scale: Vec3::ONE,
+    };
+
+    #[inline]
+    #[must_use]
+    pub const fn from_translation(translation: Vec3) -> Self {
+        Self {
+            translation,
+            ..Self::IDENTITY
+        }
+    }
+
+    /// Decomposes an affine matrix without shear, e.g. a glTF node `matrix`.
+    #[inline]
+    #[must_use]
+    pub fn from_matrix(matrix: Mat4) -> Self {
+        let (scale, rotation, translation) = matrix.to_scale_rotation_translation();
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    /// Decomposes an affine transform without shear.
+    #[inline]
+    #[must_use]
+    pub fn from_affine(affine: Affine3A) -> Self {
+        let (scale, rotation, translation) = affine.to_scale_rotation_translation();
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn compute_matrix(&self) -> Mat4 {
+        Mat4::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn compute_affine(&self) -> Affine3A {
+        Affine3A::from_scale_rotation_translation(self.scale, self.rotation, self.translation)
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Component for Transform {}
+
+/// The world matrix of an entity, computed by [`propagate_transforms`].
+///
+/// Spawn it together with the [`Transform`], renderers only read this one.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+pub struct GlobalTransform(Mat4);
+
+impl GlobalTransform {
+    pub const IDENTITY: Self = Self(Mat4::IDENTITY);
+
+    #[inline]
+    #[must_use]
+    pub fn matrix(&self) -> Mat4 {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn translation(&self) -> Vec3 {
+        Vec3::new(self.0.w_axis.x, self.0.w_axis.y, self.0.w_axis.z)
+    }
+
+    /// Decomposes the world matrix into a [`Transform`].
+    #[inline]
+    #[must_use]
+    pub fn compute_transform(&self) -> Transform {
+        Transform::from_matrix(self.0)
+    }
+}
+
+impl Default for GlobalTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Transform> for GlobalTransform {
+    fn from(transform: Transform) -> Self {
+        Self(transform.compute_matrix())
+    }
+}
+
+impl Component for GlobalTransform {}
+
+/// Computes the [`GlobalTransform`] of every entity from its [`Transform`] and the world
+/// matrix of its [`Parent`], entities without a [`Transform`] pass the matrix of their parent on
+/// to their children.
+///
+/// Only the subtrees below a [`Transform`] or [`Parent`] added, changed or removed since the
+/// system last ran are written, so renderers can use `Changed<GlobalTransform>` to re-upload moved
+/// matrices only. Entities spawned after it ran, e.g. through commands of a later stage, are
+/// picked up on its next run. Belongs into `Stage::PostUpdate`, after everything that moves
+/// entities.
+pub fn propagate_transforms(
+    roots: Query<Entity, Without<Parent>>,
+    nodes: Query<(Option<&Transform>, Option<&Children>)>,
+    mut globals: Query<&mut GlobalTransform>,
+    moved: Query<Entity, Changed<Transform>>,
+    reparented: Query<Entity, Changed<Parent>>,
+    unparented: RemovedComponents<Parent>,
+) {
+    // Detached entities keep the world matrix of their old parent otherwise, e.g. if they have
+    // no `Transform` which was changed while detaching them
+    let unparented: HashSet<Entity> = unparented.iter().collect();
+    // Walked with an explicit stack, hierarchies can be deeper than the call stack allows
+    let mut stack: Vec<(Entity, Mat4, bool)> = roots
+        .iter()
+        .map(|entity| (entity, Mat4::IDENTITY, false))
+        .collect();
+    while let Some((entity, parent_matrix, parent_changed)) = stack.pop() {
+        let Ok((transform, children)) = nodes.get(entity) else {
+            continue;
+        };
+        let changed = parent_changed
+            || moved.get(entity).is_ok()
+            || reparented.get(entity).is_ok()
+            || unparented.contains(&entity);
+        let matrix = match transform {
+            Some(transform) => parent_matrix * transform.compute_matrix(),
+            None => parent_matrix,
+        };
+        if changed {
+            if let Ok(mut global) = globals.get_mut(entity) {
+                global.0 = matrix;
+            }
+        }
+        if let Some(children) = children {
+            stack.extend(children.iter().map(|&child| (child, matrix, changed)));
+        }
+    }
+}
@@ -1,143 +1,925 @@
-use std::collections::HashMap;
+use std::{
+    any::TypeId,
+    cell::UnsafeCell,
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use rayon::prelude::*;
 
 /// The `World` struct represents the game world.
-use crate::{archetype::Archetype, component::Component, entity::Entity};
+use crate::{
+    archetype::Archetype,
+    borrow::{BorrowFlag, Ref, RefMut},
+    bundle::Bundle,
+    change::{Mut, RemovedComponents, SystemTicks, CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE},
+    command::{Command, Commands},
+    component::{Component, ComponentHooks, ComponentInfo},
+    entity::{Entity, EntityLocation, EntityMeta},
+    error::Error,
+    event::{Event, Events},
+    hierarchy::Parent,
+    query::{Access, Added, Changed, Query, QueryData, QueryFilter},
+    resource::{Resource, ResourceData},
+    snapshot::{ArchetypeSnapshot, ColumnSnapshot, WorldSnapshot},
+};
+
+/// Number of components processed by one task of [`World::par_for_each_mut`].
+pub const PAR_BATCH_SIZE: usize = 1024;
+
+/// Required and excluded component IDs of a query.
+type QueryKey = (Vec<usize>, Vec<usize>);
+
+/// Archetypes matched by a query, `checked` archetypes have already been tested.
+#[derive(Default)]
+struct QueryCache {
+    archetypes: Vec<usize>,
+    checked: usize,
+}
 
 pub struct World {
-    entities: Vec<Entity>,
-    next_entity: Entity,
-    archetypes: HashMap<Vec<usize>, Archetype>,
-    component_ids: HashMap<String, usize>,
+    /// Generation and location of every entity, indexed by the entity index.
+    entities: Vec<EntityMeta>,
+    /// Indices of deleted entities, reused by [`World::create_entity`] and [`Commands::spawn`].
+    free_entities: Mutex<Vec<u32>>,
+    /// Number of indices past the end of `entities` reserved by [`Commands::spawn`].
+    reserved_entities: AtomicUsize,
+    archetypes: Vec<Archetype>,
+    /// Maps the sorted component IDs of an archetype to its index in `archetypes`.
+    archetype_ids: HashMap<Vec<usize>, usize>,
+    component_ids: HashMap<TypeId, usize>,
+    /// Layout and drop function of every component, indexed by the component ID.
+    component_infos: Vec<ComponentInfo>,
+    /// Runtime borrow state of every component, indexed by the component ID.
+    component_borrows: Vec<BorrowFlag>,
+    /// Lifecycle hooks of every component, indexed by the component ID.
+    component_hooks: Vec<ComponentHooks>,
+    /// How often every component was borrowed mutably by queries, indexed by the component ID.
+    component_write_versions: Vec<AtomicU64>,
+    /// Matched archetypes per query, keyed by the required and excluded component IDs.
+    query_cache: Mutex<HashMap<QueryKey, QueryCache>>,
+    resource_ids: HashMap<TypeId, usize>,
+    /// Resource slots, indexed by the resource ID.
+    resources: Vec<ResourceData>,
+    /// Swaps the buffers of every event type added through [`World::add_event`].
+    event_updaters: Vec<fn(&mut World)>,
+    /// Commands recorded through [`World::commands`], applied by [`World::flush_commands`].
+    commands: Mutex<Vec<Command>>,
+    /// Current tick, advanced by every system run and [`World::clear_trackers`]. Components
+    /// added or changed outside of systems are stamped with it.
+    change_tick: AtomicU32,
+    /// Tick of the last [`World::clear_trackers`], the `last_run` of queries made directly on
+    /// the world.
+    last_change_tick: u32,
+    /// Tick of the last [`World::check_change_ticks`] which clamped old ticks.
+    last_check_tick: u32,
+    /// Entities that lost a component since the last [`World::clear_trackers`], indexed by the component ID.
+    removed_components: Vec<Vec<Entity>>,
+    /// Archetypes of the last snapshot, indexed by the archetype index, reused for unchanged columns.
+    snapshot_cache: Vec<ArchetypeSnapshot>,
 }
 
 impl World {
     pub fn new() -> Self {
-        World {
+        let mut world = World {
             entities: Vec::new(),
-            next_entity: 0,
-            archetypes: HashMap::new(),
+            free_entities: Mutex::new(Vec::new()),
+            reserved_entities: AtomicUsize::new(0),
+            archetypes: Vec::new(),
+            archetype_ids: HashMap::new(),
             component_ids: HashMap::new(),
-        }
+            component_infos: Vec::new(),
+            component_borrows: Vec::new(),
+            component_hooks: Vec::new(),
+            component_write_versions: Vec::new(),
+            query_cache: Mutex::new(HashMap::new()),
+            resource_ids: HashMap::new(),
+            resources: Vec::new(),
+            event_updaters: Vec::new(),
+            commands: Mutex::new(Vec::new()),
+            // Systems which never ran treat tick 0 as older than anything in the world
+            change_tick: AtomicU32::new(1),
+            last_change_tick: 0,
+            last_check_tick: 0,
+            removed_components: Vec::new(),
+            snapshot_cache: Vec::new(),
+        };
+        // Entities without any components live in the empty archetype
+        world.archetype_index(Vec::new());
+        world
     }
 
     /// Creates a new entity in the world and returns its entity ID.
+    ///
+    /// Indices of deleted entities are reused with a new generation.
     pub fn create_entity(&mut self) -> Entity {
-        let entity = self.next_entity;
-        self.next_entity += 1;
-        self.entities.push(entity);
+        self.spawn(())
+    }
+
+    /// Creates a new entity with all components of the bundle, e.g.
+    /// `world.spawn((Transform::default(), Mesh::default(), Name("Player")))`.
+    ///
+    /// The entity is placed into its final archetype right away instead of moving once per component.
+    pub fn spawn<B: Bundle>(&mut self, bundle: B) -> Entity {
+        self.flush_reserved();
+        let index = match self.free_entities.get_mut().unwrap().pop() {
+            Some(index) => index,
+            None => {
+                self.entities.push(EntityMeta {
+                    generation: 0,
+                    location: None,
+                });
+                (self.entities.len() - 1) as u32
+            }
+        };
+        let entity = Entity::new(index, self.entities[index as usize].generation);
+        self.spawn_at(entity, bundle);
         entity
     }
 
+    /// Returns `true` if the entity has not been deleted.
+    pub fn is_alive(&self, entity: Entity) -> bool {
+        self.location(entity).is_ok()
+    }
+
     /// Deletes an entity from the world.
-    pub fn delete_entity(&mut self, entity: Entity) -> Result<(), String> {
-        if let Some(index) = self.entities.iter().position(|&e| e == entity) {
-            self.entities.swap_remove(index);
-            self.archetypes.values_mut().for_each(|archetype| {
-                archetype.remove_entity(entity);
-            });
-            Ok(())
-        } else {
-            Err(format!("Entity with ID {} does not exist", entity))
+    ///
+    /// The entity is removed from the [`crate::hierarchy::Children`] of its parent. Its own children are
+    /// detached and become roots which stay at the same position in the world, use
+    /// [`World::despawn_recursive`] to delete them as well.
+    pub fn delete_entity(&mut self, entity: Entity) -> Result<(), Error> {
+        self.location(entity)?;
+        self.unlink_hierarchy(entity);
+        let location = self.location(entity)?;
+        self.trigger_on_remove(entity, self.archetypes[location.archetype].components());
+        for &component_id in self.archetypes[location.archetype].components() {
+            self.removed_components[component_id].push(entity);
+        }
+        let moved = self.archetypes[location.archetype].swap_remove(location.row);
+        if let Some(moved) = moved {
+            self.entities[moved.index() as usize].location = Some(location);
+        }
+        let meta = &mut self.entities[entity.index() as usize];
+        meta.location = None;
+        // An index whose generation is exhausted is retired instead of risking aliasing
+        if let Some(generation) = meta.generation.checked_add(1) {
+            meta.generation = generation;
+            self.free_entities.get_mut().unwrap().push(entity.index());
         }
+        Ok(())
     }
 
     /// Registers a component type in the world and returns its component ID.
+    ///
+    /// Components are registered automatically the first time they are added, registering
+    /// an already known type returns its existing ID.
     pub fn register_component<T: Component + 'static>(&mut self) -> usize {
-        let component_name = std::any::type_name::<T>().to_owned();
-        let component_id = self.component_ids.len();
-        self.component_ids.insert(component_name, component_id);
+        if let Some(component_id) = self.component_id::<T>() {
+            return component_id;
+        }
+        let component_id = self.component_infos.len();
+        self.component_ids.insert(TypeId::of::<T>(), component_id);
+        self.component_infos.push(ComponentInfo::of::<T>());
+        self.component_borrows.push(BorrowFlag::default());
+        self.component_hooks.push(ComponentHooks::default());
+        self.component_write_versions.push(AtomicU64::new(0));
+        self.removed_components.push(Vec::new());
         component_id
     }
 
+    /// Registers a component that is stored in snapshots taken with [`World::snapshot`].
+    pub fn register_snapshot<T: Component + Clone>(&mut self) -> usize {
+        let component_id = self.register_component::<T>();
+        self.component_infos[component_id].clone = ComponentInfo::of_clone::<T>().clone;
+        component_id
+    }
+
+    /// Calls `hook` every time the component `T` is added to an entity, right after it was
+    /// written. Replacing an existing component does not count as adding it.
+    ///
+    /// Hooks only get shared access to the world, structural changes go through
+    /// [`World::commands`].
+    pub fn on_add<T: Component>(&mut self, hook: impl Fn(&World, Entity) + Send + Sync + 'static) {
+        let component_id = self.register_component::<T>();
+        self.component_hooks[component_id]
+            .on_add
+            .push(Box::new(hook));
+    }
+
+    /// Calls `hook` every time the component `T` is removed from an entity, either by
+    /// [`World::remove_component`] or by deleting the entity. The component can still be
+    /// read while the hook runs.
+    pub fn on_remove<T: Component>(
+        &mut self,
+        hook: impl Fn(&World, Entity) + Send + Sync + 'static,
+    ) {
+        let component_id = self.register_component::<T>();
+        self.component_hooks[component_id]
+            .on_remove
+            .push(Box::new(hook));
+    }
+
     /// Adds a component to an entity in the world.
+    ///
+    /// The entity is moved into the archetype matching its new set of components,
+    /// if the entity already has a component of this type it is replaced.
     pub fn add_component<T: Component + 'static>(
         &mut self,
         entity: Entity,
         component: T,
-    ) -> Result<(), String> {
-        let component_id = self.component_ids.get(std::any::type_name::<T>());
-        if let Some(&component_id) = component_id {
-            let archetype_key = vec![component_id];
-            if let Some(archetype) = self.archetypes.get_mut(&archetype_key) {
-                archetype.add_entity(entity);
-                archetype.add_component(component_id, component);
-                return Ok(());
-            } else {
-                let mut archetype = Archetype::new();
-                archetype.add_entity(entity);
-                archetype.add_component(component_id, component);
-                self.archetypes.insert(archetype_key, archetype);
-                return Ok(());
-            }
+    ) -> Result<(), Error> {
+        self.insert(entity, component)
+    }
+
+    /// Adds all components of the bundle to an entity in a single archetype move,
+    /// components the entity already has are replaced.
+    pub fn insert<B: Bundle>(&mut self, entity: Entity, bundle: B) -> Result<(), Error> {
+        let mut location = self.location(entity)?;
+        let mut component_ids = Vec::new();
+        B::component_ids(self, &mut component_ids);
+
+        let archetype = &self.archetypes[location.archetype];
+        let mut added: Vec<usize> = component_ids
+            .iter()
+            .copied()
+            .filter(|&id| !archetype.has_component(id))
+            .collect();
+        if !added.is_empty() {
+            added.sort_unstable();
+            added.dedup();
+            let mut archetype_key = archetype.components().to_vec();
+            archetype_key.extend(&added);
+            archetype_key.sort_unstable();
+            location = self.move_entity(location, archetype_key);
         }
-        Err(format!(
-            "Component type not registered: {}",
-            std::any::type_name::<T>()
-        ))
+        let tick = *self.change_tick.get_mut();
+        // SAFETY: the archetype has every component of the bundle, the columns of newly
+        // added components are exactly one row short
+        unsafe {
+            bundle.write(
+                &mut self.archetypes[location.archetype],
+                location.row,
+                &mut component_ids.iter(),
+                tick,
+            );
+        }
+        self.trigger_on_add(entity, &added);
+        Ok(())
     }
 
     /// Removes a component from an entity in the world.
+    ///
+    /// The entity is moved into the archetype matching its remaining components. Removing the
+    /// [`Parent`] removes the entity from the [`crate::hierarchy::Children`] of its parent as
+    /// well, use [`World::remove_parent`] to keep it at the same position in the world.
     pub fn remove_component<T: Component + 'static>(
         &mut self,
         entity: Entity,
-    ) -> Result<(), String> {
-        let component_id = self.component_ids.get(std::any::type_name::<T>());
-        if let Some(&component_id) = component_id {
-            let archetype_key = vec![component_id];
-            if let Some(archetype) = self.archetypes.get_mut(&archetype_key) {
-                archetype.remove_entity(entity);
-                archetype.remove_component(component_id, entity);
-                return Ok(());
-            }
+    ) -> Result<(), Error> {
+        let location = self.location(entity)?;
+        let component_id = self
+            .component_id::<T>()
+            .filter(|&id| self.archetypes[location.archetype].has_component(id))
+            .ok_or(Error::ComponentMissing {
+                entity,
+                component: std::any::type_name::<T>(),
+            })?;
+
+        if self.component_id::<Parent>() == Some(component_id) {
+            self.detach(entity);
         }
-        Err(format!(
-            "Component type not registered: {}",
-            std::any::type_name::<T>()
-        ))
+        self.trigger_on_remove(entity, &[component_id]);
+        let archetype = &self.archetypes[location.archetype];
+        let archetype_key = archetype
+            .components()
+            .iter()
+            .copied()
+            .filter(|&id| id != component_id)
+            .collect();
+
+        self.move_entity(location, archetype_key);
+        self.removed_components[component_id].push(entity);
+        Ok(())
     }
 
     /// Retrieves a component by its component ID and entity ID.
-    pub fn get_component<T: Component + 'static>(&self, entity: Entity) -> Result<&T, String> {
-        let component_id = self.component_ids.get(std::any::type_name::<T>());
-        if let Some(&component_id) = component_id {
-            let archetype_key = vec![component_id];
-            if let Some(archetype) = self.archetypes.get(&archetype_key) {
-                if let Some(component) = archetype.get_component::<T>(component_id, entity) {
-                    return Ok(component);
-                }
+    ///
+    /// The component stays borrowed until the returned [`Ref`] is dropped.
+    pub fn get_component<T: Component + 'static>(
+        &self,
+        entity: Entity,
+    ) -> Result<Ref<'_, T>, Error> {
+        let location = self.location(entity)?;
+        let missing = Error::ComponentMissing {
+            entity,
+            component: std::any::type_name::<T>(),
+        };
+        let component_id = self.component_id::<T>().ok_or(missing)?;
+        let flag = &self.component_borrows[component_id];
+        if !flag.borrow() {
+            return Err(Error::AlreadyBorrowed(std::any::type_name::<T>()));
+        }
+        // SAFETY: we hold a shared borrow of the component
+        match unsafe {
+            self.archetypes[location.archetype].get_component::<T>(component_id, location.row)
+        } {
+            Some(component) => Ok(unsafe { Ref::new(component, flag) }),
+            None => {
+                flag.release();
+                Err(missing)
             }
         }
-        Err(format!(
-            "Component not found for entity ID {}: {}",
-            entity,
-            std::any::type_name::<T>()
-        ))
     }
 
     /// Retrieves a mutable component by its component ID and entity ID.
+    ///
+    /// Like a query fetching `&mut T`, the component is only marked as changed once it is
+    /// written through the returned [`Mut`].
     pub fn get_component_mut<T: Component + 'static>(
         &mut self,
         entity: Entity,
-    ) -> Result<&mut T, String> {
-        let component_id = self.component_ids.get(std::any::type_name::<T>());
-        if let Some(&component_id) = component_id {
-            let archetype_key = vec![component_id];
-            if let Some(archetype) = self.archetypes.get_mut(&archetype_key) {
-                if let Some(component) = archetype.get_component_mut::<T>(component_id, entity) {
-                    return Ok(component);
+    ) -> Result<Mut<'_, T>, Error> {
+        let location = self.location(entity)?;
+        let tick = *self.change_tick.get_mut();
+        self.component_id::<T>()
+            .and_then(|component_id| {
+                self.archetypes[location.archetype].get_component_mut::<T>(
+                    component_id,
+                    location.row,
+                    tick,
+                )
+            })
+            .ok_or(Error::ComponentMissing {
+                entity,
+                component: std::any::type_name::<T>(),
+            })
+    }
+
+    /// Calls `f` for every component of type `T`, marking all of them as changed.
+    ///
+    /// The columns of all archetypes storing `T` are split into batches of
+    /// [`PAR_BATCH_SIZE`] components, which run on rayon's work-stealing thread pool.
+    pub fn par_for_each_mut<T: Component + Send + Sync>(
+        &mut self,
+        f: impl Fn(&mut T) + Send + Sync,
+    ) {
+        let Some(component_id) = self.component_id::<T>() else {
+            return;
+        };
+        let tick = *self.change_tick.get_mut();
+        let columns: Vec<_> = self
+            .archetypes
+            .iter_mut()
+            .filter(|archetype| archetype.has_component(component_id) && archetype.len() > 0)
+            .filter_map(|archetype| archetype.column_mut(component_id))
+            // SAFETY: the world only creates columns with the registered type of `component_id`
+            .map(|column| unsafe { column.as_slice_mut::<T>() })
+            .collect();
+        columns.into_par_iter().for_each(|(items, ticks)| {
+            items
+                .par_chunks_mut(PAR_BATCH_SIZE)
+                .zip(ticks.par_chunks_mut(PAR_BATCH_SIZE))
+                .for_each(|(items, ticks)| {
+                    for (item, ticks) in items.iter_mut().zip(ticks) {
+                        f(item);
+                        ticks.changed = tick;
+                    }
+                });
+        });
+    }
+
+    /// Inserts a resource, replacing the previous resource of the same type.
+    pub fn insert_resource<T: Resource>(&mut self, resource: T) {
+        let resource_id = match self.resource_id::<T>() {
+            Some(resource_id) => resource_id,
+            None => {
+                self.resource_ids
+                    .insert(TypeId::of::<T>(), self.resources.len());
+                self.resources.push(ResourceData::new::<T>());
+                self.resources.len() - 1
+            }
+        };
+        self.resources[resource_id].value = Some(Box::new(UnsafeCell::new(resource)));
+    }
+
+    /// Removes a resource and returns it.
+    pub fn remove_resource<T: Resource>(&mut self) -> Result<T, Error> {
+        let resource = self
+            .resource_id::<T>()
+            .and_then(|resource_id| self.resources[resource_id].value.take())
+            .ok_or(Error::ResourceMissing(std::any::type_name::<T>()))?;
+        // SAFETY: the slot of `T` only ever stores a `T`
+        let resource = unsafe { Box::from_raw(Box::into_raw(resource).cast::<UnsafeCell<T>>()) };
+        Ok(resource.into_inner())
+    }
+
+    /// Returns `true` if a resource of the type has been inserted.
+    pub fn contains_resource<T: Resource>(&self) -> bool {
+        self.resource_data::<T>().is_ok()
+    }
+
+    /// Retrieves a resource.
+    ///
+    /// The resource stays borrowed until the returned [`Ref`] is dropped.
+    pub fn resource<T: Resource>(&self) -> Result<Ref<'_, T>, Error> {
+        let data = self.resource_data::<T>()?;
+        if !data.borrow.borrow() {
+            return Err(Error::AlreadyBorrowed(data.name));
+        }
+        // SAFETY: we hold a shared borrow of the resource
+        Ok(unsafe { Ref::new(data.get::<T>().unwrap(), &data.borrow) })
+    }
+
+    /// Retrieves a resource mutably.
+    ///
+    /// Only needs a shared world so multiple resources can be borrowed at once, the
+    /// resource stays borrowed until the returned [`RefMut`] is dropped.
+    pub fn resource_mut<T: Resource>(&self) -> Result<RefMut<'_, T>, Error> {
+        let data = self.resource_data::<T>()?;
+        if !data.borrow.borrow_mut() {
+            return Err(Error::AlreadyBorrowed(data.name));
+        }
+        // SAFETY: we hold an exclusive borrow of the resource
+        Ok(unsafe { RefMut::new(data.get_mut::<T>().unwrap(), &data.borrow) })
+    }
+
+    /// Adds the [`Events`] resource of an event type, its buffers are swapped by
+    /// [`World::update_events`].
+    pub fn add_event<T: Event>(&mut self) {
+        if !self.contains_resource::<Events<T>>() {
+            self.insert_resource(Events::<T>::new());
+            self.event_updaters.push(|world| {
+                if let Ok(mut events) = world.resource_mut::<Events<T>>() {
+                    events.update();
                 }
+            });
+        }
+    }
+
+    /// Sends an event, failing if the event type was not added.
+    pub fn send_event<T: Event>(&self, event: T) -> Result<(), Error> {
+        self.resource_mut::<Events<T>>()?.send(event);
+        Ok(())
+    }
+
+    /// Swaps the buffers of all added event types, usually called once per frame.
+    ///
+    /// Events are dropped after the second update following the one they were sent in.
+    pub fn update_events(&mut self) {
+        for index in 0..self.event_updaters.len() {
+            (self.event_updaters[index])(self);
+        }
+    }
+
+    /// Returns a queue for structural changes which can be recorded while the world is borrowed.
+    pub fn commands(&self) -> Commands<'_> {
+        Commands::new(self)
+    }
+
+    /// Applies all recorded commands in the order they were recorded.
+    ///
+    /// Every command is applied even if an earlier one failed, the first error is returned.
+    pub fn flush_commands(&mut self) -> Result<(), Error> {
+        self.flush_reserved();
+        let commands = std::mem::take(self.commands.get_mut().unwrap());
+        let mut result = Ok(());
+        for command in commands {
+            let command_result = command(self);
+            if result.is_ok() {
+                result = command_result;
             }
         }
-        Err(format!(
-            "Component not found for entity ID {}: {}",
-            entity,
-            std::any::type_name::<T>()
-        ))
+        result
     }
 
     /// Returns an iterator over the entities in the world.
     pub fn iter_entities(&self) -> impl Iterator<Item = &Entity> {
-        self.entities.iter()
+        self.archetypes
+            .iter()
+            .flat_map(|archetype| archetype.iter_entities())
+    }
+
+    /// Queries all entities that have the components of `Q`.
+    ///
+    /// Fails if one of the components is borrowed in a conflicting way.
+    pub fn query<Q: QueryData>(&self) -> Result<Query<'_, Q>, Error> {
+        self.query_filtered::<Q, ()>()
+    }
+
+    /// Queries all entities that have the components of `Q` and pass the filter `F`,
+    /// e.g. `(With<A>, Without<B>)`.
+    ///
+    /// Fails if one of the components is borrowed in a conflicting way.
+    pub fn query_filtered<Q: QueryData, F: QueryFilter>(&self) -> Result<Query<'_, Q, F>, Error> {
+        self.query_with_ticks(self.ticks())
+    }
+
+    /// Queries like [`World::query_filtered`], reporting changes between the given ticks.
+    pub(crate) fn query_with_ticks<Q: QueryData, F: QueryFilter>(
+        &self,
+        ticks: SystemTicks,
+    ) -> Result<Query<'_, Q, F>, Error> {
+        let mut access = Access::default();
+        Q::access(self, &mut access);
+        F::access(self, &mut access);
+        if let Some(component_id) = access.self_conflict() {
+            return Err(Error::ConflictingAccess(
+                self.component_infos[component_id].name,
+            ));
+        }
+        self.acquire_access(&access)?;
+        let archetypes = self.matched_archetypes(&access);
+        Ok(Query::new(self, access, archetypes, ticks))
+    }
+
+    /// Queries all entities whose component `T` was added or mutably accessed since the
+    /// trackers were last cleared, e.g. to only rebuild the matrices of moved transforms.
+    pub fn changed<T: Component>(&self) -> Result<Query<'_, Entity, Changed<T>>, Error> {
+        self.query_filtered::<Entity, Changed<T>>()
+    }
+
+    /// Queries all entities the component `T` was added to since the trackers were last cleared.
+    pub fn added<T: Component>(&self) -> Result<Query<'_, Entity, Added<T>>, Error> {
+        self.query_filtered::<Entity, Added<T>>()
+    }
+
+    /// Returns the entities that lost the component `T` since the trackers were last cleared.
+    pub fn removed<T: Component>(&self) -> RemovedComponents<'_, T> {
+        let entities = match self.component_id::<T>() {
+            Some(component_id) => self.removed_components[component_id].as_slice(),
+            None => &[],
+        };
+        RemovedComponents::new(entities)
+    }
+
+    /// Returns the current change tick.
+    pub fn change_tick(&self) -> u32 {
+        self.change_tick.load(Ordering::Acquire)
+    }
+
+    /// Advances the change tick and returns the previous one, called by every system run so
+    /// the system can tell its own changes apart from later ones.
+    pub fn increment_change_tick(&self) -> u32 {
+        self.change_tick.fetch_add(1, Ordering::AcqRel)
+    }
+
+    /// Returns the ticks used by queries made directly on the world, reporting changes since
+    /// the last [`World::clear_trackers`].
+    pub(crate) fn ticks(&self) -> SystemTicks {
+        SystemTicks::new(self.last_change_tick, self.change_tick())
+    }
+
+    /// Starts a new change detection frame, usually called once per frame after all systems ran.
+    ///
+    /// Queries made directly on the world no longer report components added or changed
+    /// before and the removals are forgotten. Systems are not affected, they report every
+    /// change since they last ran.
+    pub fn clear_trackers(&mut self) {
+        self.last_change_tick = self.increment_change_tick();
+        for removed in &mut self.removed_components {
+            removed.clear();
+        }
+    }
+
+    /// Clamps the change ticks of all components once every [`CHECK_TICK_THRESHOLD`] ticks, so
+    /// ticks older than [`MAX_CHANGE_AGE`] are not mistaken for new ones after
+    /// the tick wrapped around. Called by [`crate::schedule::Schedule::run`].
+    pub fn check_change_ticks(&mut self) {
+        let tick = *self.change_tick.get_mut();
+        if tick.wrapping_sub(self.last_check_tick) < CHECK_TICK_THRESHOLD {
+            return;
+        }
+        for archetype in &mut self.archetypes {
+            archetype.check_change_ticks(tick);
+        }
+        if tick.wrapping_sub(self.last_change_tick) > MAX_CHANGE_AGE {
+            self.last_change_tick = tick.wrapping_sub(MAX_CHANGE_AGE);
+        }
+        self.last_check_tick = tick;
+    }
+
+    /// Captures all entities and their components registered through [`World::register_snapshot`],
+    /// e.g. to roll back a few frames and simulate them again.
+    ///
+    /// Columns which did not change since the previous snapshot are shared with it instead of
+    /// being cloned again.
+    pub fn snapshot(&mut self) -> WorldSnapshot {
+        self.flush_reserved();
+        let archetypes: Vec<ArchetypeSnapshot> = self
+            .archetypes
+            .iter()
+            .enumerate()
+            .map(|(index, archetype)| {
+                let cached = self
+                    .snapshot_cache
+                    .get(index)
+                    .filter(|cached| cached.version == archetype.version());
+                let columns = archetype
+                    .components()
+                    .iter()
+                    .filter_map(|&component_id| {
+                        let clone = self.component_infos[component_id].clone?;
+                        let write_version =
+                            self.component_write_versions[component_id].load(Ordering::Relaxed);
+                        let unchanged = cached.and_then(|cached| {
+                            cached.columns.iter().find(|column| {
+                                column.component_id == component_id
+                                    && column.write_version == write_version
+                            })
+                        });
+                        if let Some(column) = unchanged {
+                            return Some(column.clone());
+                        }
+                        let column = archetype.column(component_id).unwrap();
+                        Some(ColumnSnapshot {
+                            component_id,
+                            write_version,
+                            clone,
+                            // SAFETY: the world is borrowed exclusively and `clone` was
+                            // registered for this component
+                            column: Arc::new(unsafe { column.clone_with(clone) }),
+                        })
+                    })
+                    .collect();
+                ArchetypeSnapshot {
+                    version: archetype.version(),
+                    entities: match cached {
+                        Some(cached) => cached.entities.clone(),
+                        None => archetype.entities().into(),
+                    },
+                    columns,
+                }
+            })
+            .collect();
+        self.snapshot_cache = archetypes.clone();
+        WorldSnapshot {
+            entities: self.entities.clone(),
+            free_entities: self.free_entities.get_mut().unwrap().clone(),
+            archetypes,
+            change_tick: *self.change_tick.get_mut(),
+        }
+    }
+
+    /// Replaces all entities and components with the state of the snapshot.
+    ///
+    /// Components not stored in the snapshot are dropped, resources are kept as they are.
+    /// Pending commands are discarded and no hooks run, entities spawned afterwards get the
+    /// same IDs as they did after the snapshot was taken. The change tick keeps advancing.
+    pub fn restore(&mut self, snapshot: &WorldSnapshot) {
+        self.commands.get_mut().unwrap().clear();
+        *self.reserved_entities.get_mut() = 0;
+        self.archetypes.clear();
+        self.archetype_ids.clear();
+        self.query_cache.get_mut().unwrap().clear();
+        self.snapshot_cache.clear();
+        self.archetype_index(Vec::new());
+
+        self.entities = snapshot
+            .entities
+            .iter()
+            .map(|meta| EntityMeta {
+                generation: meta.generation,
+                location: None,
+            })
+            .collect();
+        *self.free_entities.get_mut().unwrap() = snapshot.free_entities.clone();
+        for archetype in &snapshot.archetypes {
+            let archetype_key = archetype
+                .columns
+                .iter()
+                .map(|column| column.component_id)
+                .collect();
+            let index = self.archetype_index(archetype_key);
+            let target = &mut self.archetypes[index];
+            for (row, &entity) in archetype.entities.iter().enumerate() {
+                // SAFETY: every column of the target archetype is stored in the snapshot and
+                // receives a clone of the row
+                let new_row = unsafe {
+                    let new_row = target.push_entity(entity);
+                    for column in &archetype.columns {
+                        target.column_mut(column.component_id).unwrap().push_clone(
+                            column.column.get_ptr(row),
+                            column.column.get_ticks(row),
+                            column.clone,
+                        );
+                    }
+                    new_row
+                };
+                self.entities[entity.index() as usize].location = Some(EntityLocation {
+                    archetype: index,
+                    row: new_row,
+                });
+            }
+        }
+        // The tick is not rewound, systems would otherwise miss changes until it caught up
+        // with their last run again. Restored components keep their ticks
+        let tick = *self.change_tick.get_mut();
+        for archetype in &mut self.archetypes {
+            archetype.check_change_ticks(tick);
+        }
+        for removed in &mut self.removed_components {
+            removed.clear();
+        }
+    }
+
+    /// Returns the indices of all archetypes matching the access, only testing archetypes
+    /// created since the same query last ran.
+    fn matched_archetypes(&self, access: &Access) -> Vec<usize> {
+        if access.unmatched {
+            return Vec::new();
+        }
+        let mut required = access.required.clone();
+        required.sort_unstable();
+        required.dedup();
+        let mut excluded = access.excluded.clone();
+        excluded.sort_unstable();
+        excluded.dedup();
+
+        let mut query_cache = self.query_cache.lock().unwrap();
+        let cache = query_cache
+            .entry((required.clone(), excluded.clone()))
+            .or_default();
+        for (index, archetype) in self.archetypes.iter().enumerate().skip(cache.checked) {
+            if archetype.matches(&required, &excluded) {
+                cache.archetypes.push(index);
+            }
+        }
+        cache.checked = self.archetypes.len();
+        cache.archetypes.clone()
+    }
+
+    fn acquire_access(&self, access: &Access) -> Result<(), Error> {
+        for (i, &component_id) in access.reads.iter().enumerate() {
+            if !self.component_borrows[component_id].borrow() {
+                access.reads[..i]
+                    .iter()
+                    .for_each(|&id| self.component_borrows[id].release());
+                return Err(Error::AlreadyBorrowed(
+                    self.component_infos[component_id].name,
+                ));
+            }
+        }
+        for (i, &component_id) in access.writes.iter().enumerate() {
+            if !self.component_borrows[component_id].borrow_mut() {
+                access.writes[..i]
+                    .iter()
+                    .for_each(|&id| self.component_borrows[id].release_mut());
+                access
+                    .reads
+                    .iter()
+                    .for_each(|&id| self.component_borrows[id].release());
+                return Err(Error::AlreadyBorrowed(
+                    self.component_infos[component_id].name,
+                ));
+            }
+        }
+        for &component_id in &access.writes {
+            self.component_write_versions[component_id].fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn release_access(&self, access: &Access) {
+        access
+            .reads
+            .iter()
+            .for_each(|&id| self.component_borrows[id].release());
+        access
+            .writes
+            .iter()
+            .for_each(|&id| self.component_borrows[id].release_mut());
+    }
+
+    pub(crate) fn archetype(&self, index: usize) -> &Archetype {
+        &self.archetypes[index]
+    }
+
+    /// Returns the ID of a component, `None` if it was never registered.
+    pub(crate) fn component_id<T: Component + 'static>(&self) -> Option<usize> {
+        self.component_ids.get(&TypeId::of::<T>()).copied()
+    }
+
+    /// Reserves an entity which becomes alive once it is spawned with [`World::spawn_at`].
+    pub(crate) fn reserve_entity(&self) -> Entity {
+        if let Some(index) = self.free_entities.lock().unwrap().pop() {
+            return Entity::new(index, self.entities[index as usize].generation);
+        }
+        let index = self.entities.len() + self.reserved_entities.fetch_add(1, Ordering::Relaxed);
+        Entity::new(index as u32, 0)
+    }
+
+    /// Creates the bookkeeping of entities reserved past the end of `entities`.
+    fn flush_reserved(&mut self) {
+        let reserved = std::mem::take(self.reserved_entities.get_mut());
+        self.entities.extend((0..reserved).map(|_| EntityMeta {
+            generation: 0,
+            location: None,
+        }));
+    }
+
+    /// Places an allocated entity that is not alive yet into the archetype of the bundle.
+    pub(crate) fn spawn_at<B: Bundle>(&mut self, entity: Entity, bundle: B) {
+        let mut component_ids = Vec::new();
+        B::component_ids(self, &mut component_ids);
+        let mut archetype_key = component_ids.clone();
+        archetype_key.sort_unstable();
+        archetype_key.dedup();
+
+        let archetype = self.archetype_index(archetype_key.clone());
+        let tick = *self.change_tick.get_mut();
+        // SAFETY: the archetype has exactly the components of the bundle
+        let row = unsafe {
+            let archetype = &mut self.archetypes[archetype];
+            let row = archetype.push_entity(entity);
+            bundle.write(archetype, row, &mut component_ids.iter(), tick);
+            row
+        };
+        self.entities[entity.index() as usize].location = Some(EntityLocation { archetype, row });
+        self.trigger_on_add(entity, &archetype_key);
+    }
+
+    fn trigger_on_add(&self, entity: Entity, component_ids: &[usize]) {
+        for &component_id in component_ids {
+            for hook in &self.component_hooks[component_id].on_add {
+                hook(self, entity);
+            }
+        }
+    }
+
+    fn trigger_on_remove(&self, entity: Entity, component_ids: &[usize]) {
+        for &component_id in component_ids {
+            for hook in &self.component_hooks[component_id].on_remove {
+                hook(self, entity);
+            }
+        }
+    }
+
+    pub(crate) fn push_command(&self, command: Command) {
+        self.commands.lock().unwrap().push(command);
+    }
+
+    /// Returns the ID of a resource, `None` if it was never inserted.
+    pub(crate) fn resource_id<T: Resource>(&self) -> Option<usize> {
+        self.resource_ids.get(&TypeId::of::<T>()).copied()
+    }
+
+    fn resource_data<T: Resource>(&self) -> Result<&ResourceData, Error> {
+        self.resource_id::<T>()
+            .map(|resource_id| &self.resources[resource_id])
+            .filter(|data| data.value.is_some())
+            .ok_or(Error::ResourceMissing(std::any::type_name::<T>()))
+    }
+
+    pub(crate) fn location(&self, entity: Entity) -> Result<EntityLocation, Error> {
+        self.entities
+            .get(entity.index() as usize)
+            .filter(|meta| meta.generation == entity.generation())
+            .and_then(|meta| meta.location)
+            .ok_or(Error::EntityNotFound(entity))
+    }
+
+    /// Returns the index of the archetype for the given sorted component IDs, creating it if needed.
+    fn archetype_index(&mut self, archetype_key: Vec<usize>) -> usize {
+        if let Some(&index) = self.archetype_ids.get(&archetype_key) {
+            return index;
+        }
+        let index = self.archetypes.len();
+        self.archetypes
+            .push(Archetype::new(archetype_key.clone(), &self.component_infos));
+        self.archetype_ids.insert(archetype_key, index);
+        index
+    }
+
+    /// Moves an entity into the archetype with the given components and updates the locations.
+    ///
+    /// Components the target archetype lacks are dropped, new columns have to be filled by the caller.
+    fn move_entity(
+        &mut self,
+        location: EntityLocation,
+        archetype_key: Vec<usize>,
+    ) -> EntityLocation {
+        let target = self.archetype_index(archetype_key);
+        let (source, target_archetype) = if location.archetype < target {
+            let (left, right) = self.archetypes.split_at_mut(target);
+            (&mut left[location.archetype], &mut right[0])
+        } else {
+            let (left, right) = self.archetypes.split_at_mut(location.archetype);
+            (&mut right[0], &mut left[target])
+        };
+        let entity = source.entities()[location.row];
+        let (moved, row) = unsafe { source.move_row(location.row, target_archetype) };
+        if let Some(moved) = moved {
+            self.entities[moved.index() as usize].location = Some(location);
+        }
+        let new_location = EntityLocation {
+            archetype: target,
+            row,
+        };
+        self.entities[entity.index() as usize].location = Some(new_location);
+        new_location
     }
 }
 
@@ -0,0 +1,102 @@
+use vent_ecs::{component::Component, entity::Entity, world::World, Error};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Position(f32, f32);
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Velocity(f32, f32);
+
+#[derive(Clone, PartialEq, Debug)]
+struct Name(String);
+
+impl Component for Position {}
+impl Component for Velocity {}
+impl Component for Name {}
+
+fn name(world: &World, entity: Entity) -> String {
+    world.get_component::<Name>(entity).unwrap().0.clone()
+}
+
+#[test]
+fn insert_keeps_other_components() {
+    let mut world = World::new();
+    let entities: Vec<_> = (0..4)
+        .map(|i| world.spawn((Position(i as f32, 0.0), Name(format!("entity {}", i)))))
+        .collect();
+
+    // Moving a row out of the middle swaps the last row into its place
+    world
+        .add_component(entities[1], Velocity(1.0, 2.0))
+        .unwrap();
+    for (i, &entity) in entities.iter().enumerate() {
+        assert_eq!(
+            *world.get_component::<Position>(entity).unwrap(),
+            Position(i as f32, 0.0)
+        );
+        assert_eq!(name(&world, entity), format!("entity {}", i));
+    }
+    assert_eq!(
+        *world.get_component::<Velocity>(entities[1]).unwrap(),
+        Velocity(1.0, 2.0)
+    );
+    assert!(world.get_component::<Velocity>(entities[0]).is_err());
+
+    // Replacing a component does not move the entity or touch the others
+    world.insert(entities[1], Position(5.0, 5.0)).unwrap();
+    assert_eq!(
+        *world.get_component::<Position>(entities[1]).unwrap(),
+        Position(5.0, 5.0)
+    );
+    assert_eq!(
+        *world.get_component::<Velocity>(entities[1]).unwrap(),
+        Velocity(1.0, 2.0)
+    );
+    assert_eq!(name(&world, entities[1]), "entity 1");
+}
+
+#[test]
+fn remove_keeps_other_components() {
+    let mut world = World::new();
+    let entities: Vec<_> = (0..4)
+        .map(|i| {
+            world.spawn((
+                Position(i as f32, 0.0),
+                Velocity(0.0, i as f32),
+                Name(format!("entity {}", i)),
+            ))
+        })
+        .collect();
+
+    world.remove_component::<Velocity>(entities[0]).unwrap();
+    world.remove_component::<Position>(entities[2]).unwrap();
+    assert_eq!(
+        world.remove_component::<Velocity>(entities[0]),
+        Err(Error::ComponentMissing {
+            entity: entities[0],
+            component: std::any::type_name::<Velocity>(),
+        })
+    );
+
+    for (i, &entity) in entities.iter().enumerate() {
+        assert_eq!(name(&world, entity), format!("entity {}", i));
+        assert_eq!(world.get_component::<Velocity>(entity).is_ok(), i != 0);
+        assert_eq!(world.get_component::<Position>(entity).is_ok(), i != 2);
+    }
+    assert_eq!(
+        *world.get_component::<Position>(entities[0]).unwrap(),
+        Position(0.0, 0.0)
+    );
+    assert_eq!(
+        *world.get_component::<Velocity>(entities[2]).unwrap(),
+        Velocity(0.0, 2.0)
+    );
+
+    // Moving back into an existing archetype
+    world
+        .add_component(entities[0], Velocity(9.0, 9.0))
+        .unwrap();
+    let query = world.query::<(Entity, &Position, &Velocity)>().unwrap();
+    let mut moving: Vec<_> = query.iter().map(|(entity, ..)| entity).collect();
+    moving.sort();
+    assert_eq!(moving, vec![entities[0], entities[1], entities[3]]);
+}
@@ -0,0 +1,207 @@
+use vent_ecs::{
+    change::{SystemTicks, MAX_CHANGE_AGE},
+    command::Commands,
+    component::Component,
+    entity::Entity,
+    query::{Added, Changed, Query},
+    resource::{Res, ResMut, Resource},
+    schedule::{Schedule, Stage},
+    world::World,
+};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Position(i32);
+
+impl Component for Position {}
+
+/// What the reader systems saw, one entry per run.
+#[derive(Default)]
+struct Seen {
+    changed: Vec<usize>,
+    added: Vec<usize>,
+}
+
+impl Resource for Seen {}
+
+/// Whether `mover` should move every entity this frame.
+struct Moving(bool);
+
+impl Resource for Moving {}
+
+fn mover(mut query: Query<&mut Position>, moving: Res<Moving>) {
+    if moving.0 {
+        for mut position in query.iter_mut() {
+            position.0 += 1;
+        }
+    }
+}
+
+fn reader(
+    changed: Query<Entity, Changed<Position>>,
+    added: Query<Entity, Added<Position>>,
+    mut seen: ResMut<Seen>,
+) {
+    seen.changed.push(changed.len());
+    seen.added.push(added.len());
+}
+
+fn world() -> World {
+    let mut world = World::new();
+    world.insert_resource(Seen::default());
+    world.insert_resource(Moving(true));
+    for i in 0..3 {
+        world.spawn(Position(i));
+    }
+    world
+}
+
+fn frame(world: &mut World, schedule: &mut Schedule) {
+    schedule.run(world).unwrap();
+    world.clear_trackers();
+}
+
+fn take_seen(world: &World) -> (Vec<usize>, Vec<usize>) {
+    let mut seen = world.resource_mut::<Seen>().unwrap();
+    (
+        std::mem::take(&mut seen.changed),
+        std::mem::take(&mut seen.added),
+    )
+}
+
+#[test]
+fn changes_of_later_stages_are_seen_next_frame() {
+    let mut world = world();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Stage::PreUpdate, reader);
+    schedule.add_system(Stage::PostUpdate, mover);
+
+    for _ in 0..3 {
+        frame(&mut world, &mut schedule);
+    }
+    // The first run sees the spawned entities, every later run the moves of the frame before
+    assert_eq!(take_seen(&world), (vec![3, 3, 3], vec![3, 0, 0]));
+
+    world.resource_mut::<Moving>().unwrap().0 = false;
+    frame(&mut world, &mut schedule);
+    frame(&mut world, &mut schedule);
+    assert_eq!(take_seen(&world), (vec![3, 0], vec![0, 0]));
+}
+
+#[test]
+fn changes_of_earlier_stages_are_seen_once() {
+    let mut world = world();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Stage::PreUpdate, mover);
+    schedule.add_system(Stage::Update, reader);
+    schedule.add_system(Stage::PostUpdate, reader);
+
+    frame(&mut world, &mut schedule);
+    frame(&mut world, &mut schedule);
+    // Both readers see the moves of the same frame
+    assert_eq!(take_seen(&world), (vec![3, 3, 3, 3], vec![3, 3, 0, 0]));
+
+    world.resource_mut::<Moving>().unwrap().0 = false;
+    frame(&mut world, &mut schedule);
+    assert_eq!(take_seen(&world), (vec![0, 0], vec![0, 0]));
+}
+
+#[test]
+fn only_written_components_are_changed() {
+    let mut world = world();
+    world.clear_trackers();
+
+    let mut query = world.query::<&mut Position>().unwrap();
+    for (i, mut position) in query.iter_mut().enumerate() {
+        match i {
+            0 => position.0 += 1,
+            1 => position.bypass_change_detection().0 += 1,
+            _ => assert!(position.0 >= 0),
+        }
+    }
+    drop(query);
+    assert_eq!(world.changed::<Position>().unwrap().len(), 1);
+}
+
+#[test]
+fn only_written_world_components_are_changed() {
+    let mut world = world();
+    world.clear_trackers();
+    let entities: Vec<Entity> = world.iter_entities().copied().collect();
+
+    assert!(world.get_component_mut::<Position>(entities[0]).unwrap().0 >= 0);
+    world
+        .get_component_mut::<Position>(entities[1])
+        .unwrap()
+        .bypass_change_detection()
+        .0 += 1;
+    assert!(world.changed::<Position>().unwrap().is_empty());
+
+    world.get_component_mut::<Position>(entities[2]).unwrap().0 += 1;
+    let changed: Vec<Entity> = world.changed::<Position>().unwrap().iter().collect();
+    assert_eq!(changed, [entities[2]]);
+}
+
+#[test]
+fn changes_outside_of_systems() {
+    let mut world = world();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Stage::Update, reader);
+    frame(&mut world, &mut schedule);
+    take_seen(&world);
+
+    let entity = world.spawn(Position(10));
+    let other = world
+        .iter_entities()
+        .copied()
+        .find(|&e| e != entity)
+        .unwrap();
+    world.get_component_mut::<Position>(other).unwrap().0 = 20;
+    // Queries made directly on the world report changes since the last clear
+    assert_eq!(world.changed::<Position>().unwrap().len(), 2);
+    assert_eq!(world.added::<Position>().unwrap().len(), 1);
+
+    world.clear_trackers();
+    assert!(world.changed::<Position>().unwrap().is_empty());
+    // Systems still see them
+    frame(&mut world, &mut schedule);
+    assert_eq!(take_seen(&world), (vec![2], vec![1]));
+}
+
+#[test]
+fn commands_of_the_last_stage_are_seen_next_frame() {
+    let mut world = world();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Stage::PreUpdate, reader);
+    schedule.add_system(
+        Stage::RenderPrep,
+        |commands: Commands, moving: Res<Moving>| {
+            if moving.0 {
+                commands.spawn(Position(100));
+            }
+        },
+    );
+
+    frame(&mut world, &mut schedule);
+    world.resource_mut::<Moving>().unwrap().0 = false;
+    frame(&mut world, &mut schedule);
+    frame(&mut world, &mut schedule);
+    assert_eq!(take_seen(&world), (vec![3, 1, 0], vec![3, 1, 0]));
+}
+
+#[test]
+fn ticks_compare_across_wrap_around() {
+    let ticks = SystemTicks::new(u32::MAX - 5, 3);
+    assert!(ticks.is_newer(u32::MAX - 4));
+    assert!(ticks.is_newer(u32::MAX));
+    assert!(ticks.is_newer(0));
+    assert!(ticks.is_newer(3));
+    assert!(!ticks.is_newer(u32::MAX - 5));
+    assert!(!ticks.is_newer(u32::MAX - 100));
+    // Ticks after this run belong to systems running later
+    assert!(!ticks.is_newer(4));
+
+    // The last run stays older than its own tick up to the maximum age
+    let ticks = SystemTicks::new(10, 10u32.wrapping_add(MAX_CHANGE_AGE));
+    assert!(!ticks.is_newer(10));
+    assert!(ticks.is_newer(11));
+}
@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use vent_ecs::{component::Component, world::World, Error};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Id(u32);
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Position(f32, f32);
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Velocity(f32, f32);
+
+#[derive(Clone, PartialEq, Debug)]
+struct Name(String);
+
+impl Component for Id {}
+impl Component for Position {}
+impl Component for Velocity {}
+impl Component for Name {}
+
+#[test]
+fn reservations_do_not_collide_with_spawn() {
+    let mut world = World::new();
+    let initial: Vec<_> = (0..8).map(|i| world.spawn(Id(i))).collect();
+    // Free a few indices so reservations recycle some and allocate new ones past the end
+    for &entity in &initial[2..5] {
+        world.delete_entity(entity).unwrap();
+    }
+
+    let commands = world.commands();
+    let reserved: Vec<_> = (100..106).map(|i| commands.spawn(Id(i))).collect();
+    assert!(reserved.iter().all(|&entity| !world.is_alive(entity)));
+    let spawned: Vec<_> = (200..206).map(|i| world.spawn(Id(i))).collect();
+    world.flush_commands().unwrap();
+
+    let alive: Vec<_> = initial[..2]
+        .iter()
+        .chain(&initial[5..])
+        .chain(&reserved)
+        .chain(&spawned)
+        .copied()
+        .collect();
+    let unique: HashSet<_> = alive.iter().map(|entity| entity.index()).collect();
+    assert_eq!(unique.len(), alive.len());
+    assert!(alive.iter().all(|&entity| world.is_alive(entity)));
+    for (entity, id) in reserved.iter().zip(100..).chain(spawned.iter().zip(200..)) {
+        assert_eq!(*world.get_component::<Id>(*entity).unwrap(), Id(id));
+    }
+    assert_eq!(world.query::<&Id>().unwrap().len(), alive.len());
+}
+
+#[test]
+fn bundles() {
+    let mut world = World::new();
+    let entity = world.spawn((
+        Position(0.0, 0.0),
+        (Velocity(1.0, 0.0), Name("player".to_owned())),
+    ));
+    assert_eq!(
+        *world.get_component::<Velocity>(entity).unwrap(),
+        Velocity(1.0, 0.0)
+    );
+
+    // Inserting replaces existing components and adds new ones in one move
+    world.insert(entity, (Position(5.0, 5.0), Id(7))).unwrap();
+    assert_eq!(
+        *world.get_component::<Position>(entity).unwrap(),
+        Position(5.0, 5.0)
+    );
+    assert_eq!(*world.get_component::<Id>(entity).unwrap(), Id(7));
+    assert_eq!(
+        world.get_component::<Name>(entity).unwrap().0,
+        "player".to_owned()
+    );
+    let query = world.query::<(&Position, &Velocity, &Name, &Id)>().unwrap();
+    assert_eq!(query.len(), 1);
+    drop(query);
+
+    let empty = world.spawn(());
+    assert!(world.is_alive(empty));
+    assert!(world.get_component::<Position>(empty).is_err());
+}
+
+#[test]
+fn commands_apply_in_order() {
+    let mut world = World::new();
+    let entity = world.spawn((Position(0.0, 0.0), Velocity(1.0, 1.0)));
+    let stale = world.spawn(Id(0));
+    world.delete_entity(stale).unwrap();
+
+    let commands = world.commands();
+    let spawned = commands.spawn((Id(1), Position(2.0, 2.0)));
+    commands.insert(spawned, Name("spawned".to_owned()));
+    commands.remove::<Velocity>(entity);
+    commands.despawn(stale);
+    commands.insert(entity, Id(2));
+    commands.add(move |world| {
+        world.get_component_mut::<Position>(spawned)?.0 += 1.0;
+        Ok(())
+    });
+
+    // Failing commands do not stop the ones after them
+    assert_eq!(world.flush_commands(), Err(Error::EntityNotFound(stale)));
+    assert!(world.get_component::<Velocity>(entity).is_err());
+    assert_eq!(*world.get_component::<Id>(entity).unwrap(), Id(2));
+    assert_eq!(
+        *world.get_component::<Position>(spawned).unwrap(),
+        Position(3.0, 2.0)
+    );
+    assert_eq!(world.get_component::<Name>(spawned).unwrap().0, "spawned");
+    assert_eq!(world.flush_commands(), Ok(()));
+}
+
+#[test]
+fn reserved_entity_is_dead_until_flushed() {
+    let mut world = World::new();
+    let commands = world.commands();
+    let entity = commands.spawn(Id(1));
+    assert_eq!(
+        world.get_component::<Id>(entity).err(),
+        Some(Error::EntityNotFound(entity))
+    );
+    world.flush_commands().unwrap();
+    assert_eq!(*world.get_component::<Id>(entity).unwrap(), Id(1));
+}
@@ -0,0 +1,41 @@
+use vent_ecs::{component::Component, entity::Entity, world::World, Error};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Health(u32);
+
+impl Component for Health {}
+
+#[test]
+fn recycled_index_gets_new_generation() {
+    let mut world = World::new();
+    let first = world.spawn(Health(10));
+    world.delete_entity(first).unwrap();
+    let second = world.spawn(Health(20));
+
+    assert_eq!(second.index(), first.index());
+    assert_ne!(second.generation(), first.generation());
+    assert!(!world.is_alive(first));
+    assert!(world.is_alive(second));
+    assert_eq!(Entity::from_bits(second.to_bits()), second);
+}
+
+#[test]
+fn stale_entity_returns_error() {
+    let mut world = World::new();
+    let stale = world.spawn(Health(10));
+    world.delete_entity(stale).unwrap();
+    let alive = world.spawn(Health(20));
+
+    let not_found = Some(Error::EntityNotFound(stale));
+    assert_eq!(world.delete_entity(stale).err(), not_found);
+    assert_eq!(world.add_component(stale, Health(30)).err(), not_found);
+    assert_eq!(world.remove_component::<Health>(stale).err(), not_found);
+    assert_eq!(world.get_component::<Health>(stale).err(), not_found);
+    assert_eq!(world.get_component_mut::<Health>(stale).err(), not_found);
+
+    // The entity now using the index is untouched
+    assert_eq!(*world.get_component::<Health>(alive).unwrap(), Health(20));
+    let query = world.query::<&Health>().unwrap();
+    assert_eq!(query.get(stale).err(), not_found);
+    assert_eq!(query.len(), 1);
+}
@@ -0,0 +1,206 @@
+use std::sync::{Arc, Mutex};
+
+use vent_ecs::{
+    component::Component,
+    event::{Event, EventCursor, EventReader, EventWriter, Events},
+    resource::{ResMut, Resource},
+    schedule::{Schedule, Stage},
+    world::World,
+};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Hit(u32);
+
+impl Event for Hit {}
+
+/// The events every reader system saw, one entry per run.
+#[derive(Default)]
+struct Seen {
+    early: Vec<Vec<u32>>,
+    late: Vec<Vec<u32>>,
+}
+
+impl Resource for Seen {}
+
+/// The events sent by `writer` in the next frame.
+struct Outbox(Vec<u32>);
+
+impl Resource for Outbox {}
+
+fn read(cursor: &mut EventCursor<Hit>, events: &Events<Hit>) -> Vec<u32> {
+    cursor.read(events).map(|hit| hit.0).collect()
+}
+
+#[test]
+fn events_live_for_two_updates() {
+    let mut events = Events::<Hit>::new();
+    let mut cursor = EventCursor::default();
+    let mut late = EventCursor::default();
+
+    events.send(Hit(1));
+    events.update();
+    events.send(Hit(2));
+    assert_eq!(cursor.len(&events), 2);
+    assert_eq!(read(&mut cursor, &events), [1, 2]);
+    assert!(cursor.is_empty(&events));
+    assert!(read(&mut cursor, &events).is_empty());
+
+    // The first event is dropped by the second update after it was sent
+    events.update();
+    events.send(Hit(3));
+    assert_eq!(events.len(), 2);
+    assert_eq!(read(&mut cursor, &events), [3]);
+    assert_eq!(read(&mut late, &events), [2, 3]);
+
+    events.update();
+    events.update();
+    assert!(events.is_empty());
+    events.send_batch([Hit(4), Hit(5)]);
+    assert_eq!(read(&mut cursor, &events), [4, 5]);
+    assert_eq!(read(&mut late, &events), [4, 5]);
+}
+
+#[test]
+fn clear_and_drain() {
+    let mut events = Events::<Hit>::new();
+    let mut cursor = EventCursor::default();
+    events.send_batch([Hit(1), Hit(2)]);
+    events.update();
+    events.send(Hit(3));
+    assert_eq!(
+        events.drain().map(|hit| hit.0).collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+    assert!(cursor.is_empty(&events));
+
+    events.send(Hit(4));
+    events.clear();
+    assert!(read(&mut cursor, &events).is_empty());
+    events.send(Hit(5));
+    assert_eq!(read(&mut cursor, &events), [5]);
+}
+
+#[test]
+fn readers_see_every_event_once() {
+    let mut world = World::new();
+    world.add_event::<Hit>();
+    world.insert_resource(Seen::default());
+    world.insert_resource(Outbox(Vec::new()));
+    let mut schedule = Schedule::new();
+    // One reader runs before the writer and one after it
+    schedule.add_system(
+        Stage::PreUpdate,
+        |mut events: EventReader<Hit>, mut seen: ResMut<Seen>| {
+            seen.early.push(events.read().map(|hit| hit.0).collect());
+        },
+    );
+    schedule.add_system(
+        Stage::Update,
+        |mut events: EventWriter<Hit>, mut outbox: ResMut<Outbox>| {
+            events.send_batch(outbox.0.drain(..).map(Hit));
+        },
+    );
+    schedule.add_system(
+        Stage::PostUpdate,
+        |mut events: EventReader<Hit>, mut seen: ResMut<Seen>| {
+            seen.late.push(events.read().map(|hit| hit.0).collect());
+        },
+    );
+
+    for outbox in [vec![1, 2], vec![], vec![3], vec![], vec![]] {
+        world.resource_mut::<Outbox>().unwrap().0 = outbox;
+        schedule.run(&mut world).unwrap();
+        world.update_events();
+    }
+    let seen = world.resource::<Seen>().unwrap();
+    assert_eq!(seen.early, [vec![], vec![1, 2], vec![], vec![3], vec![]]);
+    assert_eq!(seen.late, [vec![1, 2], vec![], vec![3], vec![], vec![]]);
+    drop(seen);
+    assert!(world.resource::<Events<Hit>>().unwrap().is_empty());
+
+    // Sending outside of systems needs the event type to be added
+    world.send_event(Hit(6)).unwrap();
+    assert!(world.send_event(Missed).is_err());
+}
+
+struct Missed;
+
+impl Event for Missed {}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Health(u32);
+
+struct Marker;
+
+impl Component for Health {}
+impl Component for Marker {}
+
+type Log = Arc<Mutex<Vec<String>>>;
+
+fn take(log: &Log) -> Vec<String> {
+    std::mem::take(&mut log.lock().unwrap())
+}
+
+#[test]
+fn hooks_run_in_order() {
+    let mut world = World::new();
+    let log = Log::default();
+    for name in ["first", "second"] {
+        let add = log.clone();
+        world.on_add::<Health>(move |world, entity| {
+            // The component is already written
+            let health = world.get_component::<Health>(entity).unwrap().0;
+            add.lock().unwrap().push(format!("add {name} {health}"));
+        });
+        let remove = log.clone();
+        world.on_remove::<Health>(move |world, entity| {
+            // And can still be read while it is removed
+            let health = world.get_component::<Health>(entity).unwrap().0;
+            remove
+                .lock()
+                .unwrap()
+                .push(format!("remove {name} {health}"));
+        });
+    }
+
+    let entity = world.spawn((Health(10), Marker));
+    assert_eq!(take(&log), ["add first 10", "add second 10"]);
+    // Replacing the component does not add it again
+    world.insert(entity, Health(20)).unwrap();
+    world.remove_component::<Marker>(entity).unwrap();
+    assert!(take(&log).is_empty());
+
+    world.remove_component::<Health>(entity).unwrap();
+    assert_eq!(take(&log), ["remove first 20", "remove second 20"]);
+    world.add_component(entity, Health(30)).unwrap();
+    world.delete_entity(entity).unwrap();
+    assert_eq!(
+        take(&log),
+        [
+            "add first 30",
+            "add second 30",
+            "remove first 30",
+            "remove second 30"
+        ]
+    );
+}
+
+#[test]
+fn hooks_record_commands() {
+    let mut world = World::new();
+    world.on_add::<Health>(|world, entity| {
+        world.commands().insert(entity, Marker);
+    });
+    world.on_remove::<Marker>(|world, entity| {
+        world.commands().remove::<Health>(entity);
+    });
+
+    let entity = world.spawn(Health(1));
+    assert!(world.get_component::<Marker>(entity).is_err());
+    world.flush_commands().unwrap();
+    assert!(world.get_component::<Marker>(entity).is_ok());
+
+    world.remove_component::<Marker>(entity).unwrap();
+    world.flush_commands().unwrap();
+    assert!(world.get_component::<Health>(entity).is_err());
+}
@@ -0,0 +1,246 @@
+use vent_ecs::{
+    command::Commands,
+    entity::Entity,
+    hierarchy::{Children, Parent},
+    schedule::{Schedule, Stage},
+    transform::{propagate_transforms, GlobalTransform, Transform},
+    world::World,
+};
+use vent_math::vec::vec3::Vec3;
+
+fn translation(world: &World, entity: Entity) -> Vec3 {
+    world
+        .get_component::<GlobalTransform>(entity)
+        .unwrap()
+        .translation()
+}
+
+fn spawn_at(world: &mut World, x: f32) -> Entity {
+    world.spawn((
+        Transform::from_translation(Vec3::new(x, 0.0, 0.0)),
+        GlobalTransform::IDENTITY,
+    ))
+}
+
+fn frame(world: &mut World, schedule: &mut Schedule) {
+    schedule.run(world).unwrap();
+    world.clear_trackers();
+}
+
+#[test]
+fn propagates_to_children() {
+    let mut world = World::new();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Stage::PostUpdate, propagate_transforms);
+    let parent = spawn_at(&mut world, 1.0);
+    let child = spawn_at(&mut world, 2.0);
+    world.set_parent(child, parent).unwrap();
+
+    frame(&mut world, &mut schedule);
+    assert_eq!(translation(&world, parent), Vec3::new(1.0, 0.0, 0.0));
+    assert_eq!(translation(&world, child), Vec3::new(2.0, 0.0, 0.0));
+
+    world
+        .get_component_mut::<Transform>(parent)
+        .unwrap()
+        .translation = Vec3::new(5.0, 0.0, 0.0);
+    frame(&mut world, &mut schedule);
+    assert_eq!(translation(&world, child), Vec3::new(6.0, 0.0, 0.0));
+}
+
+#[test]
+fn propagates_entities_spawned_by_later_stages() {
+    let mut world = World::new();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Stage::PostUpdate, propagate_transforms);
+    schedule.add_system(Stage::RenderPrep, |commands: Commands| {
+        commands.spawn((
+            Transform::from_translation(Vec3::new(3.0, 0.0, 0.0)),
+            GlobalTransform::IDENTITY,
+        ));
+    });
+
+    frame(&mut world, &mut schedule);
+    frame(&mut world, &mut schedule);
+    let query = world.query::<(&Transform, &GlobalTransform)>().unwrap();
+    // The entity spawned in the last frame is propagated next frame
+    assert_eq!(query.len(), 2);
+    let propagated = query
+        .iter()
+        .filter(|(_, global)| global.translation() == Vec3::new(3.0, 0.0, 0.0))
+        .count();
+    assert_eq!(propagated, 1);
+}
+
+#[test]
+fn delete_entity_detaches_children() {
+    let mut world = World::new();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Stage::PostUpdate, propagate_transforms);
+    let root = spawn_at(&mut world, 1.0);
+    let parent = spawn_at(&mut world, 2.0);
+    let children = [spawn_at(&mut world, 3.0), spawn_at(&mut world, 4.0)];
+    world.set_parent(parent, root).unwrap();
+    for child in children {
+        world.set_parent(child, parent).unwrap();
+    }
+    frame(&mut world, &mut schedule);
+
+    world.delete_entity(parent).unwrap();
+    // The parent is no longer a child of the root
+    assert!(world.get_component::<Children>(root).unwrap().is_empty());
+    // The children become roots at the same position in the world
+    for (child, x) in children.into_iter().zip([3.0, 4.0]) {
+        assert!(world.is_alive(child));
+        assert!(world.get_component::<Parent>(child).is_err());
+        assert_eq!(
+            world.get_component::<Transform>(child).unwrap().translation,
+            Vec3::new(x, 0.0, 0.0)
+        );
+    }
+    frame(&mut world, &mut schedule);
+    assert_eq!(translation(&world, children[0]), Vec3::new(3.0, 0.0, 0.0));
+    assert_eq!(translation(&world, children[1]), Vec3::new(4.0, 0.0, 0.0));
+}
+
+#[test]
+fn despawn_recursive_deletes_descendants() {
+    let mut world = World::new();
+    let root = spawn_at(&mut world, 0.0);
+    let parent = spawn_at(&mut world, 0.0);
+    let child = spawn_at(&mut world, 0.0);
+    let other = spawn_at(&mut world, 0.0);
+    world.set_parent(parent, root).unwrap();
+    world.set_parent(child, parent).unwrap();
+    world.set_parent(other, root).unwrap();
+
+    world.despawn_recursive(parent).unwrap();
+    assert!(!world.is_alive(parent));
+    assert!(!world.is_alive(child));
+    assert!(world.is_alive(other));
+    assert_eq!(&**world.get_component::<Children>(root).unwrap(), &[other]);
+}
+
+#[test]
+fn propagates_through_entities_without_transform() {
+    let mut world = World::new();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Stage::PostUpdate, propagate_transforms);
+    let root = spawn_at(&mut world, 1.0);
+    let group = world.spawn(GlobalTransform::IDENTITY);
+    let child = spawn_at(&mut world, 2.0);
+    world.set_parent(group, root).unwrap();
+    world.set_parent(child, group).unwrap();
+    // Roots without a transform are walked as well
+    let detached_group = world.spawn(GlobalTransform::IDENTITY);
+    let detached_child = spawn_at(&mut world, 5.0);
+    world.set_parent(detached_child, detached_group).unwrap();
+
+    frame(&mut world, &mut schedule);
+    assert_eq!(translation(&world, group), Vec3::new(1.0, 0.0, 0.0));
+    assert_eq!(translation(&world, child), Vec3::new(2.0, 0.0, 0.0));
+    assert_eq!(
+        translation(&world, detached_child),
+        Vec3::new(5.0, 0.0, 0.0)
+    );
+
+    world
+        .get_component_mut::<Transform>(root)
+        .unwrap()
+        .translation = Vec3::new(3.0, 0.0, 0.0);
+    frame(&mut world, &mut schedule);
+    assert_eq!(translation(&world, child), Vec3::new(4.0, 0.0, 0.0));
+}
+
+#[test]
+fn remove_parent_propagates_entities_without_transform() {
+    let mut world = World::new();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Stage::PostUpdate, propagate_transforms);
+    let root = spawn_at(&mut world, 1.0);
+    let group = world.spawn(GlobalTransform::IDENTITY);
+    let child = spawn_at(&mut world, 2.0);
+    world.set_parent(group, root).unwrap();
+    world.set_parent(child, group).unwrap();
+    frame(&mut world, &mut schedule);
+    assert_eq!(translation(&world, group), Vec3::new(1.0, 0.0, 0.0));
+
+    // Without a transform the group can not keep its position, it moves to the origin together
+    // with its children
+    world.remove_parent(group).unwrap();
+    frame(&mut world, &mut schedule);
+    assert_eq!(translation(&world, group), Vec3::ZERO);
+    assert_eq!(translation(&world, child), Vec3::new(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn removing_parent_component_detaches() {
+    let mut world = World::new();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Stage::PostUpdate, propagate_transforms);
+    let parent = spawn_at(&mut world, 1.0);
+    let child = spawn_at(&mut world, 3.0);
+    world.set_parent(child, parent).unwrap();
+    frame(&mut world, &mut schedule);
+
+    world.remove_component::<Parent>(child).unwrap();
+    assert!(world.get_component::<Children>(parent).unwrap().is_empty());
+    // The transform relative to the old parent is now relative to the world
+    frame(&mut world, &mut schedule);
+    assert_eq!(translation(&world, child), Vec3::new(2.0, 0.0, 0.0));
+}
+
+#[test]
+fn propagates_deep_hierarchies() {
+    let mut world = World::new();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Stage::PostUpdate, propagate_transforms);
+    let chain: Vec<Entity> = (0..100_000).map(|_| spawn_at(&mut world, 1.0)).collect();
+    // Attached bottom up, so every parent is still a root when its child is attached, the
+    // children keep their world position and end up at the origin of their parent
+    for pair in chain.windows(2).rev() {
+        world.set_parent(pair[1], pair[0]).unwrap();
+    }
+
+    world
+        .get_component_mut::<Transform>(chain[0])
+        .unwrap()
+        .translation = Vec3::new(2.0, 0.0, 0.0);
+
+    frame(&mut world, &mut schedule);
+    assert_eq!(
+        translation(&world, chain[chain.len() - 1]),
+        Vec3::new(2.0, 0.0, 0.0)
+    );
+}
+
+#[test]
+fn set_parent_keeps_local_transform_below_zero_scale() {
+    let mut world = World::new();
+    let parent = world.spawn(Transform {
+        scale: Vec3::ZERO,
+        ..Transform::from_translation(Vec3::new(1.0, 0.0, 0.0))
+    });
+    let child = spawn_at(&mut world, 2.0);
+    world.set_parent(child, parent).unwrap();
+
+    let transform = *world.get_component::<Transform>(child).unwrap();
+    assert_eq!(transform.translation, Vec3::new(2.0, 0.0, 0.0));
+    assert_eq!(transform.scale, Vec3::ONE);
+}
+
+#[test]
+fn set_parent_to_current_parent_keeps_order() {
+    let mut world = World::new();
+    let parent = spawn_at(&mut world, 0.0);
+    let children = [spawn_at(&mut world, 1.0), spawn_at(&mut world, 2.0)];
+    for child in children {
+        world.set_parent(child, parent).unwrap();
+    }
+
+    world.set_parent(children[0], parent).unwrap();
+    assert_eq!(
+        &**world.get_component::<Children>(parent).unwrap(),
+        &children
+    );
+}
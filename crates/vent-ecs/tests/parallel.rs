@@ -0,0 +1,97 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use vent_ecs::{
+    component::Component,
+    entity::Entity,
+    query::Query,
+    world::{World, PAR_BATCH_SIZE},
+};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Position(u64);
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Velocity(u64);
+
+struct Marker;
+
+impl Component for Position {}
+impl Component for Velocity {}
+impl Component for Marker {}
+
+fn step(position: &mut Position) {
+    position.0 = position.0.wrapping_mul(31).wrapping_add(7);
+}
+
+/// Spawns entities into several archetypes, some of them spanning multiple batches.
+fn world() -> (World, Vec<Entity>) {
+    let mut world = World::new();
+    let mut entities = Vec::new();
+    for i in 0..PAR_BATCH_SIZE as u64 * 3 + 17 {
+        entities.push(world.spawn(Position(i)));
+    }
+    for i in 0..PAR_BATCH_SIZE as u64 + 1 {
+        entities.push(world.spawn((Position(i * 2), Velocity(i))));
+    }
+    for i in 0..5 {
+        entities.push(world.spawn((Position(i * 3), Marker)));
+    }
+    // Entities without the component are not visited
+    for i in 0..PAR_BATCH_SIZE as u64 {
+        world.spawn(Velocity(i));
+    }
+    (world, entities)
+}
+
+#[test]
+fn matches_sequential_iteration() {
+    let (mut parallel, entities) = world();
+    let (sequential, _) = world();
+
+    let visited = AtomicUsize::new(0);
+    parallel.par_for_each_mut::<Position>(|position| {
+        visited.fetch_add(1, Ordering::Relaxed);
+        step(position);
+    });
+    assert_eq!(visited.into_inner(), entities.len());
+
+    let mut query: Query<&mut Position> = sequential.query().unwrap();
+    for mut position in query.iter_mut() {
+        step(&mut position);
+    }
+    drop(query);
+    for &entity in &entities {
+        assert_eq!(
+            *parallel.get_component::<Position>(entity).unwrap(),
+            *sequential.get_component::<Position>(entity).unwrap()
+        );
+    }
+    let velocities: u64 = parallel
+        .query::<&Velocity>()
+        .unwrap()
+        .iter()
+        .map(|velocity| velocity.0)
+        .sum();
+    assert_eq!(
+        velocities,
+        (0..PAR_BATCH_SIZE as u64 + 1).sum::<u64>() + (0..PAR_BATCH_SIZE as u64).sum::<u64>()
+    );
+}
+
+#[test]
+fn marks_components_changed() {
+    let (mut world, entities) = world();
+    world.clear_trackers();
+    assert!(world.changed::<Position>().unwrap().is_empty());
+
+    world.par_for_each_mut::<Position>(step);
+    assert_eq!(world.changed::<Position>().unwrap().len(), entities.len());
+    assert!(world.changed::<Velocity>().unwrap().is_empty());
+}
+
+#[test]
+fn unknown_component_is_a_no_op() {
+    let mut world = World::new();
+    world.spawn(Velocity(1));
+    world.par_for_each_mut::<Position>(|_| unreachable!());
+}
@@ -0,0 +1,119 @@
+use vent_ecs::{
+    component::Component,
+    entity::Entity,
+    query::{With, Without},
+    world::World,
+    Error,
+};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Position(i32);
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Velocity(i32);
+
+struct Player;
+
+struct Frozen;
+
+impl Component for Position {}
+impl Component for Velocity {}
+impl Component for Player {}
+impl Component for Frozen {}
+
+fn sorted(mut entities: Vec<Entity>) -> Vec<Entity> {
+    entities.sort();
+    entities
+}
+
+#[test]
+fn conflicting_access() {
+    let mut world = World::new();
+    world.spawn((Position(0), Velocity(1)));
+    let conflict = Some(Error::ConflictingAccess(std::any::type_name::<Position>()));
+
+    assert_eq!(world.query::<(&mut Position, &Position)>().err(), conflict);
+    assert_eq!(world.query::<(&Position, &mut Position)>().err(), conflict);
+    assert_eq!(
+        world.query::<(&mut Position, &mut Position)>().err(),
+        conflict
+    );
+
+    // Queries borrow their components until they are dropped
+    let query = world.query::<&mut Position>().unwrap();
+    assert_eq!(
+        world.query::<&Position>().err(),
+        Some(Error::AlreadyBorrowed(std::any::type_name::<Position>()))
+    );
+    assert!(world.query::<&Velocity>().is_ok());
+    drop(query);
+    let first = world.query::<&Position>().unwrap();
+    let second = world.query::<(&Position, &Velocity)>().unwrap();
+    assert_eq!(first.len(), second.len());
+}
+
+#[test]
+fn with_without_filters() {
+    let mut world = World::new();
+    let player = world.spawn((Position(0), Player));
+    let frozen_player = world.spawn((Position(1), Player, Frozen));
+    let frozen = world.spawn((Position(2), Frozen));
+    let other = world.spawn((Position(3), Velocity(1)));
+
+    let query = world.query_filtered::<Entity, With<Player>>().unwrap();
+    assert_eq!(sorted(query.iter().collect()), vec![player, frozen_player]);
+    drop(query);
+    let query = world
+        .query_filtered::<Entity, (With<Position>, Without<Frozen>)>()
+        .unwrap();
+    assert_eq!(sorted(query.iter().collect()), vec![player, other]);
+    drop(query);
+    let query = world
+        .query_filtered::<&Position, (With<Frozen>, Without<Player>)>()
+        .unwrap();
+    assert_eq!(query.iter().copied().collect::<Vec<_>>(), vec![Position(2)]);
+    drop(query);
+
+    let query = world
+        .query::<(Entity, &Position, Option<&Velocity>)>()
+        .unwrap();
+    let velocities: Vec<_> = query
+        .iter()
+        .filter_map(|(entity, _, velocity)| velocity.map(|velocity| (entity, *velocity)))
+        .collect();
+    assert_eq!(velocities, vec![(other, Velocity(1))]);
+    assert_eq!(query.len(), 4);
+    drop(query);
+
+    let query = world.query_filtered::<Entity, With<Velocity>>().unwrap();
+    assert!(!query.iter().any(|entity| entity == frozen));
+}
+
+#[test]
+fn get_rejects_non_matching_entities() {
+    let mut world = World::new();
+    let player = world.spawn((Position(0), Player));
+    let frozen = world.spawn((Position(1), Player, Frozen));
+    let moving = world.spawn((Position(2), Velocity(1)));
+
+    let query = world
+        .query_filtered::<&Position, (With<Player>, Without<Frozen>)>()
+        .unwrap();
+    assert_eq!(query.get(player).copied(), Ok(Position(0)));
+    assert_eq!(query.get(frozen).err(), Some(Error::QueryMismatch(frozen)));
+    assert_eq!(query.get(moving).err(), Some(Error::QueryMismatch(moving)));
+    drop(query);
+
+    let mut query = world.query::<(&mut Position, &Velocity)>().unwrap();
+    assert_eq!(
+        query.get_mut(player).err(),
+        Some(Error::QueryMismatch(player))
+    );
+    let (mut position, velocity) = query.get_mut(moving).unwrap();
+    position.0 += velocity.0;
+    drop(query);
+    assert_eq!(
+        *world.get_component::<Position>(moving).unwrap(),
+        Position(3)
+    );
+}
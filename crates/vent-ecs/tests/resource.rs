@@ -0,0 +1,84 @@
+use vent_ecs::{
+    resource::{Res, ResMut, Resource},
+    schedule::{Schedule, Stage},
+    world::World,
+    Error,
+};
+
+#[derive(PartialEq, Debug)]
+struct DeltaTime(f32);
+
+#[derive(PartialEq, Debug)]
+struct Score(u32);
+
+impl Resource for DeltaTime {}
+impl Resource for Score {}
+
+#[test]
+fn borrow_errors() {
+    let mut world = World::new();
+    world.insert_resource(DeltaTime(0.016));
+    world.insert_resource(Score(0));
+    let borrowed = Some(Error::AlreadyBorrowed(std::any::type_name::<Score>()));
+
+    let first = world.resource::<Score>().unwrap();
+    let second = world.resource::<Score>().unwrap();
+    assert_eq!(world.resource_mut::<Score>().err(), borrowed);
+    // Other resources are borrowed independently
+    world.resource_mut::<DeltaTime>().unwrap().0 = 0.032;
+    drop((first, second));
+
+    let mut score = world.resource_mut::<Score>().unwrap();
+    assert_eq!(world.resource::<Score>().err(), borrowed);
+    assert_eq!(world.resource_mut::<Score>().err(), borrowed);
+    score.0 += 1;
+    drop(score);
+
+    assert_eq!(*world.resource::<Score>().unwrap(), Score(1));
+    assert_eq!(*world.resource::<DeltaTime>().unwrap(), DeltaTime(0.032));
+}
+
+#[test]
+fn missing_resources() {
+    let mut world = World::new();
+    let missing = Some(Error::ResourceMissing(std::any::type_name::<Score>()));
+    assert!(!world.contains_resource::<Score>());
+    assert_eq!(world.resource::<Score>().err(), missing);
+    assert_eq!(world.resource_mut::<Score>().err(), missing);
+    assert_eq!(world.remove_resource::<Score>().err(), missing);
+
+    world.insert_resource(Score(3));
+    world.insert_resource(Score(5));
+    assert_eq!(world.remove_resource::<Score>(), Ok(Score(5)));
+    assert!(!world.contains_resource::<Score>());
+    assert_eq!(world.resource::<Score>().err(), missing);
+}
+
+#[test]
+fn system_resource_errors() {
+    let mut world = World::new();
+    let mut schedule = Schedule::new();
+    schedule.add_system(
+        Stage::Update,
+        |delta: Res<DeltaTime>, mut score: ResMut<Score>| {
+            score.0 += (delta.0 * 1000.0) as u32;
+        },
+    );
+
+    world.insert_resource(Score(0));
+    assert_eq!(
+        schedule.run(&mut world),
+        Err(Error::ResourceMissing(std::any::type_name::<DeltaTime>()))
+    );
+    world.insert_resource(DeltaTime(0.016));
+    schedule.run(&mut world).unwrap();
+    assert_eq!(*world.resource::<Score>().unwrap(), Score(16));
+
+    // Reading and writing the same resource in one system can not be borrowed
+    schedule.add_system(Stage::PostUpdate, |_: Res<Score>, _: ResMut<Score>| {});
+    assert_eq!(
+        schedule.run(&mut world),
+        Err(Error::AlreadyBorrowed(std::any::type_name::<Score>()))
+    );
+    assert!(world.resource_mut::<Score>().is_ok());
+}
@@ -0,0 +1,283 @@
+use serde::{Deserialize, Serialize};
+use vent_ecs::{
+    component::Component,
+    entity::Entity,
+    hierarchy::{Children, Parent},
+    scene::{DynamicScene, EntityMap, MapEntities, SceneError, SceneRegistry},
+    transform::Transform,
+    world::World,
+    Error,
+};
+use vent_math::vec::vec3::Vec3;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+struct Name(String);
+
+/// References another entity, e.g. the target of a camera.
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+struct Target(Entity);
+
+/// Not registered, so it is not saved.
+struct Selected;
+
+#[derive(Serialize, Deserialize)]
+struct Selectable;
+
+impl Component for Name {}
+impl Component for Target {}
+impl Component for Selected {}
+impl Component for Selectable {}
+
+impl MapEntities for Target {
+    fn map_entities(&mut self, map: &EntityMap) {
+        self.0 = map.get(self.0);
+    }
+}
+
+fn registry() -> SceneRegistry {
+    let mut registry = SceneRegistry::default();
+    registry
+        .register::<Name>("Name")
+        .register_mapped::<Target>("Target");
+    registry
+}
+
+fn name(world: &World, entity: Entity) -> String {
+    world.get_component::<Name>(entity).unwrap().0.clone()
+}
+
+fn parent(world: &World, entity: Entity) -> Option<Entity> {
+    world
+        .get_component::<Parent>(entity)
+        .ok()
+        .map(|parent| parent.get())
+}
+
+fn children(world: &World, entity: Entity) -> Vec<Entity> {
+    world
+        .get_component::<Children>(entity)
+        .map(|children| children.to_vec())
+        .unwrap_or_default()
+}
+
+#[test]
+fn round_trip_remaps_hierarchy() {
+    let registry = registry();
+    let mut source = World::new();
+    let spawn = |world: &mut World, name: &str, x: f32| {
+        world.spawn((
+            Name(name.to_owned()),
+            Transform::from_translation(Vec3::new(x, 0.0, 0.0)),
+        ))
+    };
+    let root = spawn(&mut source, "root", 1.0);
+    let left = spawn(&mut source, "left", 2.0);
+    let right = spawn(&mut source, "right", 3.0);
+    let leaf = spawn(&mut source, "leaf", 4.0);
+    source.set_parent(left, root).unwrap();
+    source.set_parent(right, root).unwrap();
+    source.set_parent(leaf, left).unwrap();
+    source.insert(root, (Target(leaf), Selected)).unwrap();
+
+    let json =
+        serde_json::to_string(&DynamicScene::from_world(&source, &registry).unwrap()).unwrap();
+    let scene: DynamicScene = serde_json::from_str(&json).unwrap();
+    assert_eq!(scene.entities.len(), 4);
+
+    // A world with live and freed entities, so the scene entities are spawned elsewhere
+    let mut world = World::new();
+    let existing: Vec<_> = (0..6)
+        .map(|i| world.spawn(Name(format!("existing {i}"))))
+        .collect();
+    world.delete_entity(existing[1]).unwrap();
+    world.set_parent(existing[3], existing[2]).unwrap();
+    let map = scene.write_to_world(&mut world, &registry).unwrap();
+    assert_eq!(map.len(), 4);
+
+    let saved = [root, left, right, leaf];
+    let [root, left, right, leaf] = saved.map(|entity| map.get(entity));
+    for (entity, expected) in [
+        (root, "root"),
+        (left, "left"),
+        (right, "right"),
+        (leaf, "leaf"),
+    ] {
+        assert!(!existing.contains(&entity));
+        assert_eq!(name(&world, entity), expected);
+    }
+    assert_eq!(parent(&world, root), None);
+    assert_eq!(parent(&world, left), Some(root));
+    assert_eq!(parent(&world, right), Some(root));
+    assert_eq!(parent(&world, leaf), Some(left));
+    assert_eq!(children(&world, root), [left, right]);
+    assert_eq!(children(&world, left), [leaf]);
+    assert!(children(&world, leaf).is_empty());
+    assert_eq!(*world.get_component::<Target>(root).unwrap(), Target(leaf));
+    assert!(world.get_component::<Selected>(root).is_err());
+    // Local transforms are stored, the leaf was attached relative to `left`
+    assert_eq!(
+        world.get_component::<Transform>(leaf).unwrap().translation,
+        Vec3::new(2.0, 0.0, 0.0)
+    );
+
+    // The existing hierarchy is untouched
+    assert_eq!(parent(&world, existing[3]), Some(existing[2]));
+    assert_eq!(children(&world, existing[2]), [existing[3]]);
+    assert_eq!(world.query::<&Name>().unwrap().len(), 5 + 4);
+
+    // Loading twice creates a second, independent copy
+    let second = scene.write_to_world(&mut world, &registry).unwrap();
+    for (entity, first) in saved.into_iter().zip([root, left, right, leaf]) {
+        assert_ne!(second.get(entity), first);
+    }
+    assert_eq!(world.query::<&Name>().unwrap().len(), 5 + 8);
+}
+
+#[test]
+fn save_and_load() {
+    let registry = registry();
+    let mut world = World::new();
+    let parent_entity = world.spawn(Name("parent".to_owned()));
+    let child = world.spawn(Name("child".to_owned()));
+    world.set_parent(child, parent_entity).unwrap();
+
+    let path = std::env::temp_dir().join(format!("vent-ecs-scene-{}.json", std::process::id()));
+    DynamicScene::from_world(&world, &registry)
+        .unwrap()
+        .save(&path)
+        .unwrap();
+    let scene = DynamicScene::load(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    let mut loaded = World::new();
+    let map = scene
+        .unwrap()
+        .write_to_world(&mut loaded, &registry)
+        .unwrap();
+    assert_eq!(name(&loaded, map.get(child)), "child");
+    assert_eq!(
+        parent(&loaded, map.get(child)),
+        Some(map.get(parent_entity))
+    );
+}
+
+#[test]
+fn unknown_components_are_rejected() {
+    let mut world = World::new();
+    world.spawn(Name("named".to_owned()));
+    let scene = DynamicScene::from_world(&world, &registry()).unwrap();
+
+    let mut loaded = World::new();
+    let result = scene.write_to_world(&mut loaded, &SceneRegistry::default());
+    assert!(matches!(
+        result,
+        Err(SceneError::UnknownComponent(name)) if name == "Name"
+    ));
+    assert_eq!(loaded.iter_entities().count(), 0);
+}
+
+#[test]
+fn failed_loads_remove_spawned_entities() {
+    let mut source = World::new();
+    let parent_entity = source.spawn(Name("parent".to_owned()));
+    let child = source.spawn(Name("child".to_owned()));
+    source.set_parent(child, parent_entity).unwrap();
+    let mut scene = DynamicScene::from_world(&source, &registry()).unwrap();
+    // The last entity fails after the others already got their components
+    scene.entities[1]
+        .components
+        .insert("Name".to_owned(), serde_json::json!(42));
+
+    let mut world = World::new();
+    let existing = world.spawn(Name("existing".to_owned()));
+    let result = scene.write_to_world(&mut world, &registry());
+    assert!(matches!(result, Err(SceneError::Json(_))));
+    assert_eq!(
+        world.iter_entities().copied().collect::<Vec<_>>(),
+        [existing]
+    );
+}
+
+#[test]
+fn parents_outside_of_scene_get_children() {
+    let mut source = World::new();
+    let parent_entity = source.spawn(Name("parent".to_owned()));
+    let child = source.spawn(Name("child".to_owned()));
+    source.set_parent(child, parent_entity).unwrap();
+    let mut scene = DynamicScene::from_world(&source, &registry()).unwrap();
+    scene
+        .entities
+        .retain(|scene_entity| scene_entity.entity != parent_entity);
+
+    // The parent is missing in an empty world
+    let mut world = World::new();
+    let result = scene.write_to_world(&mut world, &registry());
+    assert!(matches!(
+        result,
+        Err(SceneError::World(Error::EntityNotFound(entity))) if entity == parent_entity
+    ));
+    assert_eq!(world.iter_entities().count(), 0);
+
+    let existing = world.spawn(Name("existing".to_owned()));
+    assert_eq!(existing, parent_entity);
+    let map = scene.write_to_world(&mut world, &registry()).unwrap();
+    assert_eq!(parent(&world, map.get(child)), Some(existing));
+    assert_eq!(children(&world, existing), [map.get(child)]);
+}
+
+#[test]
+fn duplicate_entities_are_rejected() {
+    let mut source = World::new();
+    source.spawn(Name("first".to_owned()));
+    source.spawn(Name("second".to_owned()));
+    let mut scene = DynamicScene::from_world(&source, &registry()).unwrap();
+    let duplicate = scene.entities[0].entity;
+    scene.entities[1].entity = duplicate;
+
+    let mut world = World::new();
+    let result = scene.write_to_world(&mut world, &registry());
+    assert!(matches!(
+        result,
+        Err(SceneError::DuplicateEntity(entity)) if entity == duplicate
+    ));
+    assert_eq!(world.iter_entities().count(), 0);
+}
+
+#[test]
+fn borrowed_components_fail_to_save() {
+    let mut world = World::new();
+    world.spawn(Name("named".to_owned()));
+
+    let names = world.query::<&mut Name>().unwrap();
+    let result = DynamicScene::from_world(&world, &registry());
+    assert!(matches!(
+        result,
+        Err(SceneError::World(Error::AlreadyBorrowed(_)))
+    ));
+    drop(names);
+    assert!(DynamicScene::from_world(&world, &registry()).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "Scene component name Name is already registered")]
+fn duplicate_names_are_rejected() {
+    let mut registry = registry();
+    registry.register::<Selectable>("Name");
+}
+
+#[test]
+fn transforms_are_stored_as_arrays() {
+    let mut world = World::new();
+    world.spawn(Transform::from_translation(Vec3::new(0.0, 1.0, 0.0)));
+    let scene = DynamicScene::from_world(&world, &SceneRegistry::default()).unwrap();
+
+    let components = &scene.entities[0].components;
+    assert_eq!(
+        components["Transform"],
+        serde_json::json!({
+            "translation": [0.0, 1.0, 0.0],
+            "rotation": [0.0, 0.0, 0.0, 1.0],
+            "scale": [1.0, 1.0, 1.0]
+        })
+    );
+}
@@ -0,0 +1,202 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use vent_ecs::{
+    component::Component,
+    query::Query,
+    resource::{Res, ResMut, Resource},
+    schedule::{IntoSystemConfig, Schedule, Stage},
+    world::World,
+    Error,
+};
+
+/// The names of the systems in the order they ran, only read by the systems so they
+/// do not conflict.
+#[derive(Default)]
+struct Log(Mutex<Vec<&'static str>>);
+
+impl Resource for Log {}
+
+impl Log {
+    fn push(&self, name: &'static str) {
+        self.0.lock().unwrap().push(name);
+    }
+
+    fn take(&self) -> Vec<&'static str> {
+        std::mem::take(&mut self.0.lock().unwrap())
+    }
+}
+
+/// Tracks which systems use the position column right now.
+#[derive(Default)]
+struct Running {
+    writing: AtomicBool,
+    readers: AtomicUsize,
+    overlapped: AtomicBool,
+}
+
+impl Resource for Running {}
+
+impl Running {
+    fn write(&self) {
+        if self.writing.swap(true, Ordering::SeqCst) || self.readers.load(Ordering::SeqCst) > 0 {
+            self.overlapped.store(true, Ordering::SeqCst);
+        }
+        thread::sleep(Duration::from_millis(2));
+        self.writing.store(false, Ordering::SeqCst);
+    }
+
+    fn read(&self) {
+        self.readers.fetch_add(1, Ordering::SeqCst);
+        if self.writing.load(Ordering::SeqCst) {
+            self.overlapped.store(true, Ordering::SeqCst);
+        }
+        thread::sleep(Duration::from_millis(2));
+        self.readers.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+struct Position(f32);
+
+impl Component for Position {}
+
+struct Missing;
+
+impl Resource for Missing {}
+
+struct AlsoMissing;
+
+impl Resource for AlsoMissing {}
+
+fn world() -> World {
+    let mut world = World::new();
+    world.insert_resource(Log::default());
+    world.insert_resource(Running::default());
+    for i in 0..16 {
+        world.spawn(Position(i as f32));
+    }
+    world
+}
+
+#[test]
+fn stages_run_in_order() {
+    let mut world = world();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Stage::RenderPrep, |log: Res<Log>| log.push("render prep"));
+    schedule.add_system(Stage::PostUpdate, |log: Res<Log>| log.push("post update"));
+    schedule.add_system(Stage::Update, |log: Res<Log>| log.push("update"));
+    schedule.add_system(Stage::PreUpdate, |log: Res<Log>| log.push("pre update"));
+
+    for _ in 0..3 {
+        schedule.run(&mut world).unwrap();
+        assert_eq!(
+            world.resource::<Log>().unwrap().take(),
+            ["pre update", "update", "post update", "render prep"]
+        );
+    }
+}
+
+#[test]
+fn before_and_after() {
+    let mut world = world();
+    let mut schedule = Schedule::with_threads(4);
+    // Added in reverse, only the constraints order these systems
+    schedule.add_system(
+        Stage::Update,
+        (|log: Res<Log>| log.push("render"))
+            .label("render")
+            .after("physics"),
+    );
+    schedule.add_system(
+        Stage::Update,
+        (|log: Res<Log>| log.push("physics"))
+            .label("physics")
+            .after("input"),
+    );
+    schedule.add_system(
+        Stage::Update,
+        (|log: Res<Log>| log.push("input"))
+            .label("input")
+            .before("physics"),
+    );
+
+    for _ in 0..20 {
+        schedule.run(&mut world).unwrap();
+        assert_eq!(
+            world.resource::<Log>().unwrap().take(),
+            ["input", "physics", "render"]
+        );
+    }
+
+    schedule.add_system(Stage::Update, (|| {}).label("input").after("render"));
+    assert!(matches!(
+        schedule.run(&mut world),
+        Err(Error::SystemCycle(_))
+    ));
+}
+
+#[test]
+fn conflicting_systems_never_overlap() {
+    let mut world = world();
+    let mut schedule = Schedule::with_threads(4);
+    for _ in 0..4 {
+        schedule.add_system(
+            Stage::Update,
+            |mut query: Query<&mut Position>, running: Res<Running>| {
+                running.write();
+                for mut position in query.iter_mut() {
+                    position.0 += 1.0;
+                }
+            },
+        );
+        schedule.add_system(
+            Stage::Update,
+            |query: Query<&Position>, running: Res<Running>| {
+                running.read();
+                assert_eq!(query.len(), 16);
+            },
+        );
+    }
+
+    for _ in 0..5 {
+        schedule.run(&mut world).unwrap();
+    }
+    assert!(!world
+        .resource::<Running>()
+        .unwrap()
+        .overlapped
+        .load(Ordering::SeqCst));
+    // Every writer ran once per frame
+    let query = world.query::<&Position>().unwrap();
+    let sum: f32 = query.iter().map(|position| position.0).sum();
+    assert_eq!(sum, (0..16).sum::<i32>() as f32 + 16.0 * 20.0);
+}
+
+#[test]
+fn error_of_first_added_system() {
+    let mut world = world();
+    let mut schedule = Schedule::with_threads(4);
+    schedule.add_system(Stage::PreUpdate, |log: Res<Log>| log.push("pre update"));
+    schedule.add_system(Stage::Update, |_: ResMut<AlsoMissing>| {});
+    schedule.add_system(Stage::Update, |_: Res<Missing>| {});
+    schedule.add_system(Stage::Update, |log: Res<Log>| log.push("update"));
+    schedule.add_system(Stage::PostUpdate, |log: Res<Log>| log.push("post update"));
+
+    for _ in 0..10 {
+        assert_eq!(
+            schedule.run(&mut world),
+            Err(Error::ResourceMissing(std::any::type_name::<AlsoMissing>()))
+        );
+        // The rest of the stage still runs, later stages are skipped
+        assert_eq!(
+            world.resource::<Log>().unwrap().take(),
+            ["pre update", "update"]
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use vent_ecs::{
+    command::Commands,
+    component::Component,
+    entity::Entity,
+    query::Query,
+    resource::{Res, Resource},
+    schedule::{Schedule, Stage},
+    world::World,
+};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Position(i64, i64);
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Velocity(i64, i64);
+
+#[derive(Clone, PartialEq, Debug)]
+struct Name(String);
+
+/// Not registered for snapshots.
+struct Cache;
+
+impl Component for Position {}
+impl Component for Velocity {}
+impl Component for Name {}
+impl Component for Cache {}
+
+struct Frame(u64);
+
+impl Resource for Frame {}
+
+fn movement(mut query: Query<(&mut Position, &Velocity)>) {
+    for (mut position, velocity) in query.iter_mut() {
+        position.0 += velocity.0;
+        position.1 += velocity.1;
+    }
+}
+
+fn spawner(frame: Res<Frame>, query: Query<(Entity, &Position)>, commands: Commands) {
+    let frame = frame.0 as i64;
+    commands.spawn((
+        Position(frame, -frame),
+        Velocity(frame % 3, 1),
+        Name(format!("spawned {}", frame)),
+    ));
+    for (entity, position) in query.iter() {
+        if position.0 > 12 {
+            commands.despawn(entity);
+        }
+    }
+}
+
+fn setup() -> (World, Schedule) {
+    let mut world = World::new();
+    world.register_snapshot::<Position>();
+    world.register_snapshot::<Velocity>();
+    world.register_snapshot::<Name>();
+    world.insert_resource(Frame(0));
+    for i in 0..4 {
+        world.spawn((
+            Position(i, 0),
+            Velocity(1, i),
+            Name(format!("initial {}", i)),
+        ));
+    }
+
+    let mut schedule = Schedule::new();
+    schedule.add_system(Stage::Update, movement);
+    schedule.add_system(Stage::PostUpdate, spawner);
+    (world, schedule)
+}
+
+fn step(world: &mut World, schedule: &mut Schedule) {
+    schedule.run(world).unwrap();
+    world.clear_trackers();
+    world.resource_mut::<Frame>().unwrap().0 += 1;
+}
+
+/// All entities with their components, sorted by entity.
+fn state(world: &World) -> Vec<(Entity, Position, Velocity, Name)> {
+    let query = world
+        .query::<(Entity, &Position, &Velocity, &Name)>()
+        .unwrap();
+    let mut state: Vec<_> = query
+        .iter()
+        .map(|(entity, position, velocity, name)| (entity, *position, *velocity, name.clone()))
+        .collect();
+    state.sort_by_key(|(entity, ..)| *entity);
+    state
+}
+
+#[test]
+fn rollback_and_replay() {
+    let (mut world, mut schedule) = setup();
+    for _ in 0..5 {
+        step(&mut world, &mut schedule);
+    }
+
+    let snapshot = world.snapshot();
+    let frame = world.resource::<Frame>().unwrap().0;
+    let mut history = vec![state(&world)];
+    for _ in 0..10 {
+        step(&mut world, &mut schedule);
+        history.push(state(&world));
+    }
+
+    world.restore(&snapshot);
+    world.resource_mut::<Frame>().unwrap().0 = frame;
+    assert_eq!(state(&world), history[0]);
+    for expected in &history[1..] {
+        step(&mut world, &mut schedule);
+        assert_eq!(&state(&world), expected);
+    }
+}
+
+#[test]
+fn snapshots_are_independent() {
+    let (mut world, mut schedule) = setup();
+    let first = world.snapshot();
+    let unchanged = world.snapshot();
+    step(&mut world, &mut schedule);
+    let second = world.snapshot();
+    assert_eq!(first.len(), 4);
+    assert_eq!(second.len(), 5);
+
+    let after_step = state(&world);
+    world.restore(&unchanged);
+    let initial = state(&world);
+    assert_eq!(initial.len(), 4);
+    world.restore(&second);
+    assert_eq!(state(&world), after_step);
+    world.restore(&first);
+    assert_eq!(state(&world), initial);
+}
+
+#[test]
+fn restore_drops_components_without_snapshot() {
+    let (mut world, _) = setup();
+    let entity = world.spawn((Position(0, 0), Cache));
+    let snapshot = world.snapshot();
+    world.delete_entity(entity).unwrap();
+
+    world.restore(&snapshot);
+    assert!(world.is_alive(entity));
+    assert_eq!(
+        *world.get_component::<Position>(entity).unwrap(),
+        Position(0, 0)
+    );
+    assert!(world.get_component::<Cache>(entity).is_err());
+}
@@ -0,0 +1,93 @@
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use vent_ecs::{component::Component, entity::Entity, query::With, world::World};
+
+/// Counts how often it was dropped.
+struct Tracked(Arc<AtomicUsize>, u64);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Position(f32, f32);
+
+struct Marker;
+
+#[derive(PartialEq, Debug)]
+struct Unit;
+
+impl Component for Tracked {}
+impl Component for Position {}
+impl Component for Marker {}
+impl Component for Unit {}
+
+#[test]
+fn columns_drop_components() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let mut world = World::new();
+    let entities: Vec<_> = (0..10)
+        .map(|i| world.spawn((Tracked(drops.clone(), i), Position(i as f32, 0.0))))
+        .collect();
+
+    // Moving between archetypes moves the component instead of dropping it
+    world.add_component(entities[0], Marker).unwrap();
+    world.remove_component::<Position>(entities[1]).unwrap();
+    assert_eq!(drops.load(Ordering::Relaxed), 0);
+    assert_eq!(world.get_component::<Tracked>(entities[0]).unwrap().1, 0);
+    assert_eq!(world.get_component::<Tracked>(entities[1]).unwrap().1, 1);
+
+    world.delete_entity(entities[2]).unwrap();
+    assert_eq!(drops.load(Ordering::Relaxed), 1);
+    world.remove_component::<Tracked>(entities[3]).unwrap();
+    assert_eq!(drops.load(Ordering::Relaxed), 2);
+    // Replacing drops the previous value
+    world
+        .add_component(entities[4], Tracked(drops.clone(), 40))
+        .unwrap();
+    assert_eq!(drops.load(Ordering::Relaxed), 3);
+    assert_eq!(world.get_component::<Tracked>(entities[4]).unwrap().1, 40);
+    for (i, &entity) in entities.iter().enumerate().skip(5) {
+        assert_eq!(world.get_component::<Tracked>(entity).unwrap().1, i as u64);
+    }
+
+    drop(world);
+    assert_eq!(drops.load(Ordering::Relaxed), 11);
+}
+
+#[test]
+fn zero_sized_components() {
+    let mut world = World::new();
+    let entities: Vec<_> = (0..1000)
+        .map(|i| {
+            if i % 2 == 0 {
+                world.spawn((Marker, Position(i as f32, 0.0)))
+            } else {
+                world.spawn(Unit)
+            }
+        })
+        .collect();
+    world.add_component(entities[0], Unit).unwrap();
+    world.remove_component::<Marker>(entities[2]).unwrap();
+    world.delete_entity(entities[4]).unwrap();
+
+    let query = world.query_filtered::<Entity, With<Marker>>().unwrap();
+    assert_eq!(query.len(), 498);
+    drop(query);
+    let query = world.query::<(Entity, &Unit)>().unwrap();
+    assert_eq!(query.len(), 501);
+    assert!(query.iter().all(|(_, unit)| *unit == Unit));
+    drop(query);
+
+    assert!(world.get_component::<Marker>(entities[0]).is_ok());
+    assert!(world.get_component::<Marker>(entities[2]).is_err());
+    assert_eq!(
+        *world.get_component::<Position>(entities[2]).unwrap(),
+        Position(2.0, 0.0)
+    );
+}
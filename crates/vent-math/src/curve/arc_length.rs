@@ -0,0 +1,66 @@
+use super::{Curve, CurvePoint};
+
+/// Maps distances along a curve to curve parameters, so the curve can be traversed with a
+/// constant speed.
+///
+/// The curve is approximated by straight lines between evenly spaced samples of `t`, more
+/// samples make the mapping more accurate.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ArcLengthTable {
+    max_t: f32,
+    /// Distance from the start of the curve to every sample.
+    lengths: Vec<f32>,
+}
+
+impl ArcLengthTable {
+    /// Samples `curve` at `samples + 1` evenly spaced parameters.
+    #[must_use]
+    pub fn new<P: CurvePoint>(curve: &impl Curve<P>, samples: usize) -> Self {
+        let samples = samples.max(1);
+        let max_t = curve.max_t();
+        let mut lengths = Vec::with_capacity(samples + 1);
+        let mut length = 0.0;
+        let mut previous = curve.position(0.0);
+        lengths.push(0.0);
+        for i in 1..=samples {
+            let point = curve.position(max_t * i as f32 / samples as f32);
+            length += point.distance(previous);
+            lengths.push(length);
+            previous = point;
+        }
+        Self { max_t, lengths }
+    }
+
+    /// Returns the approximated length of the whole curve.
+    #[inline]
+    pub fn length(&self) -> f32 {
+        *self.lengths.last().unwrap()
+    }
+
+    /// Returns the parameter `t` at `distance` along the curve, clamped to the curve.
+    pub fn t_at_distance(&self, distance: f32) -> f32 {
+        let samples = self.lengths.len() - 1;
+        if distance <= 0.0 {
+            return 0.0;
+        }
+        if distance >= self.length() {
+            return self.max_t;
+        }
+        // First sample at or beyond `distance`, never the first one
+        let i = self.lengths.partition_point(|&length| length < distance);
+        let (start, end) = (self.lengths[i - 1], self.lengths[i]);
+        let fraction = if end > start {
+            (distance - start) / (end - start)
+        } else {
+            0.0
+        };
+        self.max_t * ((i - 1) as f32 + fraction) / samples as f32
+    }
+
+    /// Returns the parameter `t` at `fraction` of the curve's length, where `0.0` is the start
+    /// and `1.0` the end.
+    #[inline]
+    pub fn t_at_fraction(&self, fraction: f32) -> f32 {
+        self.t_at_distance(fraction * self.length())
+    }
+}
@@ -0,0 +1,60 @@
+use super::{Curve, CurvePoint};
+
+/// A cubic Bézier curve from `p0` to `p3`, pulled towards the control points `p1` and `p2`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubicBezier<P> {
+    pub p0: P,
+    pub p1: P,
+    pub p2: P,
+    pub p3: P,
+}
+
+impl<P: CurvePoint> CubicBezier<P> {
+    #[inline]
+    #[must_use]
+    pub const fn new(p0: P, p1: P, p2: P, p3: P) -> Self {
+        Self { p0, p1, p2, p3 }
+    }
+
+    /// Splits the curve at `t` into two curves covering `0.0..=t` and `t..=1.0`.
+    #[must_use]
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        // De Casteljau's algorithm
+        let lerp = |a: P, b: P| a + (b - a) * t;
+        let p01 = lerp(self.p0, self.p1);
+        let p12 = lerp(self.p1, self.p2);
+        let p23 = lerp(self.p2, self.p3);
+        let p012 = lerp(p01, p12);
+        let p123 = lerp(p12, p23);
+        let mid = lerp(p012, p123);
+        (
+            Self::new(self.p0, p01, p012, mid),
+            Self::new(mid, p123, p23, self.p3),
+        )
+    }
+}
+
+impl<P: CurvePoint> Curve<P> for CubicBezier<P> {
+    #[inline]
+    fn max_t(&self) -> f32 {
+        1.0
+    }
+
+    #[inline]
+    fn position(&self, t: f32) -> P {
+        let u = 1.0 - t;
+        self.p0 * (u * u * u)
+            + self.p1 * (3.0 * u * u * t)
+            + self.p2 * (3.0 * u * t * t)
+            + self.p3 * (t * t * t)
+    }
+
+    #[inline]
+    fn velocity(&self, t: f32) -> P {
+        let u = 1.0 - t;
+        (self.p1 - self.p0) * (3.0 * u * u)
+            + (self.p2 - self.p1) * (6.0 * u * t)
+            + (self.p3 - self.p2) * (3.0 * t * t)
+    }
+}
@@ -0,0 +1,66 @@
+use super::{hermite::CubicHermite, Curve, CurvePoint};
+
+/// A uniform Catmull-Rom spline passing through all of its points.
+///
+/// Segment `i` runs from `points[i]` at `t = i` to `points[i + 1]` at `t = i + 1`, the
+/// tangent at each point is parallel to the line between its neighbours. `t` outside of
+/// `0.0..=max_t` extrapolates the first or last segment.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CatmullRom<P> {
+    pub points: Vec<P>,
+}
+
+impl<P: CurvePoint> CatmullRom<P> {
+    /// Creates a spline through `points`, which needs at least two points.
+    #[must_use]
+    pub fn new(points: Vec<P>) -> Self {
+        assert!(
+            points.len() >= 2,
+            "A Catmull-Rom spline needs at least 2 points"
+        );
+        Self { points }
+    }
+
+    /// Returns the tangent at `points[i]`, the end points use their only neighbour.
+    fn tangent(&self, i: usize) -> P {
+        let last = self.points.len() - 1;
+        let prev = self.points[i.saturating_sub(1)];
+        let next = self.points[(i + 1).min(last)];
+        if i == 0 || i == last {
+            next - prev
+        } else {
+            (next - prev) * 0.5
+        }
+    }
+
+    /// Returns segment `floor(t)` as a Hermite curve together with `t` relative to it.
+    pub fn segment(&self, t: f32) -> (CubicHermite<P>, f32) {
+        let segments = self.points.len() - 1;
+        let i = (t.max(0.0) as usize).min(segments - 1);
+        let segment = CubicHermite::new(
+            self.points[i],
+            self.tangent(i),
+            self.points[i + 1],
+            self.tangent(i + 1),
+        );
+        (segment, t - i as f32)
+    }
+}
+
+impl<P: CurvePoint> Curve<P> for CatmullRom<P> {
+    #[inline]
+    fn max_t(&self) -> f32 {
+        (self.points.len() - 1) as f32
+    }
+
+    fn position(&self, t: f32) -> P {
+        let (segment, t) = self.segment(t);
+        segment.position(t)
+    }
+
+    fn velocity(&self, t: f32) -> P {
+        let (segment, t) = self.segment(t);
+        segment.velocity(t)
+    }
+}
@@ -0,0 +1,161 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+/// An easing function, mapping the progress of a transition to the progress of its value.
+///
+/// `In` functions start slow, `Out` functions end slow and `InOut` functions do both. The
+/// curves follow the common definitions from <https://easings.net>.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    Linear,
+    QuadraticIn,
+    QuadraticOut,
+    QuadraticInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuarticIn,
+    QuarticOut,
+    QuarticInOut,
+    QuinticIn,
+    QuinticOut,
+    QuinticInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExponentialIn,
+    ExponentialOut,
+    ExponentialInOut,
+    CircularIn,
+    CircularOut,
+    CircularInOut,
+    /// Overshoots below `0.0` before moving towards `1.0`.
+    BackIn,
+    /// Overshoots beyond `1.0` before settling.
+    BackOut,
+    BackInOut,
+    /// Oscillates with a growing amplitude.
+    ElasticIn,
+    /// Oscillates around `1.0` with a decaying amplitude, like a spring.
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    /// Bounces off `1.0` like a dropped ball.
+    BounceOut,
+    BounceInOut,
+}
+
+impl Easing {
+    /// Returns the eased value of `t`, which is clamped to `0.0..=1.0`.
+    ///
+    /// Every function maps `0.0` to `0.0` and `1.0` to `1.0`.
+    #[must_use]
+    pub fn ease(self, t: f32) -> f32 {
+        const BACK: f32 = 1.70158;
+        const BACK_IN_OUT: f32 = BACK * 1.525;
+        const ELASTIC: f32 = 2.0 * PI / 3.0;
+        const ELASTIC_IN_OUT: f32 = 2.0 * PI / 4.5;
+
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::QuadraticIn => t * t,
+            Self::QuadraticOut => 1.0 - (1.0 - t).powi(2),
+            Self::QuadraticInOut => in_out(t, |t| t * t),
+            Self::CubicIn => t.powi(3),
+            Self::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Self::CubicInOut => in_out(t, |t| t.powi(3)),
+            Self::QuarticIn => t.powi(4),
+            Self::QuarticOut => 1.0 - (1.0 - t).powi(4),
+            Self::QuarticInOut => in_out(t, |t| t.powi(4)),
+            Self::QuinticIn => t.powi(5),
+            Self::QuinticOut => 1.0 - (1.0 - t).powi(5),
+            Self::QuinticInOut => in_out(t, |t| t.powi(5)),
+            Self::SineIn => 1.0 - (t * FRAC_PI_2).cos(),
+            Self::SineOut => (t * FRAC_PI_2).sin(),
+            Self::SineInOut => (1.0 - (t * PI).cos()) * 0.5,
+            Self::ExponentialIn => exponential_in(t),
+            Self::ExponentialOut => 1.0 - exponential_in(1.0 - t),
+            Self::ExponentialInOut => in_out(t, exponential_in),
+            Self::CircularIn => 1.0 - (1.0 - t * t).sqrt(),
+            Self::CircularOut => (1.0 - (t - 1.0).powi(2)).sqrt(),
+            Self::CircularInOut => in_out(t, |t| 1.0 - (1.0 - t * t).sqrt()),
+            Self::BackIn => back_in(t, BACK),
+            Self::BackOut => 1.0 - back_in(1.0 - t, BACK),
+            Self::BackInOut => in_out(t, |t| back_in(t, BACK_IN_OUT)),
+            Self::ElasticIn => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else {
+                    -(2.0f32).powf(10.0 * t - 10.0) * ((10.0 * t - 10.75) * ELASTIC).sin()
+                }
+            }
+            Self::ElasticOut => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else {
+                    (2.0f32).powf(-10.0 * t) * ((10.0 * t - 0.75) * ELASTIC).sin() + 1.0
+                }
+            }
+            Self::ElasticInOut => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else if t < 0.5 {
+                    -(2.0f32).powf(20.0 * t - 10.0)
+                        * ((20.0 * t - 11.125) * ELASTIC_IN_OUT).sin()
+                        * 0.5
+                } else {
+                    (2.0f32).powf(-20.0 * t + 10.0)
+                        * ((20.0 * t - 11.125) * ELASTIC_IN_OUT).sin()
+                        * 0.5
+                        + 1.0
+                }
+            }
+            Self::BounceIn => 1.0 - bounce_out(1.0 - t),
+            Self::BounceOut => bounce_out(t),
+            Self::BounceInOut => in_out(t, |t| 1.0 - bounce_out(1.0 - t)),
+        }
+    }
+}
+
+/// Runs `ease_in` over the first half and its mirror image over the second half.
+#[inline]
+fn in_out(t: f32, ease_in: impl Fn(f32) -> f32) -> f32 {
+    if t < 0.5 {
+        ease_in(2.0 * t) * 0.5
+    } else {
+        1.0 - ease_in(2.0 - 2.0 * t) * 0.5
+    }
+}
+
+#[inline]
+fn exponential_in(t: f32) -> f32 {
+    if t == 0.0 {
+        0.0
+    } else {
+        (2.0f32).powf(10.0 * t - 10.0)
+    }
+}
+
+#[inline]
+fn back_in(t: f32, overshoot: f32) -> f32 {
+    t * t * ((overshoot + 1.0) * t - overshoot)
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
@@ -0,0 +1,46 @@
+use super::{Curve, CurvePoint};
+
+/// A cubic Hermite curve from `p0` to `p1`, leaving `p0` with the velocity `m0` and arriving
+/// at `p1` with the velocity `m1`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubicHermite<P> {
+    pub p0: P,
+    pub m0: P,
+    pub p1: P,
+    pub m1: P,
+}
+
+impl<P: CurvePoint> CubicHermite<P> {
+    #[inline]
+    #[must_use]
+    pub const fn new(p0: P, m0: P, p1: P, m1: P) -> Self {
+        Self { p0, m0, p1, m1 }
+    }
+}
+
+impl<P: CurvePoint> Curve<P> for CubicHermite<P> {
+    #[inline]
+    fn max_t(&self) -> f32 {
+        1.0
+    }
+
+    #[inline]
+    fn position(&self, t: f32) -> P {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        self.p0 * (2.0 * t3 - 3.0 * t2 + 1.0)
+            + self.m0 * (t3 - 2.0 * t2 + t)
+            + self.p1 * (3.0 * t2 - 2.0 * t3)
+            + self.m1 * (t3 - t2)
+    }
+
+    #[inline]
+    fn velocity(&self, t: f32) -> P {
+        let t2 = t * t;
+        self.p0 * (6.0 * t2 - 6.0 * t)
+            + self.m0 * (3.0 * t2 - 4.0 * t + 1.0)
+            + self.p1 * (6.0 * t - 6.0 * t2)
+            + self.m1 * (3.0 * t2 - 2.0 * t)
+    }
+}
@@ -0,0 +1,51 @@
+//! Splines, easing functions and arc-length parameterisation for animation, camera paths and
+//! UI transitions.
+
+pub mod arc_length;
+pub mod bezier;
+pub mod catmull_rom;
+pub mod easing;
+pub mod hermite;
+
+use std::ops::{Add, Mul, Sub};
+
+use crate::vec::{vec2::Vec2, vec3::Vec3};
+
+pub use arc_length::ArcLengthTable;
+pub use bezier::CubicBezier;
+pub use catmull_rom::CatmullRom;
+pub use easing::Easing;
+pub use hermite::CubicHermite;
+
+/// A point type curves can be built from, implemented for [`Vec2`] and [`Vec3`].
+pub trait CurvePoint:
+    Copy + Add<Output = Self> + Sub<Output = Self> + Mul<f32, Output = Self>
+{
+    fn distance(self, rhs: Self) -> f32;
+}
+
+impl CurvePoint for Vec2 {
+    #[inline]
+    fn distance(self, rhs: Self) -> f32 {
+        (self - rhs).length()
+    }
+}
+
+impl CurvePoint for Vec3 {
+    #[inline]
+    fn distance(self, rhs: Self) -> f32 {
+        (self - rhs).length()
+    }
+}
+
+/// A parametric curve, defined for `t` in `0.0..=max_t`.
+pub trait Curve<P: CurvePoint> {
+    /// Returns the end of the parameter range, the number of segments for splines.
+    fn max_t(&self) -> f32;
+
+    /// Returns the point at `t`.
+    fn position(&self, t: f32) -> P;
+
+    /// Returns the derivative of [`Curve::position`] at `t`.
+    fn velocity(&self, t: f32) -> P;
+}
@@ -0,0 +1,34 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::{
+    scalar::{
+        affine3a::Affine3A,
+        f64::{dmat4::DMat4, dquat::DQuat},
+        mat2::Mat2,
+        mat3::Mat3,
+        mat4::Mat4,
+        quat::Quat,
+    },
+    vec::{
+        f64::{dvec2::DVec2, dvec3::DVec3, dvec4::DVec4},
+        i32::{ivec2::IVec2, ivec3::IVec3, ivec4::IVec4},
+        u32::{uvec2::UVec2, uvec3::UVec3},
+        vec2::Vec2,
+        vec3::Vec3,
+        vec4::Vec4,
+    },
+};
+
+// SAFETY: every type is `repr(C)` or `repr(transparent)` over floats or integers of a
+// single size, so there is no padding and any bit pattern, including all zeros, is valid
+macro_rules! impl_pod {
+    ($($ty:ty),*) => {
+        $(
+            unsafe impl Zeroable for $ty {}
+            unsafe impl Pod for $ty {}
+        )*
+    };
+}
+
+impl_pod!(Vec2, Vec3, Vec4, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, UVec2, UVec3);
+impl_pod!(Quat, DQuat, Mat2, Mat3, Mat4, DMat4, Affine3A);
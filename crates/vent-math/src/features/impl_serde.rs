@@ -0,0 +1,55 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    scalar::{
+        f64::{dmat4::DMat4, dquat::DQuat},
+        mat2::Mat2,
+        mat3::Mat3,
+        mat4::Mat4,
+        quat::Quat,
+    },
+    vec::{
+        f64::{dvec2::DVec2, dvec3::DVec3, dvec4::DVec4},
+        i32::{ivec2::IVec2, ivec3::IVec3, ivec4::IVec4},
+        u32::{uvec2::UVec2, uvec3::UVec3},
+        vec2::Vec2,
+        vec3::Vec3,
+        vec4::Vec4,
+    },
+};
+
+/// Serializes `$ty` as `$array`, vectors and quaternions become `[x, y, z]` and matrices
+/// arrays of their columns.
+macro_rules! impl_serde_array {
+    ($ty:ty, $array:ty) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                <$array>::from(*self).serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <$array>::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+impl_serde_array!(Vec2, [f32; 2]);
+impl_serde_array!(Vec3, [f32; 3]);
+impl_serde_array!(Vec4, [f32; 4]);
+impl_serde_array!(DVec2, [f64; 2]);
+impl_serde_array!(DVec3, [f64; 3]);
+impl_serde_array!(DVec4, [f64; 4]);
+impl_serde_array!(IVec2, [i32; 2]);
+impl_serde_array!(IVec3, [i32; 3]);
+impl_serde_array!(IVec4, [i32; 4]);
+impl_serde_array!(UVec2, [u32; 2]);
+impl_serde_array!(UVec3, [u32; 3]);
+impl_serde_array!(Quat, [f32; 4]);
+impl_serde_array!(DQuat, [f64; 4]);
+impl_serde_array!(Mat2, [[f32; 2]; 2]);
+impl_serde_array!(Mat3, [[f32; 3]; 3]);
+impl_serde_array!(Mat4, [[f32; 4]; 4]);
+impl_serde_array!(DMat4, [[f64; 4]; 4]);
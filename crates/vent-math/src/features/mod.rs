@@ -0,0 +1,7 @@
+//! Trait implementations for optional dependencies, each behind the cargo feature of the
+//! same name.
+
+#[cfg(feature = "bytemuck")]
+mod impl_bytemuck;
+#[cfg(feature = "serde")]
+mod impl_serde;
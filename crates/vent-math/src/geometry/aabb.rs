@@ -0,0 +1,135 @@
+use crate::{scalar::mat4::Mat4, vec::vec3::Vec3};
+
+use super::sphere::Sphere;
+
+/// An axis aligned bounding box.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    /// A box containing nothing, growing it by any point or box returns that point or box.
+    pub const EMPTY: Self = Self {
+        min: Vec3::INFINITY,
+        max: Vec3::NEG_INFINITY,
+    };
+
+    #[inline]
+    #[must_use]
+    pub const fn new(min: Vec3, max: Vec3) -> Self {
+        Self { min, max }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_center_half_extents(center: Vec3, half_extents: Vec3) -> Self {
+        Self::new(center - half_extents, center + half_extents)
+    }
+
+    /// Returns the smallest box containing all points, [`Aabb::EMPTY`] if there are none.
+    #[must_use]
+    pub fn from_points(points: impl IntoIterator<Item = Vec3>) -> Self {
+        points
+            .into_iter()
+            .fold(Self::EMPTY, |aabb, point| aabb.grow(point))
+    }
+
+    /// Returns `true` if the box contains nothing, i.e. `min` is greater than `max` on any axis.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn half_extents(&self) -> Vec3 {
+        (self.max - self.min) * 0.5
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min
+    }
+
+    /// Returns the box grown to contain `point`.
+    #[inline]
+    #[must_use]
+    pub fn grow(&self, point: Vec3) -> Self {
+        Self::new(self.min.min(point), self.max.max(point))
+    }
+
+    /// Returns the smallest box containing both boxes.
+    #[inline]
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(self.min.min(other.min), self.max.max(other.max))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        point.x >= self.min.x
+            && point.x <= self.max.x
+            && point.y >= self.min.y
+            && point.y <= self.max.y
+            && point.z >= self.min.z
+            && point.z <= self.max.z
+    }
+
+    /// Returns the point inside the box closest to `point`.
+    #[inline]
+    #[must_use]
+    pub fn closest_point(&self, point: Vec3) -> Vec3 {
+        point.max(self.min).min(self.max)
+    }
+
+    /// Returns `true` if the boxes overlap, touching boxes count as overlapping.
+    #[inline]
+    #[must_use]
+    pub fn intersects_aabb(&self, other: &Self) -> bool {
+        self.min.x <= other.max.x
+            && self.max.x >= other.min.x
+            && self.min.y <= other.max.y
+            && self.max.y >= other.min.y
+            && self.min.z <= other.max.z
+            && self.max.z >= other.min.z
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        sphere.intersects_aabb(self)
+    }
+
+    /// Returns the box containing this box after transforming it by `matrix`, e.g. to move a
+    /// mesh's local bounds into world space.
+    #[must_use]
+    pub fn transform(&self, matrix: &Mat4) -> Self {
+        // Arvo's method: the extents of the transformed box only depend on the absolute
+        // values of the rotation and scale
+        let center = matrix.transform_point3(self.center());
+        let half_extents = self.half_extents();
+        let extents = matrix.x_axis.xyz().abs() * half_extents.x
+            + matrix.y_axis.xyz().abs() * half_extents.y
+            + matrix.z_axis.xyz().abs() * half_extents.z;
+        Self::from_center_half_extents(center, extents)
+    }
+
+    /// Returns whether all elements of `self` and `rhs` differ by at most `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.min.abs_diff_eq(rhs.min, max_abs_diff) && self.max.abs_diff_eq(rhs.max, max_abs_diff)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{scalar::mat4::Mat4, vec::vec3::Vec3};
+
+use super::{aabb::Aabb, plane::Plane, sphere::Sphere};
+
+/// The volume visible to a camera, bounded by six planes whose normals point inside.
+///
+/// The tests are conservative: objects near the corners outside of the frustum may be
+/// reported as intersecting, but visible objects are never culled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frustum {
+    /// Left, right, bottom, top, near and far plane.
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// Extracts the planes of a view projection matrix with a `[0, 1]` depth range, like
+    /// the ones built from [`Mat4::perspective_rh`] and [`Mat4::orthographic_rh`].
+    #[must_use]
+    pub fn from_view_proj(view_proj: &Mat4) -> Self {
+        // Gribb and Hartmann: the clip space tests `-w <= x <= w`, `-w <= y <= w` and
+        // `0 <= z <= w` turn into plane equations on the rows of the matrix
+        let rows = view_proj.transpose();
+        let (x, y, z, w) = (rows.x_axis, rows.y_axis, rows.z_axis, rows.w_axis);
+        Self {
+            planes: [
+                Plane::from_vec4(w + x),
+                Plane::from_vec4(w - x),
+                Plane::from_vec4(w + y),
+                Plane::from_vec4(w - y),
+                Plane::from_vec4(z),
+                Plane::from_vec4(w - z),
+            ],
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(point) >= 0.0)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn intersects_sphere(&self, sphere: &Sphere) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(sphere.center) >= -sphere.radius)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        let center = aabb.center();
+        let half_extents = aabb.half_extents();
+        self.planes.iter().all(|plane| {
+            // Distance of the corner furthest along the normal
+            let radius = half_extents.dot(plane.normal.abs());
+            plane.signed_distance(center) >= -radius
+        })
+    }
+
+    /// Returns whether all elements of `self` and `rhs` differ by at most `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.planes
+            .iter()
+            .zip(&rhs.planes)
+            .all(|(a, b)| a.abs_diff_eq(*b, max_abs_diff))
+    }
+}
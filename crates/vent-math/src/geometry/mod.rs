@@ -0,0 +1,13 @@
+//! Bounding volumes and intersection tests for culling and picking.
+
+pub mod aabb;
+pub mod frustum;
+pub mod plane;
+pub mod ray;
+pub mod sphere;
+
+pub use aabb::Aabb;
+pub use frustum::Frustum;
+pub use plane::Plane;
+pub use ray::Ray;
+pub use sphere::Sphere;
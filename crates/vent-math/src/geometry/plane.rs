@@ -0,0 +1,66 @@
+use crate::vec::{vec3::Vec3, vec4::Vec4};
+
+/// A plane of all points `p` with `normal.dot(p) + d == 0.0`.
+///
+/// Points on the side the normal points to have a positive signed distance.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plane {
+    pub normal: Vec3,
+    pub d: f32,
+}
+
+impl Plane {
+    /// Creates a plane from its normal, which must be normalized, and its distance term.
+    #[inline]
+    #[must_use]
+    pub const fn new(normal: Vec3, d: f32) -> Self {
+        Self { normal, d }
+    }
+
+    /// Creates a plane through `point`, `normal` must be normalized.
+    #[inline]
+    #[must_use]
+    pub fn from_point_normal(point: Vec3, normal: Vec3) -> Self {
+        Self::new(normal, -normal.dot(point))
+    }
+
+    /// Creates the plane through three points, the normal faces the side from which the
+    /// points appear counter clockwise.
+    #[inline]
+    #[must_use]
+    pub fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        Self::from_point_normal(a, (b - a).cross(c - a).normalize())
+    }
+
+    /// Creates a plane from the `(a, b, c, d)` coefficients of `ax + by + cz + d = 0`,
+    /// normalizing them.
+    #[inline]
+    #[must_use]
+    pub fn from_vec4(coefficients: Vec4) -> Self {
+        let normal = coefficients.xyz();
+        let rcp_length = normal.length_recip();
+        Self::new(normal * rcp_length, coefficients.w * rcp_length)
+    }
+
+    /// Returns the distance of `point` to the plane, negative behind the plane.
+    #[inline]
+    #[must_use]
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        self.normal.dot(point) + self.d
+    }
+
+    /// Returns the point on the plane closest to `point`.
+    #[inline]
+    #[must_use]
+    pub fn project_point(&self, point: Vec3) -> Vec3 {
+        point - self.normal * self.signed_distance(point)
+    }
+
+    /// Returns whether all elements of `self` and `rhs` differ by at most `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.normal.abs_diff_eq(rhs.normal, max_abs_diff) && (self.d - rhs.d).abs() <= max_abs_diff
+    }
+}
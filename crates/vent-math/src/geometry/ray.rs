@@ -0,0 +1,112 @@
+use crate::vec::vec3::Vec3;
+
+use super::{aabb::Aabb, plane::Plane, sphere::Sphere};
+
+/// A half line starting at `origin`, e.g. a picking ray from the camera through the cursor.
+///
+/// Intersections return the distance `t` along the ray, the hit point is [`Ray::at`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray {
+    pub origin: Vec3,
+    /// Normalized direction of the ray.
+    pub direction: Vec3,
+}
+
+impl Ray {
+    /// Creates a ray, normalizing `direction`.
+    #[inline]
+    #[must_use]
+    pub fn new(origin: Vec3, direction: Vec3) -> Self {
+        Self {
+            origin,
+            direction: direction.normalize(),
+        }
+    }
+
+    /// Returns the point at distance `t` along the ray.
+    #[inline]
+    #[must_use]
+    pub fn at(&self, t: f32) -> Vec3 {
+        self.origin + self.direction * t
+    }
+
+    /// Returns the distance to the first intersection with the box, `0.0` if the ray starts
+    /// inside of it.
+    #[must_use]
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<f32> {
+        // Slab test, the division by zero of axis parallel rays yields infinities which
+        // compare correctly
+        let rcp_direction = 1.0 / self.direction;
+        let t1 = (aabb.min - self.origin) * rcp_direction;
+        let t2 = (aabb.max - self.origin) * rcp_direction;
+        let t_min = t1.min(t2);
+        let t_max = t1.max(t2);
+        let near = t_min.x.max(t_min.y).max(t_min.z).max(0.0);
+        let far = t_max.x.min(t_max.y).min(t_max.z);
+        (near <= far).then_some(near)
+    }
+
+    /// Returns the distance to the first intersection with the sphere, `0.0` if the ray
+    /// starts inside of it.
+    #[must_use]
+    pub fn intersect_sphere(&self, sphere: &Sphere) -> Option<f32> {
+        let offset = self.origin - sphere.center;
+        let b = offset.dot(self.direction);
+        let c = offset.length_squared() - sphere.radius * sphere.radius;
+        // Starts outside and points away
+        if c > 0.0 && b > 0.0 {
+            return None;
+        }
+        let discriminant = b * b - c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        Some((-b - discriminant.sqrt()).max(0.0))
+    }
+
+    /// Returns the distance to the plane, `None` if the ray is parallel to it or points away.
+    #[must_use]
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<f32> {
+        let denominator = plane.normal.dot(self.direction);
+        if denominator.abs() <= f32::EPSILON {
+            return None;
+        }
+        let t = -plane.signed_distance(self.origin) / denominator;
+        (t >= 0.0).then_some(t)
+    }
+
+    /// Returns the distance to the triangle `a`, `b`, `c`, hitting both sides.
+    #[must_use]
+    pub fn intersect_triangle(&self, a: Vec3, b: Vec3, c: Vec3) -> Option<f32> {
+        // Möller–Trumbore
+        let edge1 = b - a;
+        let edge2 = c - a;
+        let p = self.direction.cross(edge2);
+        let det = edge1.dot(p);
+        if det.abs() <= f32::EPSILON {
+            return None;
+        }
+        let rcp_det = det.recip();
+        let offset = self.origin - a;
+        let u = offset.dot(p) * rcp_det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let q = offset.cross(edge1);
+        let v = self.direction.dot(q) * rcp_det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+        let t = edge2.dot(q) * rcp_det;
+        (t >= 0.0).then_some(t)
+    }
+
+    /// Returns whether all elements of `self` and `rhs` differ by at most `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.origin.abs_diff_eq(rhs.origin, max_abs_diff)
+            && self.direction.abs_diff_eq(rhs.direction, max_abs_diff)
+    }
+}
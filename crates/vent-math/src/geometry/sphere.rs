@@ -0,0 +1,53 @@
+use crate::vec::vec3::Vec3;
+
+use super::aabb::Aabb;
+
+/// A bounding sphere.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+impl Sphere {
+    #[inline]
+    #[must_use]
+    pub const fn new(center: Vec3, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    /// Returns the sphere enclosing the box.
+    #[inline]
+    #[must_use]
+    pub fn from_aabb(aabb: &Aabb) -> Self {
+        Self::new(aabb.center(), aabb.half_extents().length())
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        (point - self.center).length_squared() <= self.radius * self.radius
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn intersects_sphere(&self, other: &Self) -> bool {
+        let radius = self.radius + other.radius;
+        (other.center - self.center).length_squared() <= radius * radius
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn intersects_aabb(&self, aabb: &Aabb) -> bool {
+        self.contains_point(aabb.closest_point(self.center))
+    }
+
+    /// Returns whether all elements of `self` and `rhs` differ by at most `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.center.abs_diff_eq(rhs.center, max_abs_diff)
+            && (self.radius - rhs.radius).abs() <= max_abs_diff
+    }
+}
@@ -1,4 +1,7 @@
 pub mod align16;
+pub mod curve;
 pub mod deref;
+mod features;
+pub mod geometry;
 pub mod scalar;
 pub mod vec;
@@ -0,0 +1,198 @@
+use std::{
+    fmt,
+    ops::{Mul, MulAssign},
+};
+
+use crate::vec::{vec3::Vec3, vec4::Vec4};
+
+use super::{mat3::Mat3, mat4::Mat4, quat::Quat};
+
+/// A 3D affine transform, a 3x3 matrix followed by a translation.
+///
+/// Stores 12 instead of the 16 floats of a [`Mat4`] and is cheaper to multiply and invert,
+/// which makes it the better fit for object transforms. Convert to a [`Mat4`] for
+/// projections and shaders.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Affine3A {
+    pub matrix3: Mat3,
+    pub translation: Vec3,
+}
+
+impl Affine3A {
+    pub const IDENTITY: Self = Self {
+        matrix3: Mat3::IDENTITY,
+        translation: Vec3::ZERO,
+    };
+
+    #[inline]
+    #[must_use]
+    pub const fn from_mat3_translation(matrix3: Mat3, translation: Vec3) -> Self {
+        Self {
+            matrix3,
+            translation,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn from_translation(translation: Vec3) -> Self {
+        Self::from_mat3_translation(Mat3::IDENTITY, translation)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_scale(scale: Vec3) -> Self {
+        Self::from_mat3_translation(Mat3::from_scale(scale), Vec3::ZERO)
+    }
+
+    /// Creates a rotation, `rotation` must be normalized.
+    #[inline]
+    #[must_use]
+    pub fn from_quat(rotation: Quat) -> Self {
+        Self::from_mat3_translation(Mat3::from_quat(rotation), Vec3::ZERO)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_scale_rotation_translation(scale: Vec3, rotation: Quat, translation: Vec3) -> Self {
+        let rotation = Mat3::from_quat(rotation);
+        Self::from_mat3_translation(
+            Mat3::from_cols(
+                rotation.x_axis * scale.x,
+                rotation.y_axis * scale.y,
+                rotation.z_axis * scale.z,
+            ),
+            translation,
+        )
+    }
+
+    /// Drops the projective row of an affine 4x4 matrix.
+    #[inline]
+    #[must_use]
+    pub fn from_mat4(matrix: &Mat4) -> Self {
+        Self::from_mat3_translation(Mat3::from_mat4(matrix), matrix.w_axis.xyz())
+    }
+
+    /// Decomposes the transform into scale, rotation and translation.
+    ///
+    /// The transform must not contain shear, a negative determinant is folded into the
+    /// x scale.
+    #[inline]
+    #[must_use]
+    pub fn to_scale_rotation_translation(&self) -> (Vec3, Quat, Vec3) {
+        let det = self.matrix3.determinant();
+        let scale = Vec3::new(
+            self.matrix3.x_axis.length() * det.signum(),
+            self.matrix3.y_axis.length(),
+            self.matrix3.z_axis.length(),
+        );
+        let inv_scale = 1.0 / scale;
+        let rotation = Quat::from_rotation_axes(
+            self.matrix3.x_axis * inv_scale.x,
+            self.matrix3.y_axis * inv_scale.y,
+            self.matrix3.z_axis * inv_scale.z,
+        );
+        (scale, rotation, self.translation)
+    }
+
+    /// Transforms a point, applying the translation.
+    #[inline]
+    #[must_use]
+    pub fn transform_point3(&self, rhs: Vec3) -> Vec3 {
+        self.matrix3.mul_vec3(rhs) + self.translation
+    }
+
+    /// Transforms a direction, ignoring the translation.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector3(&self, rhs: Vec3) -> Vec3 {
+        self.matrix3.mul_vec3(rhs)
+    }
+
+    /// Returns the inverse transform, the 3x3 part must be invertible.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let matrix3 = self.matrix3.inverse();
+        let translation = -matrix3.mul_vec3(self.translation);
+        Self::from_mat3_translation(matrix3, translation)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn mul_affine(&self, rhs: &Self) -> Self {
+        Self::from_mat3_translation(
+            self.matrix3.mul_mat3(&rhs.matrix3),
+            self.transform_point3(rhs.translation),
+        )
+    }
+
+    /// Returns whether all elements of `self` and `rhs` differ by at most `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.matrix3.abs_diff_eq(rhs.matrix3, max_abs_diff)
+            && self.translation.abs_diff_eq(rhs.translation, max_abs_diff)
+    }
+}
+
+impl Mul<Affine3A> for Affine3A {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_affine(&rhs)
+    }
+}
+
+impl MulAssign<Affine3A> for Affine3A {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_affine(&rhs);
+    }
+}
+
+impl From<Affine3A> for Mat4 {
+    #[inline]
+    fn from(affine: Affine3A) -> Self {
+        Self::from_cols(
+            Vec4::from((affine.matrix3.x_axis, 0.0)),
+            Vec4::from((affine.matrix3.y_axis, 0.0)),
+            Vec4::from((affine.matrix3.z_axis, 0.0)),
+            Vec4::from((affine.translation, 1.0)),
+        )
+    }
+}
+
+impl Default for Affine3A {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl fmt::Display for Affine3A {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(
+                f,
+                "[{:.*}, {:.*}, {:.*}, {:.*}]",
+                p,
+                self.matrix3.x_axis,
+                p,
+                self.matrix3.y_axis,
+                p,
+                self.matrix3.z_axis,
+                p,
+                self.translation
+            )
+        } else {
+            write!(
+                f,
+                "[{}, {}, {}, {}]",
+                self.matrix3.x_axis, self.matrix3.y_axis, self.matrix3.z_axis, self.translation
+            )
+        }
+    }
+}
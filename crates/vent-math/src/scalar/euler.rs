@@ -0,0 +1,40 @@
+/// The order of the three rotations making up a set of Euler angles.
+///
+/// Rotations are intrinsic, e.g. `YXZ` rotates about the local y axis first, then about the
+/// rotated x axis and last about the twice rotated z axis, so
+/// `Quat::from_euler(EulerRot::YXZ, a, b, c)` equals
+/// `Quat::from_rotation_y(a) * Quat::from_rotation_x(b) * Quat::from_rotation_z(c)`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EulerRot {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerRot {
+    /// Returns the indices of the first, second and third rotation axis.
+    #[inline]
+    pub(crate) const fn axes(self) -> (usize, usize, usize) {
+        match self {
+            EulerRot::XYZ => (0, 1, 2),
+            EulerRot::XZY => (0, 2, 1),
+            EulerRot::YXZ => (1, 0, 2),
+            EulerRot::YZX => (1, 2, 0),
+            EulerRot::ZXY => (2, 0, 1),
+            EulerRot::ZYX => (2, 1, 0),
+        }
+    }
+
+    /// Returns `1.0` for cyclic orders and `-1.0` for the others.
+    #[inline]
+    pub(crate) const fn parity(self) -> f32 {
+        match self {
+            EulerRot::XYZ | EulerRot::YZX | EulerRot::ZXY => 1.0,
+            EulerRot::XZY | EulerRot::YXZ | EulerRot::ZYX => -1.0,
+        }
+    }
+}
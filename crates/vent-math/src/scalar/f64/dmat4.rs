@@ -0,0 +1,458 @@
+use std::{
+    fmt,
+    ops::{Add, Mul, MulAssign, Sub},
+};
+
+use crate::{
+    scalar::mat4::Mat4,
+    vec::f64::{dvec3::DVec3, dvec4::DVec4},
+};
+
+use super::dquat::DQuat;
+
+/// A double precision column major 4x4 matrix, see [`Mat4`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct DMat4 {
+    pub x_axis: DVec4,
+    pub y_axis: DVec4,
+    pub z_axis: DVec4,
+    pub w_axis: DVec4,
+}
+
+impl DMat4 {
+    pub const IDENTITY: Self = Self::from_cols(DVec4::X, DVec4::Y, DVec4::Z, DVec4::W);
+
+    #[allow(clippy::too_many_arguments)]
+    #[allow(dead_code)]
+    #[must_use]
+    const fn new(
+        m00: f64,
+        m01: f64,
+        m02: f64,
+        m03: f64,
+        m10: f64,
+        m11: f64,
+        m12: f64,
+        m13: f64,
+        m20: f64,
+        m21: f64,
+        m22: f64,
+        m23: f64,
+        m30: f64,
+        m31: f64,
+        m32: f64,
+        m33: f64,
+    ) -> Self {
+        Self {
+            x_axis: DVec4::new(m00, m01, m02, m03),
+            y_axis: DVec4::new(m10, m11, m12, m13),
+            z_axis: DVec4::new(m20, m21, m22, m23),
+            w_axis: DVec4::new(m30, m31, m32, m33),
+        }
+    }
+
+    #[must_use]
+    pub const fn from_cols(x_axis: DVec4, y_axis: DVec4, z_axis: DVec4, w_axis: DVec4) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+            w_axis,
+        }
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    #[must_use]
+    pub fn as_mat4(&self) -> Mat4 {
+        Mat4::from_cols(
+            self.x_axis.as_vec4(),
+            self.y_axis.as_vec4(),
+            self.z_axis.as_vec4(),
+            self.w_axis.as_vec4(),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    fn quat_to_axes(rotation: DQuat) -> (DVec4, DVec4, DVec4) {
+        let (x, y, z, w) = rotation.into();
+        let x2 = x + x;
+        let y2 = y + y;
+        let z2 = z + z;
+        let xx = x * x2;
+        let xy = x * y2;
+        let xz = x * z2;
+        let yy = y * y2;
+        let yz = y * z2;
+        let zz = z * z2;
+        let wx = w * x2;
+        let wy = w * y2;
+        let wz = w * z2;
+
+        let x_axis = DVec4::new(1.0 - (yy + zz), xy + wz, xz - wy, 0.0);
+        let y_axis = DVec4::new(xy - wz, 1.0 - (xx + zz), yz + wx, 0.0);
+        let z_axis = DVec4::new(xz + wy, yz - wx, 1.0 - (xx + yy), 0.0);
+        (x_axis, y_axis, z_axis)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_translation(translation: DVec3) -> Self {
+        Self::from_cols(
+            DVec4::X,
+            DVec4::Y,
+            DVec4::Z,
+            DVec4::from((translation, 1.0)),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_scale(scale: DVec3) -> Self {
+        Self::from_cols(
+            DVec4::X.mul(scale.x),
+            DVec4::Y.mul(scale.y),
+            DVec4::Z.mul(scale.z),
+            DVec4::W,
+        )
+    }
+
+    /// Creates a rotation matrix, `rotation` must be normalized.
+    #[inline]
+    #[must_use]
+    pub fn from_quat(rotation: DQuat) -> Self {
+        let (x_axis, y_axis, z_axis) = Self::quat_to_axes(rotation);
+        Self::from_cols(x_axis, y_axis, z_axis, DVec4::W)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_translation(rotation: DQuat, translation: DVec3) -> Self {
+        let (x_axis, y_axis, z_axis) = Self::quat_to_axes(rotation);
+        Self::from_cols(x_axis, y_axis, z_axis, DVec4::from((translation, 1.0)))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_scale_rotation_translation(
+        scale: DVec3,
+        rotation: DQuat,
+        translation: DVec3,
+    ) -> Self {
+        let (x_axis, y_axis, z_axis) = Self::quat_to_axes(rotation);
+        Self::from_cols(
+            x_axis.mul(scale.x),
+            y_axis.mul(scale.y),
+            z_axis.mul(scale.z),
+            DVec4::from((translation, 1.0)),
+        )
+    }
+
+    /// Decomposes the matrix into scale, rotation and translation.
+    ///
+    /// The matrix must be an affine transform without shear, a negative determinant is
+    /// folded into the x scale.
+    #[inline]
+    #[must_use]
+    pub fn to_scale_rotation_translation(&self) -> (DVec3, DQuat, DVec3) {
+        let det = self.determinant();
+        let x_axis = DVec3::new(self.x_axis.x, self.x_axis.y, self.x_axis.z);
+        let y_axis = DVec3::new(self.y_axis.x, self.y_axis.y, self.y_axis.z);
+        let z_axis = DVec3::new(self.z_axis.x, self.z_axis.y, self.z_axis.z);
+        let scale = DVec3::new(
+            x_axis.length() * det.signum(),
+            y_axis.length(),
+            z_axis.length(),
+        );
+        let inv_scale = 1.0 / scale;
+        let rotation = DQuat::from_rotation_axes(
+            x_axis * inv_scale.x,
+            y_axis * inv_scale.y,
+            z_axis * inv_scale.z,
+        );
+        let translation = DVec3::new(self.w_axis.x, self.w_axis.y, self.w_axis.z);
+        (scale, rotation, translation)
+    }
+
+    #[inline]
+    pub fn look_at_rh(eye: DVec3, center: DVec3, up: DVec3) -> Self {
+        Self::look_to_rh(eye, center.sub(eye), up)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn look_to_rh(eye: DVec3, dir: DVec3, up: DVec3) -> Self {
+        let f = dir.normalize();
+        let s = f.cross(up).normalize();
+        let u = s.cross(f);
+
+        Self::from_cols(
+            DVec4::new(s.x, u.x, -f.x, 0.0),
+            DVec4::new(s.y, u.y, -f.y, 0.0),
+            DVec4::new(s.z, u.z, -f.z, 0.0),
+            DVec4::new(-eye.dot(s), -eye.dot(u), eye.dot(f), 1.0),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn perspective_rh(fov_y_radians: f64, aspect_ratio: f64, z_near: f64, z_far: f64) -> Self {
+        let (sin_fov, cos_fov) = (0.5 * fov_y_radians).sin_cos();
+        let h = cos_fov / sin_fov;
+        let w = h / aspect_ratio;
+        let r = z_far / (z_near - z_far);
+        Self::from_cols(
+            DVec4::new(w, 0.0, 0.0, 0.0),
+            DVec4::new(0.0, h, 0.0, 0.0),
+            DVec4::new(0.0, 0.0, r, -1.0),
+            DVec4::new(0.0, 0.0, r * z_near, 0.0),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn orthographic_rh(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> Self {
+        let rcp_width = 1.0 / (right - left);
+        let rcp_height = 1.0 / (top - bottom);
+        let r = 1.0 / (near - far);
+        Self::from_cols(
+            DVec4::new(rcp_width + rcp_width, 0.0, 0.0, 0.0),
+            DVec4::new(0.0, rcp_height + rcp_height, 0.0, 0.0),
+            DVec4::new(0.0, 0.0, r, 0.0),
+            DVec4::new(
+                -(left + right) * rcp_width,
+                -(top + bottom) * rcp_height,
+                r * near,
+                1.0,
+            ),
+        )
+    }
+
+    /// Returns the transpose, swapping rows and columns.
+    #[inline]
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_cols(
+            DVec4::new(self.x_axis.x, self.y_axis.x, self.z_axis.x, self.w_axis.x),
+            DVec4::new(self.x_axis.y, self.y_axis.y, self.z_axis.y, self.w_axis.y),
+            DVec4::new(self.x_axis.z, self.y_axis.z, self.z_axis.z, self.w_axis.z),
+            DVec4::new(self.x_axis.w, self.y_axis.w, self.z_axis.w, self.w_axis.w),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn determinant(&self) -> f64 {
+        let (m00, m01, m02, m03) = self.x_axis.into();
+        let (m10, m11, m12, m13) = self.y_axis.into();
+        let (m20, m21, m22, m23) = self.z_axis.into();
+        let (m30, m31, m32, m33) = self.w_axis.into();
+
+        let a2323 = m22 * m33 - m23 * m32;
+        let a1323 = m21 * m33 - m23 * m31;
+        let a1223 = m21 * m32 - m22 * m31;
+        let a0323 = m20 * m33 - m23 * m30;
+        let a0223 = m20 * m32 - m22 * m30;
+        let a0123 = m20 * m31 - m21 * m30;
+
+        m00 * (m11 * a2323 - m12 * a1323 + m13 * a1223)
+            - m01 * (m10 * a2323 - m12 * a0323 + m13 * a0223)
+            + m02 * (m10 * a1323 - m11 * a0323 + m13 * a0123)
+            - m03 * (m10 * a1223 - m11 * a0223 + m12 * a0123)
+    }
+
+    /// Returns the inverse of the matrix, which must be invertible.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let (m00, m01, m02, m03) = self.x_axis.into();
+        let (m10, m11, m12, m13) = self.y_axis.into();
+        let (m20, m21, m22, m23) = self.z_axis.into();
+        let (m30, m31, m32, m33) = self.w_axis.into();
+
+        let coef00 = m22 * m33 - m32 * m23;
+        let coef02 = m12 * m33 - m32 * m13;
+        let coef03 = m12 * m23 - m22 * m13;
+
+        let coef04 = m21 * m33 - m31 * m23;
+        let coef06 = m11 * m33 - m31 * m13;
+        let coef07 = m11 * m23 - m21 * m13;
+
+        let coef08 = m21 * m32 - m31 * m22;
+        let coef10 = m11 * m32 - m31 * m12;
+        let coef11 = m11 * m22 - m21 * m12;
+
+        let coef12 = m20 * m33 - m30 * m23;
+        let coef14 = m10 * m33 - m30 * m13;
+        let coef15 = m10 * m23 - m20 * m13;
+
+        let coef16 = m20 * m32 - m30 * m22;
+        let coef18 = m10 * m32 - m30 * m12;
+        let coef19 = m10 * m22 - m20 * m12;
+
+        let coef20 = m20 * m31 - m30 * m21;
+        let coef22 = m10 * m31 - m30 * m11;
+        let coef23 = m10 * m21 - m20 * m11;
+
+        let fac0 = DVec4::new(coef00, coef00, coef02, coef03);
+        let fac1 = DVec4::new(coef04, coef04, coef06, coef07);
+        let fac2 = DVec4::new(coef08, coef08, coef10, coef11);
+        let fac3 = DVec4::new(coef12, coef12, coef14, coef15);
+        let fac4 = DVec4::new(coef16, coef16, coef18, coef19);
+        let fac5 = DVec4::new(coef20, coef20, coef22, coef23);
+
+        let vec0 = DVec4::new(m10, m00, m00, m00);
+        let vec1 = DVec4::new(m11, m01, m01, m01);
+        let vec2 = DVec4::new(m12, m02, m02, m02);
+        let vec3 = DVec4::new(m13, m03, m03, m03);
+
+        let inv0 = vec1.mul(fac0).sub(vec2.mul(fac1)).add(vec3.mul(fac2));
+        let inv1 = vec0.mul(fac0).sub(vec2.mul(fac3)).add(vec3.mul(fac4));
+        let inv2 = vec0.mul(fac1).sub(vec1.mul(fac3)).add(vec3.mul(fac5));
+        let inv3 = vec0.mul(fac2).sub(vec1.mul(fac4)).add(vec2.mul(fac5));
+
+        let sign_a = DVec4::new(1.0, -1.0, 1.0, -1.0);
+        let sign_b = DVec4::new(-1.0, 1.0, -1.0, 1.0);
+
+        let inv0 = inv0.mul(sign_a);
+        let inv1 = inv1.mul(sign_b);
+        let inv2 = inv2.mul(sign_a);
+        let inv3 = inv3.mul(sign_b);
+
+        let det = m00 * inv0.x + m01 * inv1.x + m02 * inv2.x + m03 * inv3.x;
+        let rcp_det = det.recip();
+        Self::from_cols(
+            inv0.mul(rcp_det),
+            inv1.mul(rcp_det),
+            inv2.mul(rcp_det),
+            inv3.mul(rcp_det),
+        )
+    }
+
+    /// Transforms a point, applying the translation.
+    #[inline]
+    #[must_use]
+    pub fn transform_point3(&self, rhs: DVec3) -> DVec3 {
+        let res = self.mul_vec4(DVec4::from((rhs, 1.0)));
+        DVec3::new(res.x, res.y, res.z)
+    }
+
+    /// Transforms a direction, ignoring the translation.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector3(&self, rhs: DVec3) -> DVec3 {
+        let res = self.mul_vec4(DVec4::from((rhs, 0.0)));
+        DVec3::new(res.x, res.y, res.z)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn mul_mat4(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.mul(rhs.x_axis),
+            self.mul(rhs.y_axis),
+            self.mul(rhs.z_axis),
+            self.mul(rhs.w_axis),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn mul_vec4(&self, rhs: DVec4) -> DVec4 {
+        let mut res = self.x_axis.mul(rhs.x);
+        res = res.add(self.y_axis.mul(rhs.y));
+        res = res.add(self.z_axis.mul(rhs.z));
+        res = res.add(self.w_axis.mul(rhs.w));
+        res
+    }
+
+    /// Returns whether all elements of `self` and `rhs` differ by at most `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f64) -> bool {
+        self.x_axis.abs_diff_eq(rhs.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(rhs.y_axis, max_abs_diff)
+            && self.z_axis.abs_diff_eq(rhs.z_axis, max_abs_diff)
+            && self.w_axis.abs_diff_eq(rhs.w_axis, max_abs_diff)
+    }
+}
+
+impl Mul<DMat4> for DMat4 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.mul_mat4(&rhs)
+    }
+}
+
+impl MulAssign<DMat4> for DMat4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_mat4(&rhs);
+    }
+}
+
+impl Mul<DVec4> for DMat4 {
+    type Output = DVec4;
+    #[inline]
+    fn mul(self, rhs: DVec4) -> Self::Output {
+        self.mul_vec4(rhs)
+    }
+}
+
+impl From<[[f64; 4]; 4]> for DMat4 {
+    #[inline]
+    fn from(cols: [[f64; 4]; 4]) -> Self {
+        Self::from_cols(
+            cols[0].into(),
+            cols[1].into(),
+            cols[2].into(),
+            cols[3].into(),
+        )
+    }
+}
+
+impl From<DMat4> for [[f64; 4]; 4] {
+    #[inline]
+    fn from(matrix: DMat4) -> Self {
+        [
+            matrix.x_axis.into(),
+            matrix.y_axis.into(),
+            matrix.z_axis.into(),
+            matrix.w_axis.into(),
+        ]
+    }
+}
+
+impl Default for DMat4 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl fmt::Display for DMat4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(
+                f,
+                "[{:.*}, {:.*}, {:.*}, {:.*}]",
+                p, self.x_axis, p, self.y_axis, p, self.z_axis, p, self.w_axis
+            )
+        } else {
+            write!(
+                f,
+                "[{}, {}, {}, {}]",
+                self.x_axis, self.y_axis, self.z_axis, self.w_axis
+            )
+        }
+    }
+}
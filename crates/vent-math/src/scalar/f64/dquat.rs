@@ -0,0 +1,420 @@
+use std::{
+    fmt,
+    ops::{Mul, MulAssign, Neg},
+};
+
+use crate::{
+    scalar::{euler::EulerRot, quat::Quat},
+    vec::f64::{dvec3::DVec3, dvec4::DVec4},
+};
+
+use super::dmat4::DMat4;
+
+/// A double precision rotation, see [`Quat`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct DQuat {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl DQuat {
+    pub const IDENTITY: Self = Self::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const NAN: Self = Self::from_array([f64::NAN; 4]);
+
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn from_array(a: [f64; 4]) -> Self {
+        Self::new(a[0], a[1], a[2], a[3])
+    }
+
+    #[inline]
+    const fn from_dvec4(v: DVec4) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+
+    /// Casts all elements of `self` to `f32`.
+    #[inline]
+    #[must_use]
+    pub fn as_quat(&self) -> Quat {
+        Quat::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn xyz(self) -> DVec3 {
+        DVec3::new(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_scaled_axis(v: DVec3) -> Self {
+        let length = v.length();
+        if length == 0.0 {
+            Self::IDENTITY
+        } else {
+            Self::from_axis_angle(v / length, length)
+        }
+    }
+
+    /// Creates a rotation from the orthonormal basis vectors of a rotation matrix.
+    #[must_use]
+    pub fn from_rotation_axes(x_axis: DVec3, y_axis: DVec3, z_axis: DVec3) -> Self {
+        let (m00, m01, m02) = x_axis.into();
+        let (m10, m11, m12) = y_axis.into();
+        let (m20, m21, m22) = z_axis.into();
+        if m22 <= 0.0 {
+            // x^2 + y^2 >= z^2 + w^2
+            let dif10 = m11 - m00;
+            let omm22 = 1.0 - m22;
+            if dif10 <= 0.0 {
+                // x^2 >= y^2
+                let four_xsq = omm22 - dif10;
+                let inv4x = 0.5 / four_xsq.sqrt();
+                Self::new(
+                    four_xsq * inv4x,
+                    (m01 + m10) * inv4x,
+                    (m02 + m20) * inv4x,
+                    (m12 - m21) * inv4x,
+                )
+            } else {
+                // y^2 >= x^2
+                let four_ysq = omm22 + dif10;
+                let inv4y = 0.5 / four_ysq.sqrt();
+                Self::new(
+                    (m01 + m10) * inv4y,
+                    four_ysq * inv4y,
+                    (m12 + m21) * inv4y,
+                    (m20 - m02) * inv4y,
+                )
+            }
+        } else {
+            // z^2 + w^2 >= x^2 + y^2
+            let sum10 = m11 + m00;
+            let opm22 = 1.0 + m22;
+            if sum10 <= 0.0 {
+                // z^2 >= w^2
+                let four_zsq = opm22 - sum10;
+                let inv4z = 0.5 / four_zsq.sqrt();
+                Self::new(
+                    (m02 + m20) * inv4z,
+                    (m12 + m21) * inv4z,
+                    four_zsq * inv4z,
+                    (m01 - m10) * inv4z,
+                )
+            } else {
+                // w^2 >= z^2
+                let four_wsq = opm22 + sum10;
+                let inv4w = 0.5 / four_wsq.sqrt();
+                Self::new(
+                    (m12 - m21) * inv4w,
+                    (m20 - m02) * inv4w,
+                    (m01 - m10) * inv4w,
+                    four_wsq * inv4w,
+                )
+            }
+        }
+    }
+
+    /// Creates a rotation of `angle` radians around `axis`, which must be normalized.
+    #[inline]
+    #[must_use]
+    pub fn from_axis_angle(axis: DVec3, angle: f64) -> Self {
+        let (s, c) = (angle * 0.5).sin_cos();
+        let v = axis * s;
+        Self::new(v.x, v.y, v.z, c)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_x(angle: f64) -> Self {
+        let (s, c) = (angle * 0.5).sin_cos();
+        Self::new(s, 0.0, 0.0, c)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_y(angle: f64) -> Self {
+        let (s, c) = (angle * 0.5).sin_cos();
+        Self::new(0.0, s, 0.0, c)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_z(angle: f64) -> Self {
+        let (s, c) = (angle * 0.5).sin_cos();
+        Self::new(0.0, 0.0, s, c)
+    }
+
+    /// Creates a rotation from Euler angles in radians, applied in the given order.
+    #[must_use]
+    pub fn from_euler(order: EulerRot, a: f64, b: f64, c: f64) -> Self {
+        let rotation = |axis, angle| match axis {
+            0 => Self::from_rotation_x(angle),
+            1 => Self::from_rotation_y(angle),
+            _ => Self::from_rotation_z(angle),
+        };
+        let (i, j, k) = order.axes();
+        rotation(i, a) * rotation(j, b) * rotation(k, c)
+    }
+
+    /// Returns the Euler angles in radians for the given order, the inverse of
+    /// [`DQuat::from_euler`].
+    ///
+    /// The second angle is in `[-pi/2, pi/2]`. In gimbal lock, when it is at either end,
+    /// the third angle is returned as `0.0`.
+    #[must_use]
+    pub fn to_euler(self, order: EulerRot) -> (f64, f64, f64) {
+        // Just short of gimbal lock the first and third angle can not be told apart
+        const GIMBAL_LOCK: f64 = 0.99999;
+
+        let matrix = DMat4::from_quat(self);
+        let columns: [[f64; 4]; 3] = [
+            matrix.x_axis.into(),
+            matrix.y_axis.into(),
+            matrix.z_axis.into(),
+        ];
+        let m = |row: usize, column: usize| columns[column][row];
+        let (i, j, k) = order.axes();
+        let parity = f64::from(order.parity());
+
+        let sin_b = (parity * m(i, k)).clamp(-1.0, 1.0);
+        let b = sin_b.asin();
+        if sin_b.abs() < GIMBAL_LOCK {
+            let a = (-parity * m(j, k)).atan2(m(k, k));
+            let c = (-parity * m(i, j)).atan2(m(i, i));
+            (a, b, c)
+        } else {
+            let a = (parity * m(k, j)).atan2(m(j, j));
+            (a, b, 0.0)
+        }
+    }
+
+    /// Creates the shortest rotation from the direction `from` to the direction `to`, both
+    /// must be normalized.
+    #[must_use]
+    pub fn from_rotation_arc(from: DVec3, to: DVec3) -> Self {
+        const ONE_MINUS_EPS: f64 = 1.0 - 2.0 * f64::EPSILON;
+        let dot = from.dot(to);
+        if dot > ONE_MINUS_EPS {
+            Self::IDENTITY
+        } else if dot < -ONE_MINUS_EPS {
+            // Opposite directions, any perpendicular axis works
+            Self::from_axis_angle(from.any_orthonormal_vector(), std::f64::consts::PI)
+        } else {
+            let c = from.cross(to);
+            Self::new(c.x, c.y, c.z, 1.0 + dot).normalize()
+        }
+    }
+
+    /// Creates a rotation from the upper 3x3 part of a matrix without scale.
+    #[inline]
+    #[must_use]
+    pub fn from_mat4(matrix: &DMat4) -> Self {
+        Self::from_rotation_axes(
+            matrix.x_axis.xyz(),
+            matrix.y_axis.xyz(),
+            matrix.z_axis.xyz(),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> f64 {
+        DVec4::from(self).dot(DVec4::from(rhs))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn length(self) -> f64 {
+        DVec4::from(self).length()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn length_squared(self) -> f64 {
+        DVec4::from(self).length_squared()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        Self::from_dvec4(DVec4::from(self).normalize())
+    }
+
+    /// Returns the rotation in the opposite direction, the inverse of a normalized quaternion.
+    #[inline]
+    #[must_use]
+    pub fn conjugate(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the inverse rotation, the quaternion must be normalized.
+    #[inline]
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        self.conjugate()
+    }
+
+    /// Interpolates linearly between `self` and `end` along the shorter path and normalizes
+    /// the result.
+    ///
+    /// Faster than [`DQuat::slerp`] but the angular velocity is not constant.
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, end: Self, s: f64) -> Self {
+        let end = if self.dot(end) < 0.0 { -end } else { end };
+        Self::from_dvec4(DVec4::from(self).lerp(DVec4::from(end), s).normalize())
+    }
+
+    /// Interpolates spherically between `self` and `end` along the shorter path, both must be
+    /// normalized.
+    #[must_use]
+    pub fn slerp(self, end: Self, s: f64) -> Self {
+        // Close rotations fall back to lerp to avoid dividing by sin(~0)
+        const DOT_THRESHOLD: f64 = 0.9995;
+
+        let mut dot = self.dot(end);
+        let end = if dot < 0.0 {
+            dot = -dot;
+            -end
+        } else {
+            end
+        };
+        if dot > DOT_THRESHOLD {
+            return self.lerp(end, s);
+        }
+        let theta = dot.acos();
+        let scale_start = (theta * (1.0 - s)).sin();
+        let scale_end = (theta * s).sin();
+        let start = DVec4::from(self) * scale_start;
+        let end = DVec4::from(end) * scale_end;
+        Self::from_dvec4((start + end) / theta.sin())
+    }
+
+    /// Returns the Hamilton product, applying `rhs` first and then `self`.
+    #[inline]
+    #[must_use]
+    pub fn mul_quat(self, rhs: Self) -> Self {
+        let (x0, y0, z0, w0) = self.into();
+        let (x1, y1, z1, w1) = rhs.into();
+        Self::new(
+            w0 * x1 + x0 * w1 + y0 * z1 - z0 * y1,
+            w0 * y1 - x0 * z1 + y0 * w1 + z0 * x1,
+            w0 * z1 + x0 * y1 - y0 * x1 + z0 * w1,
+            w0 * w1 - x0 * x1 - y0 * y1 - z0 * z1,
+        )
+    }
+
+    /// Rotates a vector, the quaternion must be normalized.
+    #[inline]
+    #[must_use]
+    pub fn mul_vec3(self, rhs: DVec3) -> DVec3 {
+        let w = self.w;
+        let b = self.xyz();
+        let b2 = b.dot(b);
+        rhs * (w * w - b2) + b * (rhs.dot(b) * 2.0) + b.cross(rhs) * (w * 2.0)
+    }
+
+    /// Returns whether all elements of `self` and `rhs` differ by at most `max_abs_diff`.
+    ///
+    /// `q` and `-q` describe the same rotation but are not considered equal.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f64) -> bool {
+        (self.x - rhs.x).abs() <= max_abs_diff
+            && (self.y - rhs.y).abs() <= max_abs_diff
+            && (self.z - rhs.z).abs() <= max_abs_diff
+            && (self.w - rhs.w).abs() <= max_abs_diff
+    }
+}
+
+impl Mul<DQuat> for DQuat {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_quat(rhs)
+    }
+}
+
+impl MulAssign<DQuat> for DQuat {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_quat(rhs);
+    }
+}
+
+impl Mul<DVec3> for DQuat {
+    type Output = DVec3;
+    #[inline]
+    fn mul(self, rhs: DVec3) -> DVec3 {
+        self.mul_vec3(rhs)
+    }
+}
+
+impl Neg for DQuat {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl From<[f64; 4]> for DQuat {
+    /// Creates a quaternion from `[x, y, z, w]` without normalizing it.
+    #[inline]
+    fn from(a: [f64; 4]) -> Self {
+        Self::from_array(a)
+    }
+}
+
+impl From<DQuat> for DVec4 {
+    #[inline]
+    fn from(q: DQuat) -> Self {
+        Self::new(q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<DQuat> for (f64, f64, f64, f64) {
+    #[inline]
+    fn from(q: DQuat) -> Self {
+        (q.x, q.y, q.z, q.w)
+    }
+}
+
+impl From<DQuat> for [f64; 4] {
+    #[inline]
+    fn from(q: DQuat) -> Self {
+        [q.x, q.y, q.z, q.w]
+    }
+}
+
+impl Default for DQuat {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl fmt::Display for DQuat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(
+                f,
+                "[{:.*}, {:.*}, {:.*}, {:.*}]",
+                p, self.x, p, self.y, p, self.z, p, self.w
+            )
+        } else {
+            write!(f, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
+        }
+    }
+}
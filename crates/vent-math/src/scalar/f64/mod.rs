@@ -0,0 +1,2 @@
+pub mod dmat4;
+pub mod dquat;
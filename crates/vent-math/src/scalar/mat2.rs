@@ -0,0 +1,193 @@
+use std::{
+    fmt,
+    ops::{Add, Mul, MulAssign, Sub},
+};
+
+use crate::vec::vec2::Vec2;
+
+use super::mat3::Mat3;
+
+/// A column major 2x2 matrix, used for 2D rotations and scales.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct Mat2 {
+    pub x_axis: Vec2,
+    pub y_axis: Vec2,
+}
+
+impl Mat2 {
+    pub const ZERO: Self = Self::from_cols(Vec2::ZERO, Vec2::ZERO);
+
+    pub const IDENTITY: Self = Self::from_cols(Vec2::X, Vec2::Y);
+
+    #[inline]
+    #[must_use]
+    pub const fn from_cols(x_axis: Vec2, y_axis: Vec2) -> Self {
+        Self { x_axis, y_axis }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn from_diagonal(diagonal: Vec2) -> Self {
+        Self::from_cols(Vec2::new(diagonal.x, 0.0), Vec2::new(0.0, diagonal.y))
+    }
+
+    /// Creates a counter clockwise rotation of `angle` radians.
+    #[inline]
+    #[must_use]
+    pub fn from_angle(angle: f32) -> Self {
+        Self::from_scale_angle(Vec2::ONE, angle)
+    }
+
+    /// Creates a matrix which scales and then rotates counter clockwise by `angle` radians.
+    #[inline]
+    #[must_use]
+    pub fn from_scale_angle(scale: Vec2, angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::from_cols(
+            Vec2::new(cos * scale.x, sin * scale.x),
+            Vec2::new(-sin * scale.y, cos * scale.y),
+        )
+    }
+
+    /// Returns the upper left 2x2 part of the matrix.
+    #[inline]
+    #[must_use]
+    pub fn from_mat3(matrix: &Mat3) -> Self {
+        Self::from_cols(
+            Vec2::new(matrix.x_axis.x, matrix.x_axis.y),
+            Vec2::new(matrix.y_axis.x, matrix.y_axis.y),
+        )
+    }
+
+    /// Returns the transpose, swapping rows and columns.
+    #[inline]
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_cols(
+            Vec2::new(self.x_axis.x, self.y_axis.x),
+            Vec2::new(self.x_axis.y, self.y_axis.y),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn determinant(&self) -> f32 {
+        self.x_axis.x * self.y_axis.y - self.x_axis.y * self.y_axis.x
+    }
+
+    /// Returns the inverse of the matrix, which must be invertible.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let rcp_det = self.determinant().recip();
+        Self::from_cols(
+            Vec2::new(self.y_axis.y, -self.x_axis.y) * rcp_det,
+            Vec2::new(-self.y_axis.x, self.x_axis.x) * rcp_det,
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn mul_vec2(&self, rhs: Vec2) -> Vec2 {
+        self.x_axis * rhs.x + self.y_axis * rhs.y
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn mul_mat2(&self, rhs: &Self) -> Self {
+        Self::from_cols(self.mul_vec2(rhs.x_axis), self.mul_vec2(rhs.y_axis))
+    }
+
+    /// Returns whether all elements of `self` and `rhs` differ by at most `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.x_axis.abs_diff_eq(rhs.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(rhs.y_axis, max_abs_diff)
+    }
+}
+
+impl Add<Mat2> for Mat2 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_cols(self.x_axis.add(rhs.x_axis), self.y_axis.add(rhs.y_axis))
+    }
+}
+
+impl Sub<Mat2> for Mat2 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_cols(self.x_axis.sub(rhs.x_axis), self.y_axis.sub(rhs.y_axis))
+    }
+}
+
+impl Mul<Mat2> for Mat2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_mat2(&rhs)
+    }
+}
+
+impl MulAssign<Mat2> for Mat2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_mat2(&rhs);
+    }
+}
+
+impl Mul<Vec2> for Mat2 {
+    type Output = Vec2;
+    #[inline]
+    fn mul(self, rhs: Vec2) -> Vec2 {
+        self.mul_vec2(rhs)
+    }
+}
+
+impl Mul<f32> for Mat2 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self::from_cols(self.x_axis * rhs, self.y_axis * rhs)
+    }
+}
+
+impl From<[[f32; 2]; 2]> for Mat2 {
+    #[inline]
+    fn from(cols: [[f32; 2]; 2]) -> Self {
+        Self::from_cols(
+            Vec2::new(cols[0][0], cols[0][1]),
+            Vec2::new(cols[1][0], cols[1][1]),
+        )
+    }
+}
+
+impl From<Mat2> for [[f32; 2]; 2] {
+    #[inline]
+    fn from(matrix: Mat2) -> Self {
+        [
+            [matrix.x_axis.x, matrix.x_axis.y],
+            [matrix.y_axis.x, matrix.y_axis.y],
+        ]
+    }
+}
+
+impl Default for Mat2 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl fmt::Display for Mat2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(f, "[{:.*}, {:.*}]", p, self.x_axis, p, self.y_axis)
+        } else {
+            write!(f, "[{}, {}]", self.x_axis, self.y_axis)
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use std::{
+    fmt,
+    ops::{Add, Mul, MulAssign, Sub},
+};
+
+use crate::vec::{vec2::Vec2, vec3::Vec3};
+
+use super::{mat2::Mat2, mat4::Mat4, quat::Quat};
+
+/// A column major 3x3 matrix, used for rotations, normal matrices and 2D transforms.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct Mat3 {
+    pub x_axis: Vec3,
+    pub y_axis: Vec3,
+    pub z_axis: Vec3,
+}
+
+impl Mat3 {
+    pub const ZERO: Self = Self::from_cols(Vec3::ZERO, Vec3::ZERO, Vec3::ZERO);
+
+    pub const IDENTITY: Self = Self::from_cols(Vec3::X, Vec3::Y, Vec3::Z);
+
+    #[inline]
+    #[must_use]
+    pub const fn from_cols(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn from_diagonal(diagonal: Vec3) -> Self {
+        Self::from_cols(
+            Vec3::new(diagonal.x, 0.0, 0.0),
+            Vec3::new(0.0, diagonal.y, 0.0),
+            Vec3::new(0.0, 0.0, diagonal.z),
+        )
+    }
+
+    /// Returns the upper left 3x3 part of the matrix, dropping translation and projection.
+    #[inline]
+    #[must_use]
+    pub fn from_mat4(matrix: &Mat4) -> Self {
+        Self::from_cols(
+            matrix.x_axis.xyz(),
+            matrix.y_axis.xyz(),
+            matrix.z_axis.xyz(),
+        )
+    }
+
+    /// Creates a 3x3 matrix with `matrix` in the upper left corner.
+    #[inline]
+    #[must_use]
+    pub fn from_mat2(matrix: Mat2) -> Self {
+        Self::from_cols(
+            Vec3::from((matrix.x_axis, 0.0)),
+            Vec3::from((matrix.y_axis, 0.0)),
+            Vec3::Z,
+        )
+    }
+
+    /// Creates a rotation matrix, `rotation` must be normalized.
+    #[inline]
+    #[must_use]
+    pub fn from_quat(rotation: Quat) -> Self {
+        let (x, y, z, w) = rotation.into();
+        let x2 = x + x;
+        let y2 = y + y;
+        let z2 = z + z;
+        let xx = x * x2;
+        let xy = x * y2;
+        let xz = x * z2;
+        let yy = y * y2;
+        let yz = y * z2;
+        let zz = z * z2;
+        let wx = w * x2;
+        let wy = w * y2;
+        let wz = w * z2;
+
+        Self::from_cols(
+            Vec3::new(1.0 - (yy + zz), xy + wz, xz - wy),
+            Vec3::new(xy - wz, 1.0 - (xx + zz), yz + wx),
+            Vec3::new(xz + wy, yz - wx, 1.0 - (xx + yy)),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_scale(scale: Vec3) -> Self {
+        Self::from_diagonal(scale)
+    }
+
+    /// Creates a 2D transform which scales, then rotates counter clockwise by `angle`
+    /// radians and then translates.
+    #[inline]
+    #[must_use]
+    pub fn from_scale_angle_translation(scale: Vec2, angle: f32, translation: Vec2) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::from_cols(
+            Vec3::new(cos * scale.x, sin * scale.x, 0.0),
+            Vec3::new(-sin * scale.y, cos * scale.y, 0.0),
+            Vec3::from((translation, 1.0)),
+        )
+    }
+
+    /// Returns the transpose, swapping rows and columns.
+    #[inline]
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        Self::from_cols(
+            Vec3::new(self.x_axis.x, self.y_axis.x, self.z_axis.x),
+            Vec3::new(self.x_axis.y, self.y_axis.y, self.z_axis.y),
+            Vec3::new(self.x_axis.z, self.y_axis.z, self.z_axis.z),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn determinant(&self) -> f32 {
+        self.z_axis.dot(self.x_axis.cross(self.y_axis))
+    }
+
+    /// Returns the inverse of the matrix, which must be invertible.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let tmp0 = self.y_axis.cross(self.z_axis);
+        let tmp1 = self.z_axis.cross(self.x_axis);
+        let tmp2 = self.x_axis.cross(self.y_axis);
+        let rcp_det = self.z_axis.dot(tmp2).recip();
+        Self::from_cols(tmp0 * rcp_det, tmp1 * rcp_det, tmp2 * rcp_det).transpose()
+    }
+
+    /// Returns the matrix transforming normals like this matrix transforms positions, the
+    /// inverse transpose.
+    #[inline]
+    #[must_use]
+    pub fn normal_matrix(&self) -> Self {
+        self.inverse().transpose()
+    }
+
+    /// Transforms a 2D point, applying the translation of a 2D transform.
+    #[inline]
+    #[must_use]
+    pub fn transform_point2(&self, rhs: Vec2) -> Vec2 {
+        let res = self.mul_vec3(Vec3::from((rhs, 1.0)));
+        Vec2::new(res.x, res.y)
+    }
+
+    /// Transforms a 2D direction, ignoring the translation of a 2D transform.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector2(&self, rhs: Vec2) -> Vec2 {
+        let res = self.mul_vec3(Vec3::from((rhs, 0.0)));
+        Vec2::new(res.x, res.y)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn mul_vec3(&self, rhs: Vec3) -> Vec3 {
+        self.x_axis * rhs.x + self.y_axis * rhs.y + self.z_axis * rhs.z
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn mul_mat3(&self, rhs: &Self) -> Self {
+        Self::from_cols(
+            self.mul_vec3(rhs.x_axis),
+            self.mul_vec3(rhs.y_axis),
+            self.mul_vec3(rhs.z_axis),
+        )
+    }
+
+    /// Returns whether all elements of `self` and `rhs` differ by at most `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.x_axis.abs_diff_eq(rhs.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(rhs.y_axis, max_abs_diff)
+            && self.z_axis.abs_diff_eq(rhs.z_axis, max_abs_diff)
+    }
+}
+
+impl Add<Mat3> for Mat3 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_cols(
+            self.x_axis.add(rhs.x_axis),
+            self.y_axis.add(rhs.y_axis),
+            self.z_axis.add(rhs.z_axis),
+        )
+    }
+}
+
+impl Sub<Mat3> for Mat3 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_cols(
+            self.x_axis.sub(rhs.x_axis),
+            self.y_axis.sub(rhs.y_axis),
+            self.z_axis.sub(rhs.z_axis),
+        )
+    }
+}
+
+impl Mul<Mat3> for Mat3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_mat3(&rhs)
+    }
+}
+
+impl MulAssign<Mat3> for Mat3 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_mat3(&rhs);
+    }
+}
+
+impl Mul<Vec3> for Mat3 {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        self.mul_vec3(rhs)
+    }
+}
+
+impl Mul<f32> for Mat3 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self::from_cols(self.x_axis * rhs, self.y_axis * rhs, self.z_axis * rhs)
+    }
+}
+
+impl From<Mat3> for Mat4 {
+    /// Creates a 4x4 matrix with `matrix` in the upper left corner and no translation.
+    #[inline]
+    fn from(matrix: Mat3) -> Self {
+        Self::from_cols(
+            (matrix.x_axis, 0.0).into(),
+            (matrix.y_axis, 0.0).into(),
+            (matrix.z_axis, 0.0).into(),
+            crate::vec::vec4::Vec4::W,
+        )
+    }
+}
+
+impl From<[[f32; 3]; 3]> for Mat3 {
+    #[inline]
+    fn from(cols: [[f32; 3]; 3]) -> Self {
+        Self::from_cols(cols[0].into(), cols[1].into(), cols[2].into())
+    }
+}
+
+impl From<Mat3> for [[f32; 3]; 3] {
+    #[inline]
+    fn from(matrix: Mat3) -> Self {
+        [
+            matrix.x_axis.into(),
+            matrix.y_axis.into(),
+            matrix.z_axis.into(),
+        ]
+    }
+}
+
+impl Default for Mat3 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl fmt::Display for Mat3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(
+                f,
+                "[{:.*}, {:.*}, {:.*}]",
+                p, self.x_axis, p, self.y_axis, p, self.z_axis
+            )
+        } else {
+            write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis)
+        }
+    }
+}
@@ -1,10 +1,14 @@
-use std::ops::{Add, Mul, MulAssign, Sub};
+use std::{
+    arch::x86_64::_mm_shuffle_ps,
+    fmt,
+    ops::{Add, Mul, MulAssign, Sub},
+};
 
 use crate::vec::{vec3::Vec3, vec4::Vec4};
 
-use super::quat::Quat;
+use super::{f64::dmat4::DMat4, quat::Quat};
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 #[repr(C)]
 pub struct Mat4 {
     pub x_axis: Vec4,
@@ -55,6 +59,18 @@ impl Mat4 {
         }
     }
 
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    #[must_use]
+    pub fn as_dmat4(&self) -> DMat4 {
+        DMat4::from_cols(
+            self.x_axis.as_dvec4(),
+            self.y_axis.as_dvec4(),
+            self.z_axis.as_dvec4(),
+            self.w_axis.as_dvec4(),
+        )
+    }
+
     #[inline]
     #[must_use]
     fn quat_to_axes(rotation: Quat) -> (Vec4, Vec4, Vec4) {
@@ -78,6 +94,38 @@ impl Mat4 {
         (x_axis, y_axis, z_axis)
     }
 
+    #[inline]
+    #[must_use]
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self::from_cols(Vec4::X, Vec4::Y, Vec4::Z, Vec4::from((translation, 1.0)))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_scale(scale: Vec3) -> Self {
+        Self::from_cols(
+            Vec4::X.mul(scale.x),
+            Vec4::Y.mul(scale.y),
+            Vec4::Z.mul(scale.z),
+            Vec4::W,
+        )
+    }
+
+    /// Creates a rotation matrix, `rotation` must be normalized.
+    #[inline]
+    #[must_use]
+    pub fn from_quat(rotation: Quat) -> Self {
+        let (x_axis, y_axis, z_axis) = Self::quat_to_axes(rotation);
+        Self::from_cols(x_axis, y_axis, z_axis, Vec4::W)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_translation(rotation: Quat, translation: Vec3) -> Self {
+        let (x_axis, y_axis, z_axis) = Self::quat_to_axes(rotation);
+        Self::from_cols(x_axis, y_axis, z_axis, Vec4::from((translation, 1.0)))
+    }
+
     #[inline]
     #[must_use]
     pub fn from_scale_rotation_translation(scale: Vec3, rotation: Quat, translation: Vec3) -> Self {
@@ -90,6 +138,32 @@ impl Mat4 {
         )
     }
 
+    /// Decomposes the matrix into scale, rotation and translation.
+    ///
+    /// The matrix must be an affine transform without shear, a negative determinant is
+    /// folded into the x scale.
+    #[inline]
+    #[must_use]
+    pub fn to_scale_rotation_translation(&self) -> (Vec3, Quat, Vec3) {
+        let det = self.determinant();
+        let x_axis = Vec3::new(self.x_axis.x, self.x_axis.y, self.x_axis.z);
+        let y_axis = Vec3::new(self.y_axis.x, self.y_axis.y, self.y_axis.z);
+        let z_axis = Vec3::new(self.z_axis.x, self.z_axis.y, self.z_axis.z);
+        let scale = Vec3::new(
+            x_axis.length() * det.signum(),
+            y_axis.length(),
+            z_axis.length(),
+        );
+        let inv_scale = 1.0 / scale;
+        let rotation = Quat::from_rotation_axes(
+            x_axis * inv_scale.x,
+            y_axis * inv_scale.y,
+            z_axis * inv_scale.z,
+        );
+        let translation = Vec3::new(self.w_axis.x, self.w_axis.y, self.w_axis.z);
+        (scale, rotation, translation)
+    }
+
     #[inline]
     pub fn look_at_rh(eye: Vec3, center: Vec3, up: Vec3) -> Self {
         Self::look_to_rh(eye, center.sub(eye), up)
@@ -151,6 +225,130 @@ impl Mat4 {
         )
     }
 
+    /// Returns the transpose, swapping rows and columns.
+    #[inline]
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        unsafe {
+            // (x0, x1, y0, y1) and (x2, x3, y2, y3)
+            let tmp0 = _mm_shuffle_ps(self.x_axis.0, self.y_axis.0, 0b01_00_01_00);
+            let tmp1 = _mm_shuffle_ps(self.x_axis.0, self.y_axis.0, 0b11_10_11_10);
+            // (z0, z1, w0, w1) and (z2, z3, w2, w3)
+            let tmp2 = _mm_shuffle_ps(self.z_axis.0, self.w_axis.0, 0b01_00_01_00);
+            let tmp3 = _mm_shuffle_ps(self.z_axis.0, self.w_axis.0, 0b11_10_11_10);
+            Self::from_cols(
+                Vec4(_mm_shuffle_ps(tmp0, tmp2, 0b10_00_10_00)),
+                Vec4(_mm_shuffle_ps(tmp0, tmp2, 0b11_01_11_01)),
+                Vec4(_mm_shuffle_ps(tmp1, tmp3, 0b10_00_10_00)),
+                Vec4(_mm_shuffle_ps(tmp1, tmp3, 0b11_01_11_01)),
+            )
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn determinant(&self) -> f32 {
+        let (m00, m01, m02, m03) = self.x_axis.into();
+        let (m10, m11, m12, m13) = self.y_axis.into();
+        let (m20, m21, m22, m23) = self.z_axis.into();
+        let (m30, m31, m32, m33) = self.w_axis.into();
+
+        let a2323 = m22 * m33 - m23 * m32;
+        let a1323 = m21 * m33 - m23 * m31;
+        let a1223 = m21 * m32 - m22 * m31;
+        let a0323 = m20 * m33 - m23 * m30;
+        let a0223 = m20 * m32 - m22 * m30;
+        let a0123 = m20 * m31 - m21 * m30;
+
+        m00 * (m11 * a2323 - m12 * a1323 + m13 * a1223)
+            - m01 * (m10 * a2323 - m12 * a0323 + m13 * a0223)
+            + m02 * (m10 * a1323 - m11 * a0323 + m13 * a0123)
+            - m03 * (m10 * a1223 - m11 * a0223 + m12 * a0123)
+    }
+
+    /// Returns the inverse of the matrix, which must be invertible.
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        let (m00, m01, m02, m03) = self.x_axis.into();
+        let (m10, m11, m12, m13) = self.y_axis.into();
+        let (m20, m21, m22, m23) = self.z_axis.into();
+        let (m30, m31, m32, m33) = self.w_axis.into();
+
+        let coef00 = m22 * m33 - m32 * m23;
+        let coef02 = m12 * m33 - m32 * m13;
+        let coef03 = m12 * m23 - m22 * m13;
+
+        let coef04 = m21 * m33 - m31 * m23;
+        let coef06 = m11 * m33 - m31 * m13;
+        let coef07 = m11 * m23 - m21 * m13;
+
+        let coef08 = m21 * m32 - m31 * m22;
+        let coef10 = m11 * m32 - m31 * m12;
+        let coef11 = m11 * m22 - m21 * m12;
+
+        let coef12 = m20 * m33 - m30 * m23;
+        let coef14 = m10 * m33 - m30 * m13;
+        let coef15 = m10 * m23 - m20 * m13;
+
+        let coef16 = m20 * m32 - m30 * m22;
+        let coef18 = m10 * m32 - m30 * m12;
+        let coef19 = m10 * m22 - m20 * m12;
+
+        let coef20 = m20 * m31 - m30 * m21;
+        let coef22 = m10 * m31 - m30 * m11;
+        let coef23 = m10 * m21 - m20 * m11;
+
+        let fac0 = Vec4::new(coef00, coef00, coef02, coef03);
+        let fac1 = Vec4::new(coef04, coef04, coef06, coef07);
+        let fac2 = Vec4::new(coef08, coef08, coef10, coef11);
+        let fac3 = Vec4::new(coef12, coef12, coef14, coef15);
+        let fac4 = Vec4::new(coef16, coef16, coef18, coef19);
+        let fac5 = Vec4::new(coef20, coef20, coef22, coef23);
+
+        let vec0 = Vec4::new(m10, m00, m00, m00);
+        let vec1 = Vec4::new(m11, m01, m01, m01);
+        let vec2 = Vec4::new(m12, m02, m02, m02);
+        let vec3 = Vec4::new(m13, m03, m03, m03);
+
+        let inv0 = vec1.mul(fac0).sub(vec2.mul(fac1)).add(vec3.mul(fac2));
+        let inv1 = vec0.mul(fac0).sub(vec2.mul(fac3)).add(vec3.mul(fac4));
+        let inv2 = vec0.mul(fac1).sub(vec1.mul(fac3)).add(vec3.mul(fac5));
+        let inv3 = vec0.mul(fac2).sub(vec1.mul(fac4)).add(vec2.mul(fac5));
+
+        let sign_a = Vec4::new(1.0, -1.0, 1.0, -1.0);
+        let sign_b = Vec4::new(-1.0, 1.0, -1.0, 1.0);
+
+        let inv0 = inv0.mul(sign_a);
+        let inv1 = inv1.mul(sign_b);
+        let inv2 = inv2.mul(sign_a);
+        let inv3 = inv3.mul(sign_b);
+
+        let det = m00 * inv0.x + m01 * inv1.x + m02 * inv2.x + m03 * inv3.x;
+        let rcp_det = det.recip();
+        Self::from_cols(
+            inv0.mul(rcp_det),
+            inv1.mul(rcp_det),
+            inv2.mul(rcp_det),
+            inv3.mul(rcp_det),
+        )
+    }
+
+    /// Transforms a point, applying the translation.
+    #[inline]
+    #[must_use]
+    pub fn transform_point3(&self, rhs: Vec3) -> Vec3 {
+        let res = self.mul_vec4(Vec4::from((rhs, 1.0)));
+        Vec3::new(res.x, res.y, res.z)
+    }
+
+    /// Transforms a direction, ignoring the translation.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector3(&self, rhs: Vec3) -> Vec3 {
+        let res = self.mul_vec4(Vec4::from((rhs, 0.0)));
+        Vec3::new(res.x, res.y, res.z)
+    }
+
     #[inline]
     #[must_use]
     pub fn mul_mat4(&self, rhs: &Self) -> Self {
@@ -171,6 +369,16 @@ impl Mat4 {
         res = res.add(self.w_axis.mul(rhs.wwww()));
         res
     }
+
+    /// Returns whether all elements of `self` and `rhs` differ by at most `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        self.x_axis.abs_diff_eq(rhs.x_axis, max_abs_diff)
+            && self.y_axis.abs_diff_eq(rhs.y_axis, max_abs_diff)
+            && self.z_axis.abs_diff_eq(rhs.z_axis, max_abs_diff)
+            && self.w_axis.abs_diff_eq(rhs.w_axis, max_abs_diff)
+    }
 }
 
 impl Mul<Mat4> for Mat4 {
@@ -195,3 +403,52 @@ impl Mul<Vec4> for Mat4 {
         self.mul_vec4(rhs)
     }
 }
+
+impl From<[[f32; 4]; 4]> for Mat4 {
+    #[inline]
+    fn from(cols: [[f32; 4]; 4]) -> Self {
+        Self::from_cols(
+            cols[0].into(),
+            cols[1].into(),
+            cols[2].into(),
+            cols[3].into(),
+        )
+    }
+}
+
+impl From<Mat4> for [[f32; 4]; 4] {
+    #[inline]
+    fn from(matrix: Mat4) -> Self {
+        [
+            matrix.x_axis.into(),
+            matrix.y_axis.into(),
+            matrix.z_axis.into(),
+            matrix.w_axis.into(),
+        ]
+    }
+}
+
+impl Default for Mat4 {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl fmt::Display for Mat4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(
+                f,
+                "[{:.*}, {:.*}, {:.*}, {:.*}]",
+                p, self.x_axis, p, self.y_axis, p, self.z_axis, p, self.w_axis
+            )
+        } else {
+            write!(
+                f,
+                "[{}, {}, {}, {}]",
+                self.x_axis, self.y_axis, self.z_axis, self.w_axis
+            )
+        }
+    }
+}
@@ -1,2 +1,8 @@
+pub mod affine3a;
+pub mod euler;
+pub mod mat2;
+pub mod mat3;
 pub mod mat4;
 pub mod quat;
+
+pub mod f64;
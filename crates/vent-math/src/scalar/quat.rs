@@ -1,10 +1,13 @@
 use std::{
-    arch::x86_64::__m128,
-    ops::{Deref, DerefMut},
+    arch::x86_64::{__m128, _mm_add_ps, _mm_mul_ps, _mm_shuffle_ps},
+    fmt,
+    ops::{Deref, DerefMut, Mul, MulAssign, Neg},
 };
 
 use crate::vec::{vec3::Vec3, vec4::Vec4};
 
+use super::{euler::EulerRot, f64::dquat::DQuat, mat3::Mat3, mat4::Mat4};
+
 #[repr(C)]
 union UnionCast {
     a: [f32; 4],
@@ -35,6 +38,13 @@ impl Quat {
         Self::new(a[0], a[1], a[2], a[3])
     }
 
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    #[must_use]
+    pub fn as_dquat(&self) -> DQuat {
+        DQuat::new(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
+    }
+
     #[inline]
     #[must_use]
     pub fn xyz(self) -> Vec3 {
@@ -52,11 +62,382 @@ impl Quat {
         }
     }
 
+    /// Returns the rotation axis scaled by the rotation angle in radians, the inverse of
+    /// [`Quat::from_scaled_axis`]. The quaternion must be normalized.
+    #[inline]
+    #[must_use]
+    pub fn to_scaled_axis(self) -> Vec3 {
+        // `q` and `-q` are the same rotation, pick the one with an angle of at most pi
+        let (v, w) = if self.w < 0.0 {
+            (-self.xyz(), -self.w)
+        } else {
+            (self.xyz(), self.w)
+        };
+        let sin_half = v.length();
+        if sin_half == 0.0 {
+            Vec3::ZERO
+        } else {
+            v * (2.0 * sin_half.atan2(w) / sin_half)
+        }
+    }
+
+    /// Creates a rotation from the orthonormal basis vectors of a rotation matrix.
+    #[must_use]
+    pub fn from_rotation_axes(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Self {
+        let (m00, m01, m02) = x_axis.into();
+        let (m10, m11, m12) = y_axis.into();
+        let (m20, m21, m22) = z_axis.into();
+        if m22 <= 0.0 {
+            // x^2 + y^2 >= z^2 + w^2
+            let dif10 = m11 - m00;
+            let omm22 = 1.0 - m22;
+            if dif10 <= 0.0 {
+                // x^2 >= y^2
+                let four_xsq = omm22 - dif10;
+                let inv4x = 0.5 / four_xsq.sqrt();
+                Self::new(
+                    four_xsq * inv4x,
+                    (m01 + m10) * inv4x,
+                    (m02 + m20) * inv4x,
+                    (m12 - m21) * inv4x,
+                )
+            } else {
+                // y^2 >= x^2
+                let four_ysq = omm22 + dif10;
+                let inv4y = 0.5 / four_ysq.sqrt();
+                Self::new(
+                    (m01 + m10) * inv4y,
+                    four_ysq * inv4y,
+                    (m12 + m21) * inv4y,
+                    (m20 - m02) * inv4y,
+                )
+            }
+        } else {
+            // z^2 + w^2 >= x^2 + y^2
+            let sum10 = m11 + m00;
+            let opm22 = 1.0 + m22;
+            if sum10 <= 0.0 {
+                // z^2 >= w^2
+                let four_zsq = opm22 - sum10;
+                let inv4z = 0.5 / four_zsq.sqrt();
+                Self::new(
+                    (m02 + m20) * inv4z,
+                    (m12 + m21) * inv4z,
+                    four_zsq * inv4z,
+                    (m01 - m10) * inv4z,
+                )
+            } else {
+                // w^2 >= z^2
+                let four_wsq = opm22 + sum10;
+                let inv4w = 0.5 / four_wsq.sqrt();
+                Self::new(
+                    (m12 - m21) * inv4w,
+                    (m20 - m02) * inv4w,
+                    (m01 - m10) * inv4w,
+                    four_wsq * inv4w,
+                )
+            }
+        }
+    }
+
+    /// Creates a rotation of `angle` radians around `axis`, which must be normalized.
+    #[inline]
+    #[must_use]
     pub fn from_axis_angle(axis: Vec3, angle: f32) -> Self {
         let (s, c) = (angle * 0.5).sin_cos();
         let v = axis * s;
         Self::new(v.x, v.y, v.z, c)
     }
+
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_x(angle: f32) -> Self {
+        let (s, c) = (angle * 0.5).sin_cos();
+        Self::new(s, 0.0, 0.0, c)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_y(angle: f32) -> Self {
+        let (s, c) = (angle * 0.5).sin_cos();
+        Self::new(0.0, s, 0.0, c)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn from_rotation_z(angle: f32) -> Self {
+        let (s, c) = (angle * 0.5).sin_cos();
+        Self::new(0.0, 0.0, s, c)
+    }
+
+    /// Creates a rotation from Euler angles in radians, applied in the given order.
+    #[must_use]
+    pub fn from_euler(order: EulerRot, a: f32, b: f32, c: f32) -> Self {
+        let rotation = |axis, angle| match axis {
+            0 => Self::from_rotation_x(angle),
+            1 => Self::from_rotation_y(angle),
+            _ => Self::from_rotation_z(angle),
+        };
+        let (i, j, k) = order.axes();
+        rotation(i, a) * rotation(j, b) * rotation(k, c)
+    }
+
+    /// Returns the Euler angles in radians for the given order, the inverse of
+    /// [`Quat::from_euler`].
+    ///
+    /// The second angle is in `[-pi/2, pi/2]`. In gimbal lock, when it is at either end,
+    /// the third angle is returned as `0.0`.
+    #[must_use]
+    pub fn to_euler(self, order: EulerRot) -> (f32, f32, f32) {
+        // Just short of gimbal lock the first and third angle can not be told apart
+        const GIMBAL_LOCK: f32 = 0.99999;
+
+        let matrix = Mat4::from_quat(self);
+        let columns: [[f32; 4]; 3] = [
+            matrix.x_axis.into(),
+            matrix.y_axis.into(),
+            matrix.z_axis.into(),
+        ];
+        let m = |row: usize, column: usize| columns[column][row];
+        let (i, j, k) = order.axes();
+        let parity = order.parity();
+
+        let sin_b = (parity * m(i, k)).clamp(-1.0, 1.0);
+        let b = sin_b.asin();
+        if sin_b.abs() < GIMBAL_LOCK {
+            let a = (-parity * m(j, k)).atan2(m(k, k));
+            let c = (-parity * m(i, j)).atan2(m(i, i));
+            (a, b, c)
+        } else {
+            let a = (parity * m(k, j)).atan2(m(j, j));
+            (a, b, 0.0)
+        }
+    }
+
+    /// Creates the shortest rotation from the direction `from` to the direction `to`, both
+    /// must be normalized.
+    #[must_use]
+    pub fn from_rotation_arc(from: Vec3, to: Vec3) -> Self {
+        const ONE_MINUS_EPS: f32 = 1.0 - 2.0 * f32::EPSILON;
+        let dot = from.dot(to);
+        if dot > ONE_MINUS_EPS {
+            Self::IDENTITY
+        } else if dot < -ONE_MINUS_EPS {
+            // Opposite directions, any perpendicular axis works
+            Self::from_axis_angle(from.any_orthonormal_vector(), std::f32::consts::PI)
+        } else {
+            let c = from.cross(to);
+            Self::new(c.x, c.y, c.z, 1.0 + dot).normalize()
+        }
+    }
+
+    /// Creates a rotation from the upper 3x3 part of a matrix without scale.
+    #[inline]
+    #[must_use]
+    pub fn from_mat4(matrix: &Mat4) -> Self {
+        Self::from_rotation_axes(
+            matrix.x_axis.xyz(),
+            matrix.y_axis.xyz(),
+            matrix.z_axis.xyz(),
+        )
+    }
+
+    /// Creates a rotation from a 3x3 matrix without scale.
+    #[inline]
+    #[must_use]
+    pub fn from_mat3(matrix: &Mat3) -> Self {
+        Self::from_rotation_axes(matrix.x_axis, matrix.y_axis, matrix.z_axis)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> f32 {
+        Vec4(self.0).dot(Vec4(rhs.0))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn length(self) -> f32 {
+        Vec4(self.0).length()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn length_squared(self) -> f32 {
+        Vec4(self.0).length_squared()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        Self(Vec4(self.0).normalize().0)
+    }
+
+    /// Returns the rotation in the opposite direction, the inverse of a normalized quaternion.
+    #[inline]
+    #[must_use]
+    pub fn conjugate(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    /// Returns the inverse rotation, the quaternion must be normalized.
+    #[inline]
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        self.conjugate()
+    }
+
+    /// Interpolates linearly between `self` and `end` along the shorter path and normalizes
+    /// the result.
+    ///
+    /// Faster than [`Quat::slerp`] but the angular velocity is not constant.
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, end: Self, s: f32) -> Self {
+        let end = if self.dot(end) < 0.0 { -end } else { end };
+        Self(Vec4(self.0).lerp(Vec4(end.0), s).normalize().0)
+    }
+
+    /// Interpolates spherically between `self` and `end` along the shorter path, both must be
+    /// normalized.
+    #[must_use]
+    pub fn slerp(self, end: Self, s: f32) -> Self {
+        // Close rotations fall back to lerp to avoid dividing by sin(~0)
+        const DOT_THRESHOLD: f32 = 0.9995;
+
+        let mut dot = self.dot(end);
+        let end = if dot < 0.0 {
+            dot = -dot;
+            -end
+        } else {
+            end
+        };
+        if dot > DOT_THRESHOLD {
+            return self.lerp(end, s);
+        }
+        let theta = dot.acos();
+        let scale_start = (theta * (1.0 - s)).sin();
+        let scale_end = (theta * s).sin();
+        let start = Vec4(self.0) * scale_start;
+        let end = Vec4(end.0) * scale_end;
+        Self(((start + end) / theta.sin()).0)
+    }
+
+    /// Interpolates between `self` and `end` with spherical cubic interpolation, giving a
+    /// smooth rotation through a sequence of keys.
+    ///
+    /// `a` and `b` are the control points of `self` and `end`, created with
+    /// [`Quat::squad_control`]. Squad passes through both keys and, unlike chained
+    /// [`Quat::slerp`]s, has a continuous angular velocity at them.
+    #[must_use]
+    pub fn squad(self, a: Self, b: Self, end: Self, s: f32) -> Self {
+        self.slerp(end, s).slerp(a.slerp(b, s), 2.0 * s * (1.0 - s))
+    }
+
+    /// Returns the [`Quat::squad`] control point of the key `self` between `prev` and `next`.
+    ///
+    /// For the first and last key of a sequence pass the key itself as the missing neighbour.
+    #[must_use]
+    pub fn squad_control(self, prev: Self, next: Self) -> Self {
+        let inverse = self.inverse();
+        // ln(q) is half of the scaled axis
+        let to_prev = (inverse * prev).to_scaled_axis();
+        let to_next = (inverse * next).to_scaled_axis();
+        self * Self::from_scaled_axis((to_prev + to_next) * -0.25)
+    }
+
+    /// Returns the Hamilton product, applying `rhs` first and then `self`.
+    #[inline]
+    #[must_use]
+    pub fn mul_quat(self, rhs: Self) -> Self {
+        const CONTROL_WZYX: Vec4 = Vec4::new(1.0, -1.0, 1.0, -1.0);
+        const CONTROL_ZWXY: Vec4 = Vec4::new(1.0, 1.0, -1.0, -1.0);
+        const CONTROL_YXWZ: Vec4 = Vec4::new(-1.0, 1.0, 1.0, -1.0);
+
+        let lhs = self.0;
+        let rhs = rhs.0;
+        unsafe {
+            let l_xxxx = _mm_shuffle_ps(lhs, lhs, 0b00_00_00_00);
+            let l_yyyy = _mm_shuffle_ps(lhs, lhs, 0b01_01_01_01);
+            let l_zzzz = _mm_shuffle_ps(lhs, lhs, 0b10_10_10_10);
+            let l_wwww = _mm_shuffle_ps(lhs, lhs, 0b11_11_11_11);
+
+            let lwrx_lwry_lwrz_lwrw = _mm_mul_ps(l_wwww, rhs);
+            let r_wzyx = _mm_shuffle_ps(rhs, rhs, 0b00_01_10_11);
+            let lxrw_lxrz_lxry_lxrx = _mm_mul_ps(l_xxxx, r_wzyx);
+            let r_zwxy = _mm_shuffle_ps(r_wzyx, r_wzyx, 0b10_11_00_01);
+            let lxrw_nlxrz_lxry_nlxrx = _mm_mul_ps(lxrw_lxrz_lxry_lxrx, CONTROL_WZYX.0);
+            let lyrz_lyrw_lyrx_lyry = _mm_mul_ps(l_yyyy, r_zwxy);
+            let r_yxwz = _mm_shuffle_ps(r_zwxy, r_zwxy, 0b00_01_10_11);
+            let lyrz_lyrw_nlyrx_nlyry = _mm_mul_ps(lyrz_lyrw_lyrx_lyry, CONTROL_ZWXY.0);
+            let lzry_lzrx_lzrw_lzrz = _mm_mul_ps(l_zzzz, r_yxwz);
+            let result0 = _mm_add_ps(lwrx_lwry_lwrz_lwrw, lxrw_nlxrz_lxry_nlxrx);
+            let nlzry_lzrx_lzrw_nlzrz = _mm_mul_ps(lzry_lzrx_lzrw_lzrz, CONTROL_YXWZ.0);
+            let result1 = _mm_add_ps(lyrz_lyrw_nlyrx_nlyry, nlzry_lzrx_lzrw_nlzrz);
+            Self(_mm_add_ps(result0, result1))
+        }
+    }
+
+    /// Rotates a vector, the quaternion must be normalized.
+    #[inline]
+    #[must_use]
+    pub fn mul_vec3(self, rhs: Vec3) -> Vec3 {
+        let w = self.w;
+        let b = self.xyz();
+        let b2 = b.dot(b);
+        rhs * (w * w - b2) + b * (rhs.dot(b) * 2.0) + b.cross(rhs) * (w * 2.0)
+    }
+
+    /// Returns whether all elements of `self` and `rhs` differ by at most `max_abs_diff`.
+    ///
+    /// `q` and `-q` describe the same rotation but are not considered equal.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        (self.x - rhs.x).abs() <= max_abs_diff
+            && (self.y - rhs.y).abs() <= max_abs_diff
+            && (self.z - rhs.z).abs() <= max_abs_diff
+            && (self.w - rhs.w).abs() <= max_abs_diff
+    }
+}
+
+impl Mul<Quat> for Quat {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_quat(rhs)
+    }
+}
+
+impl MulAssign<Quat> for Quat {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.mul_quat(rhs);
+    }
+}
+
+impl Mul<Vec3> for Quat {
+    type Output = Vec3;
+    #[inline]
+    fn mul(self, rhs: Vec3) -> Vec3 {
+        self.mul_vec3(rhs)
+    }
+}
+
+impl Neg for Quat {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self((-Vec4(self.0)).0)
+    }
+}
+
+impl From<[f32; 4]> for Quat {
+    /// Creates a quaternion from `[x, y, z, w]` without normalizing it.
+    #[inline]
+    fn from(a: [f32; 4]) -> Self {
+        Self::from_array(a)
+    }
 }
 
 impl From<Quat> for Vec4 {
@@ -101,3 +482,35 @@ impl DerefMut for Quat {
         unsafe { &mut *(self as *mut Self).cast() }
     }
 }
+
+impl Default for Quat {
+    #[inline]
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl fmt::Debug for Quat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Quat")
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .field("w", &self.w)
+            .finish()
+    }
+}
+
+impl fmt::Display for Quat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(
+                f,
+                "[{:.*}, {:.*}, {:.*}, {:.*}]",
+                p, self.x, p, self.y, p, self.z, p, self.w
+            )
+        } else {
+            write!(f, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod dvec2;
+pub mod dvec3;
+pub mod dvec4;
@@ -1 +1,3 @@
 pub mod ivec2;
+pub mod ivec3;
+pub mod ivec4;
@@ -2,4 +2,6 @@ pub mod vec2;
 pub mod vec3;
 pub mod vec4;
 
+pub mod f64;
 pub mod i32;
+pub mod u32;
@@ -0,0 +1,2 @@
+pub mod uvec2;
+pub mod uvec3;
@@ -1,9 +1,16 @@
 use std::{
-    arch::x86_64::{__m128, _mm_add_ps, _mm_loadu_ps, _mm_mul_ps, _mm_set1_ps, _mm_store_ps},
-    ops::{Add, AddAssign, Deref, DerefMut, Mul, MulAssign},
+    arch::x86_64::{
+        __m128, _mm_add_ps, _mm_add_ss, _mm_cvtss_f32, _mm_div_ps, _mm_loadu_ps, _mm_mul_ps,
+        _mm_set1_ps, _mm_shuffle_ps, _mm_store_ps, _mm_sub_ps, _mm_xor_ps,
+    },
+    fmt,
+    ops::{Add, AddAssign, Deref, DerefMut, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 
-use crate::align16::Align16;
+use crate::{
+    align16::Align16,
+    vec::{f64::dvec4::DVec4, i32::ivec4::IVec4},
+};
 
 use super::{vec2::Vec2, vec3::Vec3};
 
@@ -73,24 +80,95 @@ impl Vec4 {
     #[inline]
     #[must_use]
     pub fn xxxx(self) -> Self {
-        Self::new(self.x, self.x, self.x, self.x)
+        Self(unsafe { _mm_shuffle_ps(self.0, self.0, 0b00_00_00_00) })
     }
+
     #[inline]
     #[must_use]
     pub fn yyyy(self) -> Self {
-        Self::new(self.y, self.y, self.y, self.y)
+        Self(unsafe { _mm_shuffle_ps(self.0, self.0, 0b01_01_01_01) })
     }
 
     #[inline]
     #[must_use]
     pub fn zzzz(self) -> Self {
-        Self::new(self.z, self.z, self.z, self.z)
+        Self(unsafe { _mm_shuffle_ps(self.0, self.0, 0b10_10_10_10) })
     }
 
     #[inline]
     #[must_use]
     pub fn wwww(self) -> Self {
-        Self::new(self.w, self.w, self.w, self.w)
+        Self(unsafe { _mm_shuffle_ps(self.0, self.0, 0b11_11_11_11) })
+    }
+
+    /// Returns the first three elements, dropping `w`.
+    #[inline]
+    #[must_use]
+    pub fn xyz(self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn dot(self, rhs: Self) -> f32 {
+        unsafe {
+            let xyzw = _mm_mul_ps(self.0, rhs.0);
+            // (x + z, y + w, ..)
+            let zwxx = _mm_shuffle_ps(xyzw, xyzw, 0b00_00_11_10);
+            let sum = _mm_add_ps(xyzw, zwxx);
+            let yyyy = _mm_shuffle_ps(sum, sum, 0b01_01_01_01);
+            _mm_cvtss_f32(_mm_add_ss(sum, yyyy))
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn length(self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn normalize(self) -> Self {
+        self.mul(self.length().recip())
+    }
+
+    /// Linearly interpolates between `self` and `rhs`, returning `self` at `s = 0.0` and
+    /// `rhs` at `s = 1.0`.
+    #[inline]
+    #[must_use]
+    pub fn lerp(self, rhs: Self, s: f32) -> Self {
+        self.add(rhs.sub(self).mul(s))
+    }
+
+    /// Casts all elements of `self` to `f64`.
+    #[inline]
+    #[must_use]
+    pub fn as_dvec4(&self) -> DVec4 {
+        DVec4::new(self.x as f64, self.y as f64, self.z as f64, self.w as f64)
+    }
+
+    /// Casts all elements of `self` to `i32`.
+    #[inline]
+    #[must_use]
+    pub fn as_ivec4(&self) -> IVec4 {
+        IVec4::new(self.x as i32, self.y as i32, self.z as i32, self.w as i32)
+    }
+
+    /// Returns whether all elements of `self` and `rhs` differ by at most `max_abs_diff`.
+    #[inline]
+    #[must_use]
+    pub fn abs_diff_eq(self, rhs: Self, max_abs_diff: f32) -> bool {
+        (self.x - rhs.x).abs() <= max_abs_diff
+            && (self.y - rhs.y).abs() <= max_abs_diff
+            && (self.z - rhs.z).abs() <= max_abs_diff
+            && (self.w - rhs.w).abs() <= max_abs_diff
     }
 }
 
@@ -109,6 +187,21 @@ impl AddAssign<Vec4> for Vec4 {
     }
 }
 
+impl Sub<Vec4> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_sub_ps(self.0, rhs.0) })
+    }
+}
+
+impl SubAssign<Vec4> for Vec4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 = unsafe { _mm_sub_ps(self.0, rhs.0) };
+    }
+}
+
 impl Mul<Vec4> for Vec4 {
     type Output = Self;
     #[inline]
@@ -147,6 +240,44 @@ impl Mul<Vec4> for f32 {
     }
 }
 
+impl Div<Vec4> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self(unsafe { _mm_div_ps(self.0, rhs.0) })
+    }
+}
+
+impl DivAssign<Vec4> for Vec4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        self.0 = unsafe { _mm_div_ps(self.0, rhs.0) };
+    }
+}
+
+impl Div<f32> for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: f32) -> Self {
+        Self(unsafe { _mm_div_ps(self.0, _mm_set1_ps(rhs)) })
+    }
+}
+
+impl DivAssign<f32> for Vec4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        self.0 = unsafe { _mm_div_ps(self.0, _mm_set1_ps(rhs)) };
+    }
+}
+
+impl Neg for Vec4 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(unsafe { _mm_xor_ps(_mm_set1_ps(-0.0), self.0) })
+    }
+}
+
 impl From<Vec4> for __m128 {
     #[inline(always)]
     fn from(t: Vec4) -> Self {
@@ -241,3 +372,35 @@ impl DerefMut for Vec4 {
         unsafe { &mut *(self as *mut Self).cast() }
     }
 }
+
+impl Default for Vec4 {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl fmt::Debug for Vec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Vec4")
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .field("w", &self.w)
+            .finish()
+    }
+}
+
+impl fmt::Display for Vec4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(p) = f.precision() {
+            write!(
+                f,
+                "[{:.*}, {:.*}, {:.*}, {:.*}]",
+                p, self.x, p, self.y, p, self.z, p, self.w
+            )
+        } else {
+            write!(f, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
+        }
+    }
+}
@@ -0,0 +1,276 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use vent_math::{
+    curve::{ArcLengthTable, CatmullRom, CubicBezier, CubicHermite, Curve, Easing},
+    scalar::quat::Quat,
+    vec::{vec2::Vec2, vec3::Vec3},
+};
+
+fn assert_vec2(actual: Vec2, expected: Vec2) {
+    assert!(actual.abs_diff_eq(expected, 1e-5), "{actual} != {expected}");
+}
+
+fn assert_close(actual: f32, expected: f32, epsilon: f32) {
+    assert!(
+        (actual - expected).abs() <= epsilon,
+        "{actual} != {expected}"
+    );
+}
+
+/// Approximates the unit quarter circle from (1, 0) to (0, 1).
+fn quarter_circle() -> CubicBezier<Vec2> {
+    const K: f32 = 0.552_284_8;
+    CubicBezier::new(
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, K),
+        Vec2::new(K, 1.0),
+        Vec2::new(0.0, 1.0),
+    )
+}
+
+#[test]
+fn bezier() {
+    let curve = CubicBezier::new(
+        Vec2::new(0.0, 0.0),
+        Vec2::new(0.0, 1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 0.0),
+    );
+    assert_vec2(curve.position(0.0), Vec2::new(0.0, 0.0));
+    assert_vec2(curve.position(0.5), Vec2::new(0.5, 0.75));
+    assert_vec2(curve.position(0.25), Vec2::new(0.15625, 0.5625));
+    assert_vec2(curve.position(1.0), Vec2::new(1.0, 0.0));
+    // The end tangents point at the neighbouring control points
+    assert_vec2(curve.velocity(0.0), Vec2::new(0.0, 3.0));
+    assert_vec2(curve.velocity(0.5), Vec2::new(1.5, 0.0));
+    assert_vec2(curve.velocity(1.0), Vec2::new(0.0, -3.0));
+
+    let (first, second) = curve.split(0.25);
+    assert_vec2(first.position(1.0), curve.position(0.25));
+    assert_vec2(first.position(0.5), curve.position(0.125));
+    assert_vec2(second.position(0.5), curve.position(0.625));
+}
+
+#[test]
+fn bezier_circle() {
+    let circle = quarter_circle();
+    for i in 0..=16 {
+        let point = circle.position(i as f32 / 16.0);
+        assert_close(point.length(), 1.0, 3e-4);
+    }
+}
+
+#[test]
+fn hermite() {
+    // Matching end velocities give a straight line with a constant speed
+    let line = CubicHermite::new(Vec3::ZERO, Vec3::X * 2.0, Vec3::X * 2.0, Vec3::X * 2.0);
+    for i in 0..=4 {
+        let t = i as f32 / 4.0;
+        assert!(line.position(t).abs_diff_eq(Vec3::X * 2.0 * t, 1e-6));
+        assert!(line.velocity(t).abs_diff_eq(Vec3::X * 2.0, 1e-6));
+    }
+
+    let curve = CubicHermite::new(Vec2::ZERO, Vec2::Y, Vec2::X, Vec2::NEG_Y);
+    assert_vec2(curve.position(0.5), Vec2::new(0.5, 0.25));
+    assert_vec2(curve.velocity(0.0), Vec2::Y);
+    assert_vec2(curve.velocity(1.0), Vec2::NEG_Y);
+}
+
+#[test]
+fn catmull_rom() {
+    let points = vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(3.0, 1.0),
+    ];
+    let spline = CatmullRom::new(points.clone());
+    assert_eq!(spline.max_t(), 3.0);
+    for (i, point) in points.iter().enumerate() {
+        assert_vec2(spline.position(i as f32), *point);
+    }
+    // The tangent at inner points is half the vector between the neighbours
+    assert_vec2(spline.velocity(1.0), Vec2::new(1.0, 0.0));
+    assert_vec2(spline.velocity(2.0), Vec2::new(1.0, 0.0));
+    // Both segments meeting at a point share its tangent
+    assert_vec2(spline.velocity(0.999_999), spline.velocity(1.0));
+    // Symmetric middle segment
+    assert_vec2(spline.position(1.5), Vec2::new(1.5, 0.5));
+
+    let line = CatmullRom::new((0..5).map(|i| Vec3::X * i as f32).collect());
+    for i in 0..=8 {
+        let t = i as f32 / 2.0;
+        assert!(line.position(t).abs_diff_eq(Vec3::X * t, 1e-5));
+    }
+}
+
+#[test]
+#[should_panic]
+fn catmull_rom_single_point() {
+    let _ = CatmullRom::new(vec![Vec2::ZERO]);
+}
+
+#[test]
+fn arc_length() {
+    let circle = quarter_circle();
+    let table = ArcLengthTable::new(&circle, 256);
+    assert_close(table.length(), FRAC_PI_2, 1e-3);
+    assert_eq!(table.t_at_distance(-1.0), 0.0);
+    assert_eq!(table.t_at_distance(10.0), 1.0);
+    // Equal steps in distance give equal steps in angle
+    for i in 0..=8 {
+        let fraction = i as f32 / 8.0;
+        let point = circle.position(table.t_at_fraction(fraction));
+        assert_close(point.y.atan2(point.x), fraction * FRAC_PI_2, 2e-3);
+    }
+
+    // Control points bunched at the end make the curve slow down
+    let line = CubicBezier::new(
+        Vec2::ZERO,
+        Vec2::new(0.9, 0.0),
+        Vec2::new(0.95, 0.0),
+        Vec2::X,
+    );
+    let table = ArcLengthTable::new(&line, 128);
+    assert_close(table.length(), 1.0, 1e-5);
+    for i in 0..=10 {
+        let fraction = i as f32 / 10.0;
+        assert_close(
+            line.position(table.t_at_fraction(fraction)).x,
+            fraction,
+            1e-3,
+        );
+    }
+
+    let spline = CatmullRom::new(vec![Vec2::ZERO, Vec2::X, Vec2::new(1.0, 1.0)]);
+    let table = ArcLengthTable::new(&spline, 512);
+    assert_eq!(table.t_at_fraction(1.0), 2.0);
+}
+
+#[test]
+fn easing() {
+    let all = [
+        Easing::Linear,
+        Easing::QuadraticIn,
+        Easing::QuadraticOut,
+        Easing::QuadraticInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::QuarticIn,
+        Easing::QuarticOut,
+        Easing::QuarticInOut,
+        Easing::QuinticIn,
+        Easing::QuinticOut,
+        Easing::QuinticInOut,
+        Easing::SineIn,
+        Easing::SineOut,
+        Easing::SineInOut,
+        Easing::ExponentialIn,
+        Easing::ExponentialOut,
+        Easing::ExponentialInOut,
+        Easing::CircularIn,
+        Easing::CircularOut,
+        Easing::CircularInOut,
+        Easing::BackIn,
+        Easing::BackOut,
+        Easing::BackInOut,
+        Easing::ElasticIn,
+        Easing::ElasticOut,
+        Easing::ElasticInOut,
+        Easing::BounceIn,
+        Easing::BounceOut,
+        Easing::BounceInOut,
+    ];
+    for easing in all {
+        assert_close(easing.ease(0.0), 0.0, 1e-6);
+        assert_close(easing.ease(1.0), 1.0, 1e-6);
+        assert_close(easing.ease(-1.0), 0.0, 1e-6);
+        assert_close(easing.ease(2.0), 1.0, 1e-6);
+        // In and out halves meet in the middle
+        let name = format!("{easing:?}");
+        if name.ends_with("InOut") {
+            assert_close(easing.ease(0.5), 0.5, 1e-6);
+            assert_close(easing.ease(0.5 - 1e-4), 0.5, 5e-2);
+            assert_close(easing.ease(0.5 + 1e-4), 0.5, 5e-2);
+        }
+    }
+
+    assert_close(Easing::Linear.ease(0.3), 0.3, 1e-6);
+    assert_close(Easing::QuadraticIn.ease(0.5), 0.25, 1e-6);
+    assert_close(Easing::QuadraticOut.ease(0.5), 0.75, 1e-6);
+    assert_close(Easing::QuadraticInOut.ease(0.25), 0.125, 1e-6);
+    assert_close(Easing::CubicOut.ease(0.5), 0.875, 1e-6);
+    assert_close(Easing::QuarticIn.ease(0.5), 0.0625, 1e-6);
+    assert_close(Easing::QuinticInOut.ease(0.75), 0.984_375, 1e-6);
+    assert_close(Easing::SineIn.ease(0.5), 1.0 - (PI / 4.0).cos(), 1e-6);
+    assert_close(Easing::ExponentialIn.ease(0.5), 1.0 / 32.0, 1e-6);
+    assert_close(Easing::CircularOut.ease(0.5), 0.75f32.sqrt(), 1e-6);
+    assert!(Easing::BackIn.ease(0.3) < 0.0);
+    assert!(Easing::BackOut.ease(0.7) > 1.0);
+    assert!(Easing::ElasticOut.ease(0.1) > 1.0);
+    // The ball hits the ground at the end of the first fall
+    assert_close(Easing::BounceOut.ease(1.0 / 2.75), 1.0, 1e-6);
+    assert_close(
+        Easing::BounceIn.ease(0.5),
+        1.0 - Easing::BounceOut.ease(0.5),
+        1e-6,
+    );
+}
+
+#[test]
+fn scaled_axis() {
+    let axis = Vec3::new(1.0, 2.0, 2.0) / 3.0;
+    let q = Quat::from_axis_angle(axis, 2.0);
+    assert!(q.to_scaled_axis().abs_diff_eq(axis * 2.0, 1e-5));
+    assert!((-q).to_scaled_axis().abs_diff_eq(axis * 2.0, 1e-5));
+    // A rotation by more than pi is the shorter one the other way around
+    let q = Quat::from_axis_angle(axis, 4.0);
+    assert!(q
+        .to_scaled_axis()
+        .abs_diff_eq(axis * (4.0 - 2.0 * PI), 1e-5));
+    assert!(Quat::IDENTITY.to_scaled_axis() == Vec3::ZERO);
+}
+
+#[test]
+fn squad() {
+    let keys: Vec<Quat> = (0..4)
+        .map(|i| Quat::from_rotation_y(i as f32 * 0.5))
+        .collect();
+    let control = |i: usize| keys[i].squad_control(keys[i.saturating_sub(1)], keys[(i + 1).min(3)]);
+
+    // Keys rotating with a constant velocity around one axis need no correction
+    assert!(control(1).abs_diff_eq(keys[1], 1e-6));
+    for i in 0..=4 {
+        let s = i as f32 / 4.0;
+        let q = keys[1].squad(control(1), control(2), keys[2], s);
+        assert!(q.abs_diff_eq(Quat::from_rotation_y(0.5 + 0.5 * s), 1e-5));
+    }
+
+    let keys = [
+        Quat::IDENTITY,
+        Quat::from_rotation_x(1.0),
+        Quat::from_rotation_x(1.0) * Quat::from_rotation_z(1.0),
+        Quat::from_rotation_z(-0.5),
+    ];
+    let control = |i: usize| keys[i].squad_control(keys[i.saturating_sub(1)], keys[(i + 1).min(3)]);
+    for i in 0..3 {
+        let (a, b) = (control(i), control(i + 1));
+        assert!(keys[i]
+            .squad(a, b, keys[i + 1], 0.0)
+            .abs_diff_eq(keys[i], 1e-5));
+        assert!(keys[i]
+            .squad(a, b, keys[i + 1], 1.0)
+            .abs_diff_eq(keys[i + 1], 1e-5));
+        let mid = keys[i].squad(a, b, keys[i + 1], 0.5);
+        assert_close(mid.length(), 1.0, 1e-5);
+    }
+
+    // Angular velocity is continuous across a key
+    let angular_velocity = |from: Quat, to: Quat| (from.inverse() * to).to_scaled_axis() / 1e-3;
+    let before = keys[0].squad(control(0), control(1), keys[1], 1.0 - 1e-3);
+    let after = keys[1].squad(control(1), control(2), keys[2], 1e-3);
+    let left = angular_velocity(before, keys[1]);
+    let right = angular_velocity(keys[1], after);
+    assert!(left.abs_diff_eq(right, 0.05), "{left} != {right}");
+}
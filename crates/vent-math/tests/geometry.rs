@@ -0,0 +1,238 @@
+use std::f32::consts::FRAC_PI_2;
+
+use vent_math::{
+    geometry::{Aabb, Frustum, Plane, Ray, Sphere},
+    scalar::{mat4::Mat4, quat::Quat},
+    vec::vec3::Vec3,
+};
+
+fn assert_close(actual: f32, expected: f32) {
+    assert!((actual - expected).abs() < 1e-5, "{actual} != {expected}");
+}
+
+fn unit_box() -> Aabb {
+    Aabb::new(Vec3::NEG_ONE, Vec3::ONE)
+}
+
+#[test]
+fn aabb_aabb() {
+    let a = unit_box();
+    assert!(a.intersects_aabb(&Aabb::new(Vec3::ZERO, Vec3::splat(2.0))));
+    // Touching faces
+    assert!(a.intersects_aabb(&Aabb::new(Vec3::new(1.0, -1.0, -1.0), Vec3::splat(3.0))));
+    assert!(!a.intersects_aabb(&Aabb::new(Vec3::new(1.1, 0.0, 0.0), Vec3::splat(3.0))));
+    // Overlapping on two axes only
+    assert!(!a.intersects_aabb(&Aabb::new(
+        Vec3::new(-0.5, -0.5, 2.0),
+        Vec3::new(0.5, 0.5, 3.0)
+    )));
+}
+
+#[test]
+fn aabb_construction() {
+    assert!(Aabb::EMPTY.is_empty());
+    assert!(Aabb::from_points([]).is_empty());
+    let aabb = Aabb::from_points([
+        Vec3::new(1.0, -2.0, 3.0),
+        Vec3::new(-1.0, 4.0, 0.0),
+        Vec3::new(0.0, 0.0, -5.0),
+    ]);
+    assert!(aabb == Aabb::new(Vec3::new(-1.0, -2.0, -5.0), Vec3::new(1.0, 4.0, 3.0)));
+    assert!(aabb.center() == Vec3::new(0.0, 1.0, -1.0));
+    assert!(aabb.contains_point(Vec3::new(1.0, 4.0, 3.0)));
+    assert!(!aabb.contains_point(Vec3::new(1.0, 4.1, 3.0)));
+    assert!(aabb.union(&unit_box()) == aabb);
+}
+
+#[test]
+fn aabb_transform() {
+    let rotated = unit_box().transform(&Mat4::from_rotation_translation(
+        Quat::from_rotation_z(FRAC_PI_2 * 0.5),
+        Vec3::new(10.0, 0.0, 0.0),
+    ));
+    let extent = 2.0_f32.sqrt();
+    assert_close(rotated.min.x, 10.0 - extent);
+    assert_close(rotated.max.x, 10.0 + extent);
+    assert_close(rotated.max.y, extent);
+    assert_close(rotated.max.z, 1.0);
+
+    let scaled = unit_box().transform(&Mat4::from_scale(Vec3::new(2.0, -3.0, 1.0)));
+    assert!(scaled == Aabb::new(Vec3::new(-2.0, -3.0, -1.0), Vec3::new(2.0, 3.0, 1.0)));
+}
+
+#[test]
+fn sphere_intersections() {
+    let sphere = Sphere::new(Vec3::ZERO, 1.0);
+    assert!(sphere.intersects_sphere(&Sphere::new(Vec3::new(2.0, 0.0, 0.0), 1.0)));
+    assert!(!sphere.intersects_sphere(&Sphere::new(Vec3::new(2.1, 0.0, 0.0), 1.0)));
+
+    assert!(sphere.intersects_aabb(&Aabb::new(Vec3::splat(0.5), Vec3::splat(2.0))));
+    // The corner of the box is outside of the sphere, its faces are not
+    assert!(!sphere.intersects_aabb(&Aabb::new(Vec3::splat(0.6), Vec3::splat(2.0))));
+    assert!(unit_box().intersects_sphere(&Sphere::new(Vec3::new(1.5, 0.0, 0.0), 0.6)));
+    assert!(Sphere::from_aabb(&unit_box()).contains_point(Vec3::ONE));
+}
+
+#[test]
+fn ray_aabb() {
+    let aabb = unit_box();
+    let ray = Ray::new(Vec3::new(-5.0, 0.0, 0.0), Vec3::X);
+    assert_close(ray.intersect_aabb(&aabb).unwrap(), 4.0);
+    assert!(ray.at(4.0) == Vec3::new(-1.0, 0.0, 0.0));
+    // Starting inside
+    assert_close(
+        Ray::new(Vec3::ZERO, Vec3::Y).intersect_aabb(&aabb).unwrap(),
+        0.0,
+    );
+    // Pointing away
+    assert!(Ray::new(Vec3::new(-5.0, 0.0, 0.0), Vec3::NEG_X)
+        .intersect_aabb(&aabb)
+        .is_none());
+    // Parallel to a slab, outside of it
+    assert!(Ray::new(Vec3::new(-5.0, 2.0, 0.0), Vec3::X)
+        .intersect_aabb(&aabb)
+        .is_none());
+    // Diagonal through a corner region that misses
+    assert!(
+        Ray::new(Vec3::new(-3.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 0.0))
+            .intersect_aabb(&aabb)
+            .is_none()
+    );
+    let t = Ray::new(Vec3::new(-2.0, -2.0, 0.5), Vec3::new(1.0, 1.0, 0.0))
+        .intersect_aabb(&aabb)
+        .unwrap();
+    assert_close(t, 2.0_f32.sqrt());
+}
+
+#[test]
+fn ray_sphere() {
+    let sphere = Sphere::new(Vec3::new(0.0, 0.0, -10.0), 2.0);
+    let ray = Ray::new(Vec3::ZERO, Vec3::NEG_Z);
+    assert_close(ray.intersect_sphere(&sphere).unwrap(), 8.0);
+    assert!(Ray::new(Vec3::ZERO, Vec3::Z)
+        .intersect_sphere(&sphere)
+        .is_none());
+    assert!(Ray::new(Vec3::new(0.0, 2.1, 0.0), Vec3::NEG_Z)
+        .intersect_sphere(&sphere)
+        .is_none());
+    assert_close(
+        Ray::new(Vec3::new(0.0, 0.0, -9.0), Vec3::X)
+            .intersect_sphere(&sphere)
+            .unwrap(),
+        0.0,
+    );
+}
+
+#[test]
+fn ray_triangle() {
+    let (a, b, c) = (
+        Vec3::new(-1.0, -1.0, 0.0),
+        Vec3::new(1.0, -1.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+    );
+    let ray = Ray::new(Vec3::new(0.0, 0.0, 5.0), Vec3::NEG_Z);
+    assert_close(ray.intersect_triangle(a, b, c).unwrap(), 5.0);
+    // Back faces are hit as well
+    assert_close(
+        Ray::new(Vec3::new(0.0, 0.0, -5.0), Vec3::Z)
+            .intersect_triangle(a, b, c)
+            .unwrap(),
+        5.0,
+    );
+    assert!(Ray::new(Vec3::new(0.9, 0.9, 5.0), Vec3::NEG_Z)
+        .intersect_triangle(a, b, c)
+        .is_none());
+    // Behind the origin
+    assert!(Ray::new(Vec3::new(0.0, 0.0, 5.0), Vec3::Z)
+        .intersect_triangle(a, b, c)
+        .is_none());
+    // Parallel to the triangle
+    assert!(Ray::new(Vec3::new(-5.0, 0.0, 0.0), Vec3::X)
+        .intersect_triangle(a, b, c)
+        .is_none());
+}
+
+#[test]
+fn plane() {
+    let plane = Plane::from_points(Vec3::ZERO, Vec3::X, Vec3::Y);
+    assert!(plane.normal == Vec3::Z);
+    assert_close(plane.signed_distance(Vec3::new(3.0, 4.0, -2.0)), -2.0);
+    assert!(plane.project_point(Vec3::new(3.0, 4.0, -2.0)) == Vec3::new(3.0, 4.0, 0.0));
+
+    let ground = Plane::from_point_normal(Vec3::new(0.0, 1.0, 0.0), Vec3::Y);
+    let ray = Ray::new(Vec3::new(0.0, 5.0, 0.0), Vec3::new(1.0, -1.0, 0.0));
+    let t = ray.intersect_plane(&ground).unwrap();
+    assert_close(ray.at(t).y, 1.0);
+    assert!(Ray::new(Vec3::new(0.0, 5.0, 0.0), Vec3::X)
+        .intersect_plane(&ground)
+        .is_none());
+}
+
+fn camera_frustum() -> Frustum {
+    // Camera at z = 5 looking down -z with a 90 degree field of view
+    let view = Mat4::look_at_rh(Vec3::new(0.0, 0.0, 5.0), Vec3::ZERO, Vec3::Y);
+    let projection = Mat4::perspective_rh(FRAC_PI_2, 1.0, 0.1, 100.0);
+    Frustum::from_view_proj(&(projection * view))
+}
+
+#[test]
+fn frustum_planes() {
+    let frustum = camera_frustum();
+    for plane in &frustum.planes {
+        assert_close(plane.normal.length(), 1.0);
+    }
+    // Near and far plane
+    assert_close(
+        frustum.planes[4].signed_distance(Vec3::new(0.0, 0.0, 4.9)),
+        0.0,
+    );
+    // The far plane is reconstructed from the depth row, which loses precision
+    assert!(
+        frustum.planes[5]
+            .signed_distance(Vec3::new(0.0, 0.0, -95.0))
+            .abs()
+            < 1e-3
+    );
+
+    assert!(frustum.contains_point(Vec3::ZERO));
+    assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, 6.0)));
+    assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, -96.0)));
+    // The side planes are at 45 degrees, 5 units in front of the camera they are 5 units out
+    assert!(frustum.contains_point(Vec3::new(4.9, 0.0, 0.0)));
+    assert!(!frustum.contains_point(Vec3::new(5.1, 0.0, 0.0)));
+    assert!(!frustum.contains_point(Vec3::new(0.0, -5.1, 0.0)));
+}
+
+#[test]
+fn frustum_culling() {
+    let frustum = camera_frustum();
+    assert!(frustum.intersects_sphere(&Sphere::new(Vec3::ZERO, 1.0)));
+    assert!(frustum.intersects_sphere(&Sphere::new(Vec3::new(6.0, 0.0, 0.0), 1.0)));
+    assert!(!frustum.intersects_sphere(&Sphere::new(Vec3::new(8.0, 0.0, 0.0), 1.0)));
+    assert!(!frustum.intersects_sphere(&Sphere::new(Vec3::new(0.0, 0.0, 8.0), 1.0)));
+
+    assert!(frustum.intersects_aabb(&unit_box()));
+    assert!(frustum.intersects_aabb(&Aabb::new(Vec3::new(4.5, -1.0, -1.0), Vec3::splat(6.0))));
+    assert!(!frustum.intersects_aabb(&Aabb::new(
+        Vec3::new(7.0, -1.0, -1.0),
+        Vec3::new(8.0, 1.0, 1.0)
+    )));
+    // Behind the camera and beyond the far plane
+    assert!(!frustum.intersects_aabb(&Aabb::new(Vec3::new(-1.0, -1.0, 6.0), Vec3::splat(7.0))));
+    assert!(!frustum.intersects_aabb(&Aabb::new(
+        Vec3::new(-1.0, -1.0, -200.0),
+        Vec3::new(1.0, 1.0, -150.0)
+    )));
+    // Containing the whole frustum
+    assert!(frustum.intersects_aabb(&Aabb::new(Vec3::splat(-1000.0), Vec3::splat(1000.0))));
+}
+
+#[test]
+fn orthographic_frustum() {
+    let projection = Mat4::orthographic_rh(-10.0, 10.0, -5.0, 5.0, 0.0, 50.0);
+    let frustum = Frustum::from_view_proj(&projection);
+    assert!(frustum.contains_point(Vec3::new(9.9, 4.9, -49.0)));
+    assert!(!frustum.contains_point(Vec3::new(10.1, 0.0, -1.0)));
+    assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, 1.0)));
+    assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, -51.0)));
+}
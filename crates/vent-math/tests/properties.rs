@@ -0,0 +1,660 @@
+//! Property tests comparing vent-math against straightforward `f64` reference math on
+//! random inputs.
+
+use std::f64::consts::PI;
+
+use vent_math::{
+    scalar::{
+        affine3a::Affine3A,
+        euler::EulerRot,
+        f64::{dmat4::DMat4, dquat::DQuat},
+        mat2::Mat2,
+        mat3::Mat3,
+        mat4::Mat4,
+        quat::Quat,
+    },
+    vec::{
+        f64::{dvec3::DVec3, dvec4::DVec4},
+        i32::ivec3::IVec3,
+        u32::uvec2::UVec2,
+        vec2::Vec2,
+        vec3::Vec3,
+        vec4::Vec4,
+    },
+};
+
+const CASES: usize = 1000;
+
+/// Deterministic xorshift generator, so failures can be reproduced.
+struct Rng(u64);
+
+impl Rng {
+    fn new() -> Self {
+        Self(0x9E37_79B9_7F4A_7C15)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Uniform in `[min, max)`.
+    fn range(&mut self, min: f64, max: f64) -> f64 {
+        let unit = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        min + unit * (max - min)
+    }
+
+    fn vec3(&mut self, extent: f64) -> [f64; 3] {
+        [(); 3].map(|_| self.range(-extent, extent))
+    }
+
+    fn unit_vec3(&mut self) -> [f64; 3] {
+        loop {
+            let v = self.vec3(1.0);
+            let length = dot3(v, v).sqrt();
+            if length > 0.1 {
+                return v.map(|e| e / length);
+            }
+        }
+    }
+
+    fn quat(&mut self) -> [f64; 4] {
+        let axis = self.unit_vec3();
+        let angle = self.range(-PI, PI);
+        axis_angle(axis, angle)
+    }
+}
+
+fn dot3(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn axis_angle(axis: [f64; 3], angle: f64) -> [f64; 4] {
+    let (s, c) = (angle * 0.5).sin_cos();
+    [axis[0] * s, axis[1] * s, axis[2] * s, c]
+}
+
+fn quat_mul(a: [f64; 4], b: [f64; 4]) -> [f64; 4] {
+    let [ax, ay, az, aw] = a;
+    let [bx, by, bz, bw] = b;
+    [
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+        aw * bw - ax * bx - ay * by - az * bz,
+    ]
+}
+
+fn quat_rotate(q: [f64; 4], v: [f64; 3]) -> [f64; 3] {
+    let p = quat_mul(
+        quat_mul(q, [v[0], v[1], v[2], 0.0]),
+        [-q[0], -q[1], -q[2], q[3]],
+    );
+    [p[0], p[1], p[2]]
+}
+
+/// Column major 4x4 matrices, `m[column][row]`.
+type Mat = [[f64; 4]; 4];
+
+fn mat_mul(a: &Mat, b: &Mat) -> Mat {
+    let mut out = [[0.0; 4]; 4];
+    for (column, out_column) in out.iter_mut().enumerate() {
+        for (row, value) in out_column.iter_mut().enumerate() {
+            *value = (0..4).map(|k| a[k][row] * b[column][k]).sum();
+        }
+    }
+    out
+}
+
+/// Gauss-Jordan elimination with partial pivoting, returns the inverse and determinant.
+fn mat_inverse(m: &Mat) -> (Mat, f64) {
+    // Work on rows of [m | I]
+    let mut rows = [[0.0; 8]; 4];
+    for (row, values) in rows.iter_mut().enumerate() {
+        for column in 0..4 {
+            values[column] = m[column][row];
+        }
+        values[4 + row] = 1.0;
+    }
+    let mut det = 1.0;
+    for pivot in 0..4 {
+        let best = (pivot..4)
+            .max_by(|&a, &b| rows[a][pivot].abs().total_cmp(&rows[b][pivot].abs()))
+            .unwrap();
+        if best != pivot {
+            rows.swap(best, pivot);
+            det = -det;
+        }
+        let value = rows[pivot][pivot];
+        det *= value;
+        rows[pivot].iter_mut().for_each(|e| *e /= value);
+        for row in 0..4 {
+            if row != pivot {
+                let factor = rows[row][pivot];
+                let pivot_row = rows[pivot];
+                rows[row]
+                    .iter_mut()
+                    .zip(pivot_row)
+                    .for_each(|(e, p)| *e -= factor * p);
+            }
+        }
+    }
+    let mut inverse = [[0.0; 4]; 4];
+    for (row, values) in rows.iter().enumerate() {
+        for column in 0..4 {
+            inverse[column][row] = values[4 + column];
+        }
+    }
+    (inverse, det)
+}
+
+fn random_mat(rng: &mut Rng) -> Mat {
+    [(); 4].map(|_| [(); 4].map(|_| rng.range(-2.0, 2.0)))
+}
+
+fn to_mat4(m: &Mat) -> Mat4 {
+    Mat4::from_cols(to_vec4(m[0]), to_vec4(m[1]), to_vec4(m[2]), to_vec4(m[3]))
+}
+
+fn from_mat4(m: &Mat4) -> Mat {
+    [m.x_axis, m.y_axis, m.z_axis, m.w_axis].map(|c| <[f32; 4]>::from(c).map(f64::from))
+}
+
+fn to_vec4(v: [f64; 4]) -> Vec4 {
+    Vec4::new(v[0] as f32, v[1] as f32, v[2] as f32, v[3] as f32)
+}
+
+fn to_vec3(v: [f64; 3]) -> Vec3 {
+    Vec3::new(v[0] as f32, v[1] as f32, v[2] as f32)
+}
+
+fn to_quat(q: [f64; 4]) -> Quat {
+    Quat::new(q[0] as f32, q[1] as f32, q[2] as f32, q[3] as f32)
+}
+
+fn from_quat(q: Quat) -> [f64; 4] {
+    <[f32; 4]>::from(q).map(f64::from)
+}
+
+#[track_caller]
+fn assert_close(actual: &[f64], expected: &[f64], tolerance: f64) {
+    for (a, e) in actual.iter().zip(expected) {
+        assert!(
+            (a - e).abs() <= tolerance * e.abs().max(1.0),
+            "{actual:?} != {expected:?}"
+        );
+    }
+}
+
+/// Quaternions `q` and `-q` describe the same rotation.
+#[track_caller]
+fn assert_same_rotation(actual: [f64; 4], expected: [f64; 4], tolerance: f64) {
+    let sign = if dot3(
+        [actual[0], actual[1], actual[2]],
+        [expected[0], expected[1], expected[2]],
+    ) + actual[3] * expected[3]
+        < 0.0
+    {
+        -1.0
+    } else {
+        1.0
+    };
+    assert_close(&actual.map(|e| e * sign), &expected, tolerance);
+}
+
+#[test]
+fn vec4_dot_and_lerp() {
+    let mut rng = Rng::new();
+    for _ in 0..CASES {
+        let a = [(); 4].map(|_| rng.range(-10.0, 10.0));
+        let b = [(); 4].map(|_| rng.range(-10.0, 10.0));
+        let s = rng.range(0.0, 1.0);
+        let (va, vb) = (to_vec4(a), to_vec4(b));
+
+        let dot: f64 = a.iter().zip(&b).map(|(a, b)| a * b).sum();
+        assert_close(&[va.dot(vb) as f64], &[dot], 1e-4);
+
+        let lerp: Vec<f64> = a.iter().zip(&b).map(|(a, b)| a + (b - a) * s).collect();
+        let actual = <[f32; 4]>::from(va.lerp(vb, s as f32)).map(f64::from);
+        assert_close(&actual, &lerp, 1e-5);
+    }
+}
+
+#[test]
+fn vec3_lerp() {
+    let mut rng = Rng::new();
+    for _ in 0..CASES {
+        let (a, b) = (rng.vec3(10.0), rng.vec3(10.0));
+        let s = rng.range(0.0, 1.0);
+        let expected = [0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * s);
+        let actual = <[f32; 3]>::from(to_vec3(a).lerp(to_vec3(b), s as f32)).map(f64::from);
+        assert_close(&actual, &expected, 1e-5);
+    }
+}
+
+#[test]
+fn mat4_transpose() {
+    let mut rng = Rng::new();
+    for _ in 0..CASES {
+        let m = random_mat(&mut rng);
+        let transposed = from_mat4(&to_mat4(&m).transpose());
+        for column in 0..4 {
+            for row in 0..4 {
+                assert_eq!(transposed[column][row], m[row][column] as f32 as f64);
+            }
+        }
+    }
+}
+
+#[test]
+fn mat4_determinant_and_inverse() {
+    let mut rng = Rng::new();
+    let mut checked = 0;
+    while checked < CASES {
+        let m = random_mat(&mut rng);
+        // Round to f32 first so both sides see the same matrix
+        let m = from_mat4(&to_mat4(&m));
+        let (expected_inverse, expected_det) = mat_inverse(&m);
+        // Ill-conditioned matrices amplify the f32 rounding errors
+        if expected_det.abs() < 0.5 {
+            continue;
+        }
+        checked += 1;
+
+        let matrix = to_mat4(&m);
+        assert_close(&[matrix.determinant() as f64], &[expected_det], 1e-4);
+        let inverse = from_mat4(&matrix.inverse());
+        assert_close(
+            inverse.as_flattened(),
+            expected_inverse.as_flattened(),
+            1e-3,
+        );
+    }
+}
+
+#[test]
+fn mat4_mul() {
+    let mut rng = Rng::new();
+    for _ in 0..CASES {
+        let (a, b) = (random_mat(&mut rng), random_mat(&mut rng));
+        let (a, b) = (from_mat4(&to_mat4(&a)), from_mat4(&to_mat4(&b)));
+        let actual = from_mat4(&(to_mat4(&a) * to_mat4(&b)));
+        assert_close(actual.as_flattened(), mat_mul(&a, &b).as_flattened(), 1e-5);
+    }
+}
+
+#[test]
+fn mat4_scale_rotation_translation_round_trip() {
+    let mut rng = Rng::new();
+    for _ in 0..CASES {
+        let scale = [(); 3].map(|_| rng.range(0.1, 10.0));
+        let rotation = rng.quat();
+        let translation = rng.vec3(100.0);
+        let matrix = Mat4::from_scale_rotation_translation(
+            to_vec3(scale),
+            to_quat(rotation),
+            to_vec3(translation),
+        );
+
+        let (s, r, t) = matrix.to_scale_rotation_translation();
+        assert_close(&<[f32; 3]>::from(s).map(f64::from), &scale, 1e-4);
+        assert_same_rotation(from_quat(r), rotation, 1e-3);
+        assert_close(&<[f32; 3]>::from(t).map(f64::from), &translation, 1e-6);
+
+        // The matrix transforms points like scaling, rotating and translating them in order
+        let point = rng.vec3(10.0);
+        let scaled = [0, 1, 2].map(|i| point[i] * scale[i]);
+        let rotated = quat_rotate(rotation, scaled);
+        let expected = [0, 1, 2].map(|i| rotated[i] + translation[i]);
+        let actual = <[f32; 3]>::from(matrix.transform_point3(to_vec3(point))).map(f64::from);
+        assert_close(&actual, &expected, 1e-4);
+    }
+}
+
+#[test]
+fn quat_mul_and_rotate() {
+    let mut rng = Rng::new();
+    for _ in 0..CASES {
+        let (a, b) = (rng.quat(), rng.quat());
+        let product = from_quat(to_quat(a) * to_quat(b));
+        assert_close(&product, &quat_mul(a, b), 1e-5);
+
+        let v = rng.vec3(10.0);
+        let rotated = <[f32; 3]>::from(to_quat(a) * to_vec3(v)).map(f64::from);
+        assert_close(&rotated, &quat_rotate(a, v), 1e-4);
+
+        // Matrices and quaternions agree
+        let matrix = Mat4::from_quat(to_quat(a));
+        let rotated = <[f32; 3]>::from(matrix.transform_vector3(to_vec3(v))).map(f64::from);
+        assert_close(&rotated, &quat_rotate(a, v), 1e-4);
+        assert_same_rotation(from_quat(Quat::from_mat4(&matrix)), a, 1e-4);
+
+        let inverse = from_quat(to_quat(a) * to_quat(a).inverse());
+        assert_close(&inverse, &[0.0, 0.0, 0.0, 1.0], 1e-5);
+    }
+}
+
+#[test]
+fn quat_slerp() {
+    let mut rng = Rng::new();
+    for _ in 0..CASES {
+        let (a, b) = (rng.quat(), rng.quat());
+        let s = rng.range(0.0, 1.0);
+
+        // Reference: rotate `a` towards `b` by a fraction of the relative rotation
+        let mut b_near = b;
+        if a.iter().zip(&b).map(|(a, b)| a * b).sum::<f64>() < 0.0 {
+            b_near = b.map(|e| -e);
+        }
+        let relative = quat_mul([-a[0], -a[1], -a[2], a[3]], b_near);
+        let angle = 2.0 * relative[3].clamp(-1.0, 1.0).acos();
+        let axis_length = dot3(
+            [relative[0], relative[1], relative[2]],
+            [relative[0], relative[1], relative[2]],
+        )
+        .sqrt();
+        let expected = if axis_length < 1e-9 {
+            a
+        } else {
+            let axis = [relative[0], relative[1], relative[2]].map(|e| e / axis_length);
+            quat_mul(a, axis_angle(axis, angle * s))
+        };
+
+        let actual = to_quat(a).slerp(to_quat(b), s as f32);
+        assert_same_rotation(from_quat(actual), expected, 2e-3);
+        assert!((actual.length() - 1.0).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn quat_euler_round_trip() {
+    const ORDERS: [EulerRot; 6] = [
+        EulerRot::XYZ,
+        EulerRot::XZY,
+        EulerRot::YXZ,
+        EulerRot::YZX,
+        EulerRot::ZXY,
+        EulerRot::ZYX,
+    ];
+    let mut rng = Rng::new();
+    for order in ORDERS {
+        for _ in 0..CASES {
+            // Stay away from gimbal lock, where the angles are not unique
+            let angles = [
+                rng.range(-PI * 0.99, PI * 0.99),
+                rng.range(-PI * 0.49, PI * 0.49),
+                rng.range(-PI * 0.99, PI * 0.99),
+            ];
+            let axis = |index| {
+                let mut axis = [0.0; 3];
+                axis[index] = 1.0;
+                axis
+            };
+            let names = format!("{order:?}");
+            let indices: Vec<usize> = names.bytes().map(|b| (b - b'X') as usize).collect();
+            let expected = quat_mul(
+                quat_mul(
+                    axis_angle(axis(indices[0]), angles[0]),
+                    axis_angle(axis(indices[1]), angles[1]),
+                ),
+                axis_angle(axis(indices[2]), angles[2]),
+            );
+
+            let (a, b, c) = (angles[0] as f32, angles[1] as f32, angles[2] as f32);
+            let rotation = Quat::from_euler(order, a, b, c);
+            assert_close(&from_quat(rotation), &expected, 1e-5);
+
+            let (a, b, c) = rotation.to_euler(order);
+            assert_close(&[a, b, c].map(f64::from), &angles, 1e-3);
+        }
+
+        // In gimbal lock the angles differ, but describe the same rotation
+        let rotation = Quat::from_euler(order, 0.3, std::f32::consts::FRAC_PI_2, -0.4);
+        let (a, b, c) = rotation.to_euler(order);
+        assert_same_rotation(
+            from_quat(Quat::from_euler(order, a, b, c)),
+            from_quat(rotation),
+            1e-3,
+        );
+    }
+}
+
+#[test]
+fn quat_from_rotation_arc() {
+    let mut rng = Rng::new();
+    for _ in 0..CASES {
+        let (from, to) = (rng.unit_vec3(), rng.unit_vec3());
+        let rotation = Quat::from_rotation_arc(to_vec3(from), to_vec3(to));
+        let rotated = <[f32; 3]>::from(rotation * to_vec3(from)).map(f64::from);
+        assert_close(&rotated, &to, 1e-4);
+
+        // The shortest arc rotates around the common normal
+        let angle = dot3(from, to).clamp(-1.0, 1.0).acos();
+        let axis = cross3(from, to);
+        let length = dot3(axis, axis).sqrt();
+        if length > 1e-3 {
+            let expected = axis_angle(axis.map(|e| e / length), angle);
+            assert_same_rotation(from_quat(rotation), expected, 1e-3);
+        }
+
+        let opposite = Quat::from_rotation_arc(to_vec3(from), -to_vec3(from));
+        let rotated = <[f32; 3]>::from(opposite * to_vec3(from)).map(f64::from);
+        assert_close(&rotated, &from.map(|e| -e), 1e-4);
+    }
+}
+
+/// Embeds a smaller matrix in the upper left corner of an identity matrix.
+fn embed<const N: usize>(m: [[f64; N]; N]) -> Mat {
+    let mut out = [[0.0; 4]; 4];
+    for (i, column) in out.iter_mut().enumerate() {
+        column[i] = 1.0;
+    }
+    for column in 0..N {
+        for row in 0..N {
+            out[column][row] = m[column][row];
+        }
+    }
+    out
+}
+
+#[test]
+fn mat3_determinant_and_inverse() {
+    let mut rng = Rng::new();
+    let mut checked = 0;
+    while checked < CASES {
+        let m = [(); 3].map(|_| [(); 3].map(|_| rng.range(-2.0, 2.0) as f32 as f64));
+        let (expected_inverse, expected_det) = mat_inverse(&embed(m));
+        if expected_det.abs() < 0.5 {
+            continue;
+        }
+        checked += 1;
+
+        let matrix = Mat3::from(m.map(|column| column.map(|e| e as f32)));
+        assert_close(&[matrix.determinant() as f64], &[expected_det], 1e-4);
+        let inverse = <[[f32; 3]; 3]>::from(matrix.inverse()).map(|c| c.map(f64::from));
+        assert_close(
+            embed(inverse).as_flattened(),
+            expected_inverse.as_flattened(),
+            1e-3,
+        );
+
+        let v = rng.vec3(10.0);
+        let expected = [0, 1, 2].map(|row| (0..3).map(|k| m[k][row] * v[k]).sum::<f64>());
+        let actual = <[f32; 3]>::from(matrix * to_vec3(v)).map(f64::from);
+        assert_close(&actual, &expected, 1e-4);
+    }
+}
+
+#[test]
+fn mat2_determinant_and_inverse() {
+    let mut rng = Rng::new();
+    let mut checked = 0;
+    while checked < CASES {
+        let m = [(); 2].map(|_| [(); 2].map(|_| rng.range(-2.0, 2.0) as f32 as f64));
+        let (expected_inverse, expected_det) = mat_inverse(&embed(m));
+        if expected_det.abs() < 0.5 {
+            continue;
+        }
+        checked += 1;
+
+        let matrix = Mat2::from(m.map(|column| column.map(|e| e as f32)));
+        assert_close(&[matrix.determinant() as f64], &[expected_det], 1e-5);
+        let inverse = <[[f32; 2]; 2]>::from(matrix.inverse()).map(|c| c.map(f64::from));
+        assert_close(
+            embed(inverse).as_flattened(),
+            expected_inverse.as_flattened(),
+            1e-4,
+        );
+    }
+
+    let rotated = Mat2::from_angle(std::f32::consts::FRAC_PI_2) * Vec2::X;
+    assert_close(&[rotated.x as f64, rotated.y as f64], &[0.0, 1.0], 1e-6);
+}
+
+#[test]
+fn affine3a_matches_mat4() {
+    let mut rng = Rng::new();
+    for _ in 0..CASES {
+        let scale = to_vec3([(); 3].map(|_| rng.range(0.1, 10.0)));
+        let rotation = to_quat(rng.quat());
+        let translation = to_vec3(rng.vec3(100.0));
+        let affine = Affine3A::from_scale_rotation_translation(scale, rotation, translation);
+        let matrix = Mat4::from_scale_rotation_translation(scale, rotation, translation);
+        assert_close(
+            from_mat4(&Mat4::from(affine)).as_flattened(),
+            from_mat4(&matrix).as_flattened(),
+            1e-6,
+        );
+
+        let (expected_inverse, _) = mat_inverse(&from_mat4(&matrix));
+        assert_close(
+            from_mat4(&Mat4::from(affine.inverse())).as_flattened(),
+            expected_inverse.as_flattened(),
+            1e-3,
+        );
+
+        let point = to_vec3(rng.vec3(10.0));
+        let expected = <[f32; 3]>::from(matrix.transform_point3(point)).map(f64::from);
+        let actual = <[f32; 3]>::from(affine.transform_point3(point)).map(f64::from);
+        assert_close(&actual, &expected, 1e-4);
+        let expected = <[f32; 3]>::from(matrix.transform_vector3(point)).map(f64::from);
+        let actual = <[f32; 3]>::from(affine.transform_vector3(point)).map(f64::from);
+        assert_close(&actual, &expected, 1e-4);
+
+        let other = Affine3A::from_quat(to_quat(rng.quat()));
+        assert_close(
+            from_mat4(&Mat4::from(affine * other)).as_flattened(),
+            from_mat4(&(matrix * Mat4::from(other))).as_flattened(),
+            1e-4,
+        );
+        assert!(Affine3A::from_mat4(&matrix) == affine);
+    }
+}
+
+fn to_dmat4(m: &Mat) -> DMat4 {
+    let column = |c: [f64; 4]| DVec4::from(c);
+    DMat4::from_cols(column(m[0]), column(m[1]), column(m[2]), column(m[3]))
+}
+
+fn from_dmat4(m: &DMat4) -> Mat {
+    [m.x_axis, m.y_axis, m.z_axis, m.w_axis].map(<[f64; 4]>::from)
+}
+
+#[test]
+fn dmat4_matches_reference() {
+    let mut rng = Rng::new();
+    let mut checked = 0;
+    while checked < CASES {
+        let (a, b) = (random_mat(&mut rng), random_mat(&mut rng));
+        let (expected_inverse, expected_det) = mat_inverse(&a);
+        if expected_det.abs() < 0.01 {
+            continue;
+        }
+        checked += 1;
+
+        let matrix = to_dmat4(&a);
+        assert_close(&[matrix.determinant()], &[expected_det], 1e-10);
+        assert_close(
+            from_dmat4(&matrix.inverse()).as_flattened(),
+            expected_inverse.as_flattened(),
+            1e-8,
+        );
+        assert_close(
+            from_dmat4(&(matrix * to_dmat4(&b))).as_flattened(),
+            mat_mul(&a, &b).as_flattened(),
+            1e-12,
+        );
+        assert_eq!(from_dmat4(&matrix.transpose())[1][2], a[2][1]);
+    }
+}
+
+#[test]
+fn dquat_matches_reference() {
+    let mut rng = Rng::new();
+    for _ in 0..CASES {
+        let (a, b) = (rng.quat(), rng.quat());
+        let (qa, qb) = (DQuat::from_array(a), DQuat::from_array(b));
+        assert_close(&<[f64; 4]>::from(qa * qb), &quat_mul(a, b), 1e-12);
+
+        let v = rng.vec3(1000.0);
+        let rotated = <[f64; 3]>::from(qa * DVec3::from(v));
+        assert_close(&rotated, &quat_rotate(a, v), 1e-10);
+
+        let angles = (
+            rng.range(-3.0, 3.0),
+            rng.range(-1.5, 1.5),
+            rng.range(-3.0, 3.0),
+        );
+        let (x, y, z) =
+            DQuat::from_euler(EulerRot::ZYX, angles.0, angles.1, angles.2).to_euler(EulerRot::ZYX);
+        assert_close(&[x, y, z], &[angles.0, angles.1, angles.2], 1e-9);
+
+        let scale = DVec3::new(2.0, 3.0, 4.0);
+        let translation = DVec3::from(rng.vec3(1e6));
+        let matrix = DMat4::from_scale_rotation_translation(scale, qa, translation);
+        let (s, r, t) = matrix.to_scale_rotation_translation();
+        assert_close(&<[f64; 3]>::from(s), &[2.0, 3.0, 4.0], 1e-12);
+        assert_same_rotation(<[f64; 4]>::from(r), a, 1e-12);
+        assert_close(&<[f64; 3]>::from(t), &<[f64; 3]>::from(translation), 1e-15);
+    }
+}
+
+#[test]
+fn conversions_between_families() {
+    let mut rng = Rng::new();
+    for _ in 0..CASES {
+        let v = rng.vec3(1000.0);
+        let single = to_vec3(v);
+        let double = single.as_dvec3();
+        assert!(double.as_vec3() == single);
+        assert_eq!(
+            <[i32; 3]>::from(single.as_ivec3()),
+            <[f32; 3]>::from(single).map(|e| e as i32)
+        );
+
+        let matrix = to_mat4(&random_mat(&mut rng));
+        assert_eq!(from_mat4(&matrix), from_mat4(&matrix.as_dmat4().as_mat4()));
+        let rotation = to_quat(rng.quat());
+        assert_eq!(
+            <[f32; 4]>::from(rotation),
+            <[f32; 4]>::from(rotation.as_dquat().as_quat())
+        );
+    }
+
+    let chunk = IVec3::new(-3, 4, 7);
+    assert!(chunk * 16 + IVec3::ONE == IVec3::new(-47, 65, 113));
+    assert!(-chunk == IVec3::new(3, -4, -7));
+    assert!(chunk.abs().as_uvec3().as_ivec3() == IVec3::new(3, 4, 7));
+    assert!(UVec2::new(7, 9) % 4 == UVec2::new(3, 1));
+    assert!(UVec2::new(3, 5).as_vec2() == Vec2::new(3.0, 5.0));
+}
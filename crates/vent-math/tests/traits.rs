@@ -0,0 +1,143 @@
+use vent_math::{
+    geometry::Aabb,
+    scalar::{affine3a::Affine3A, mat3::Mat3, mat4::Mat4, quat::Quat},
+    vec::{i32::ivec3::IVec3, u32::uvec2::UVec2, vec2::Vec2, vec3::Vec3, vec4::Vec4},
+};
+
+#[test]
+fn debug() {
+    assert_eq!(
+        format!("{:?}", Vec3::new(1.0, 2.0, 3.0)),
+        "Vec3 { x: 1.0, y: 2.0, z: 3.0 }"
+    );
+    assert_eq!(
+        format!("{:?}", Vec4::new(1.0, 2.0, 3.0, 4.0)),
+        "Vec4 { x: 1.0, y: 2.0, z: 3.0, w: 4.0 }"
+    );
+    assert_eq!(
+        format!("{:?}", Quat::IDENTITY),
+        "Quat { x: 0.0, y: 0.0, z: 0.0, w: 1.0 }"
+    );
+    assert!(format!("{:?}", Mat4::IDENTITY).starts_with("Mat4 { x_axis: Vec4 { x: 1.0"));
+}
+
+#[test]
+fn display() {
+    assert_eq!(Vec2::new(1.0, -2.5).to_string(), "[1, -2.5]");
+    assert_eq!(IVec3::new(1, -2, 3).to_string(), "[1, -2, 3]");
+    assert_eq!(
+        format!("{:.2}", Vec3::new(1.0, 2.0, 1.0 / 3.0)),
+        "[1.00, 2.00, 0.33]"
+    );
+    assert_eq!(
+        Mat3::IDENTITY.to_string(),
+        "[[1, 0, 0], [0, 1, 0], [0, 0, 1]]"
+    );
+    assert_eq!(
+        format!("{:.1}", Affine3A::from_translation(Vec3::X)),
+        "[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]]"
+    );
+    assert_eq!(Quat::IDENTITY.to_string(), "[0, 0, 0, 1]");
+}
+
+#[test]
+fn default() {
+    assert!(Vec3::default() == Vec3::ZERO);
+    assert!(Vec4::default().abs_diff_eq(Vec4::ZERO, 0.0));
+    assert!(Mat4::default().abs_diff_eq(Mat4::IDENTITY, 0.0));
+    assert!(Quat::default().abs_diff_eq(Quat::IDENTITY, 0.0));
+    assert!(Affine3A::default() == Affine3A::IDENTITY);
+    assert_eq!(IVec3::default(), IVec3::ZERO);
+}
+
+#[test]
+fn abs_diff_eq() {
+    let a = Vec3::new(1.0, 2.0, 3.0);
+    assert!(a.abs_diff_eq(a + Vec3::splat(1e-4), 1e-3));
+    assert!(!a.abs_diff_eq(a + Vec3::new(0.0, 0.0, 1e-2), 1e-3));
+
+    let rotation = Quat::from_rotation_y(1.0);
+    assert!((rotation * rotation.inverse()).abs_diff_eq(Quat::IDENTITY, 1e-6));
+    assert!(!rotation.abs_diff_eq(-rotation, 1e-6));
+
+    let m = Mat4::from_rotation_translation(rotation, Vec3::new(1.0, 2.0, 3.0));
+    assert!((m * m.inverse()).abs_diff_eq(Mat4::IDENTITY, 1e-5));
+    assert!(!m.abs_diff_eq(Mat4::IDENTITY, 1e-5));
+
+    let aabb = Aabb::new(Vec3::ZERO, Vec3::ONE);
+    assert!(aabb.abs_diff_eq(Aabb::new(Vec3::splat(1e-6), Vec3::ONE), 1e-5));
+
+    // Integer vectors compare exactly with a tolerance of 0, without overflowing
+    let i = IVec3::new(i32::MIN, 0, 5);
+    assert!(i.abs_diff_eq(i, 0));
+    assert!(i.abs_diff_eq(IVec3::new(i32::MIN + 1, -1, 5), 1));
+    assert!(!i.abs_diff_eq(IVec3::new(i32::MAX, 0, 5), u32::MAX - 1));
+    let u = UVec2::new(0, u32::MAX);
+    assert!(u.abs_diff_eq(UVec2::new(2, u32::MAX - 2), 2));
+    assert!(!u.abs_diff_eq(UVec2::new(3, u32::MAX), 2));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use vent_math::{geometry::Sphere, scalar::euler::EulerRot, vec::u32::uvec2::UVec2};
+
+    assert_eq!(
+        serde_json::to_string(&Vec3::new(1.0, 2.0, 3.0)).unwrap(),
+        "[1.0,2.0,3.0]"
+    );
+    assert_eq!(
+        serde_json::to_string(&Mat3::IDENTITY).unwrap(),
+        "[[1.0,0.0,0.0],[0.0,1.0,0.0],[0.0,0.0,1.0]]"
+    );
+
+    let v: Vec4 = serde_json::from_str("[1.0,2.0,3.0,4.0]").unwrap();
+    assert!(v.abs_diff_eq(Vec4::new(1.0, 2.0, 3.0, 4.0), 0.0));
+    assert!(serde_json::from_str::<Vec3>("[1.0,2.0]").is_err());
+    assert_eq!(
+        serde_json::from_str::<UVec2>("[4,2]").unwrap(),
+        UVec2::new(4, 2)
+    );
+
+    let m = Mat4::from_rotation_translation(Quat::from_rotation_x(0.5), Vec3::ONE);
+    let json = serde_json::to_string(&m).unwrap();
+    assert!(serde_json::from_str::<Mat4>(&json)
+        .unwrap()
+        .abs_diff_eq(m, 0.0));
+
+    let q = Quat::from_euler(EulerRot::YXZ, 0.1, 0.2, 0.3);
+    let json = serde_json::to_string(&q).unwrap();
+    assert!(serde_json::from_str::<Quat>(&json)
+        .unwrap()
+        .abs_diff_eq(q, 0.0));
+
+    let affine = Affine3A::from_scale_rotation_translation(Vec3::splat(2.0), q, Vec3::Y);
+    let json = serde_json::to_string(&affine).unwrap();
+    assert!(serde_json::from_str::<Affine3A>(&json).unwrap() == affine);
+
+    let sphere = Sphere::new(Vec3::X, 2.0);
+    assert_eq!(
+        serde_json::to_string(&sphere).unwrap(),
+        r#"{"center":[1.0,0.0,0.0],"radius":2.0}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<EulerRot>(r#""ZYX""#).unwrap(),
+        EulerRot::ZYX
+    );
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn bytemuck() {
+    let m = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    let floats: &[f32] = bytemuck::cast_slice(bytemuck::bytes_of(&m));
+    assert_eq!(floats.len(), 16);
+    assert_eq!(&floats[12..], &[1.0, 2.0, 3.0, 1.0]);
+
+    let vertices = [Vec3::X, Vec3::Y];
+    let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+    assert_eq!(bytes.len(), 24);
+    assert!(bytemuck::pod_read_unaligned::<Vec3>(&bytes[12..]) == Vec3::Y);
+
+    assert!(<Quat as bytemuck::Zeroable>::zeroed().abs_diff_eq(Quat::from_array([0.0; 4]), 0.0));
+}
@@ -0,0 +1,90 @@
+//! Packs an asset directory into a single archive, which is read by
+//! `vent_assets::io::archive::ArchiveAssetIo`.
+
+use std::{
+    fs::{self, File},
+    io::BufWriter,
+    path::PathBuf,
+    process::ExitCode,
+};
+
+use vent_assets::io::archive::{ArchiveWriter, Compression};
+
+const USAGE: &str = "Usage: vent-pack <directory> <output> [--compression none|zstd|lz4]";
+
+struct Args {
+    input: PathBuf,
+    output: PathBuf,
+    compression: Compression,
+}
+
+fn parse_compression(name: &str) -> Result<Compression, String> {
+    match name {
+        "none" => Ok(Compression::None),
+        "zstd" => Ok(Compression::Zstd),
+        "lz4" => Ok(Compression::Lz4),
+        _ => Err(format!("Unknown compression {}", name)),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut paths = Vec::new();
+    let mut compression = Compression::Zstd;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--compression" => {
+                let name = args.next().ok_or("Missing value for --compression")?;
+                compression = parse_compression(&name)?;
+            }
+            _ if arg.starts_with("--") => return Err(format!("Unknown option {}", arg)),
+            _ => paths.push(PathBuf::from(arg)),
+        }
+    }
+    let [input, output] = <[PathBuf; 2]>::try_from(paths)
+        .map_err(|_| "Expected an input directory and an output file".to_owned())?;
+    Ok(Args {
+        input,
+        output,
+        compression,
+    })
+}
+
+fn pack(args: &Args) -> std::io::Result<usize> {
+    let mut writer = ArchiveWriter::new();
+    writer.set_compression(args.compression);
+    writer.add_directory(&args.input)?;
+    writer.write(BufWriter::new(File::create(&args.output)?))?;
+    Ok(writer.len())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+    let args = match parse_args(args.into_iter()) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match pack(&args) {
+        Ok(count) => {
+            let size = fs::metadata(&args.output).map_or(0, |metadata| metadata.len());
+            println!(
+                "Packed {} files into {} ({} bytes)",
+                count,
+                args.output.display(),
+                size
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("Failed to pack {}: {}", args.input.display(), err);
+            ExitCode::FAILURE
+        }
+    }
+}
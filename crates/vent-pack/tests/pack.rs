@@ -0,0 +1,99 @@
+use std::{
+    fs,
+    ops::Deref,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use vent_assets::{io::archive::ArchiveAssetIo, AssetIo};
+
+/// A directory below the system temp directory for a single test, removed again on drop.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(test: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("vent-pack-{}-{}", test, std::process::id()));
+        // Leftover of an aborted run
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        Self(dir)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+fn vent_pack(args: &[&Path]) -> bool {
+    Command::new(env!("CARGO_BIN_EXE_vent-pack"))
+        .args(args)
+        .output()
+        .unwrap()
+        .status
+        .success()
+}
+
+#[test]
+fn round_trip() {
+    let dir = TempDir::new("round_trip");
+    let assets = dir.join("assets");
+    let files = [
+        ("shaders/3D/shader.vert.spv", vec![3; 4000]),
+        ("textures/skybox/right.png", (0..=255).collect()),
+        ("models/cube.obj", b"v 0 0 0\n".repeat(100)),
+        ("empty", Vec::new()),
+    ];
+    for (path, data) in &files {
+        let path = assets.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, data).unwrap();
+    }
+
+    for compression in ["none", "zstd", "lz4"] {
+        let archive = dir.join(format!("{}.pack", compression));
+        assert!(vent_pack(&[
+            &assets,
+            &archive,
+            Path::new("--compression"),
+            Path::new(compression),
+        ]));
+
+        let io = ArchiveAssetIo::open(&archive).unwrap();
+        assert_eq!(io.len(), files.len());
+        for (path, data) in &files {
+            assert_eq!(&io.read(Path::new(path)).unwrap(), data);
+        }
+    }
+    // Zstd is the default
+    let archive = dir.join("default.pack");
+    assert!(vent_pack(&[&assets, &archive]));
+    assert_eq!(
+        fs::read(&archive).unwrap(),
+        fs::read(dir.join("zstd.pack")).unwrap()
+    );
+}
+
+#[test]
+fn invalid_arguments() {
+    let dir = TempDir::new("invalid_arguments");
+    let archive = dir.join("assets.pack");
+    assert!(!vent_pack(&[&dir]));
+    assert!(!vent_pack(&[&dir.join("missing"), &archive]));
+    assert!(!vent_pack(&[
+        &dir,
+        &archive,
+        Path::new("--compression"),
+        Path::new("brotli"),
+    ]));
+    assert!(!vent_pack(&[&dir, &archive, Path::new("--level")]));
+}
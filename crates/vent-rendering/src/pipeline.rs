@@ -1,4 +1,4 @@
-use std::{fs::File, path::Path};
+use std::{fmt, fs::File, io, path::Path};
 
 use ash::{
     util::read_spv,
@@ -7,6 +7,38 @@ use ash::{
 
 use crate::instance::VulkanInstance;
 
+/// Errors returned when creating a pipeline from shader files fails.
+#[derive(Debug)]
+pub enum PipelineError {
+    /// A shader file could not be read or is not valid SPIR-V.
+    Io(io::Error),
+    /// Vulkan rejected the shader modules or the pipeline.
+    Vulkan(vk::Result),
+}
+
+impl fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PipelineError::Io(err) => write!(f, "Failed to read shader: {}", err),
+            PipelineError::Vulkan(err) => write!(f, "Failed to create pipeline: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for PipelineError {}
+
+impl From<io::Error> for PipelineError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<vk::Result> for PipelineError {
+    fn from(err: vk::Result) -> Self {
+        Self::Vulkan(err)
+    }
+}
+
 pub struct VulkanPipeline {
     pub pipeline: vk::Pipeline,
     pub pipeline_layout: vk::PipelineLayout,
@@ -35,6 +67,36 @@ impl VulkanPipeline {
         surface_resolution: vk::Extent2D,
         push_constant_ranges: &[PushConstantRange],
         desc_layout_bindings: &[vk::DescriptorSetLayoutBinding],
+    ) -> Self {
+        let vertex_code =
+            read_spv(&mut File::open(vertex_file).expect("Failed to open Vertex File")).unwrap();
+        let fragment_code =
+            read_spv(&mut File::open(fragment_file).expect("Failed to open Fragment File"))
+                .unwrap();
+        Self::create_simple_pipeline_from_code(
+            instance,
+            &vertex_code,
+            &fragment_code,
+            binding_desc,
+            attrib_desc,
+            surface_resolution,
+            push_constant_ranges,
+            desc_layout_bindings,
+        )
+    }
+
+    /// Same as [`VulkanPipeline::create_simple_pipeline`] but with already loaded SPIR-V,
+    /// e.g. shaders loaded through an asset server.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_simple_pipeline_from_code(
+        instance: &VulkanInstance,
+        vertex_code: &[u32],
+        fragment_code: &[u32],
+        binding_desc: &[vk::VertexInputBindingDescription],
+        attrib_desc: &[vk::VertexInputAttributeDescription],
+        surface_resolution: vk::Extent2D,
+        push_constant_ranges: &[PushConstantRange],
+        desc_layout_bindings: &[vk::DescriptorSetLayoutBinding],
     ) -> Self {
         let info = vk::DescriptorSetLayoutCreateInfo::default().bindings(desc_layout_bindings);
 
@@ -44,27 +106,105 @@ impl VulkanPipeline {
         let pipeline_layout =
             instance.create_pipeline_layout(push_constant_ranges, &[descriptor_set_layout]);
 
-        let vertex_code =
-            read_spv(&mut File::open(vertex_file).expect("Failed to open Vertex File")).unwrap();
-        let vertex_module_info = vk::ShaderModuleCreateInfo::default().code(&vertex_code);
-        let fragment_code =
-            read_spv(&mut File::open(fragment_file).expect("Failed to open Fragment File"))
-                .unwrap();
-        let fragment_module_info = vk::ShaderModuleCreateInfo::default().code(&fragment_code);
+        let (vertex_module, fragment_module) =
+            Self::create_shader_modules(&instance.device, vertex_code, fragment_code)
+                .expect("Failed to load shaders");
+        let pipeline = Self::create_graphics_pipeline(
+            instance,
+            vertex_module,
+            fragment_module,
+            binding_desc,
+            attrib_desc,
+            surface_resolution,
+            pipeline_layout,
+        )
+        .expect("Unable to create graphics pipeline");
 
-        let vertex_module = unsafe {
-            instance
-                .device
-                .create_shader_module(&vertex_module_info, None)
+        Self {
+            pipeline,
+            descriptor_set_layout,
+            pipeline_layout,
+            vertex_module,
+            fragment_module,
         }
-        .unwrap();
-        let fragment_module = unsafe {
-            instance
-                .device
-                .create_shader_module(&fragment_module_info, None)
+    }
+
+    /// Creates the pipeline again from changed shaders, keeping its layouts and descriptor set
+    /// layout.
+    ///
+    /// On failure the current pipeline is kept. On success the previous pipeline is destroyed,
+    /// so it must no longer be in use by the GPU.
+    pub fn reload_shaders(
+        &mut self,
+        instance: &VulkanInstance,
+        vertex_code: &[u32],
+        fragment_code: &[u32],
+        binding_desc: &[vk::VertexInputBindingDescription],
+        attrib_desc: &[vk::VertexInputAttributeDescription],
+        surface_resolution: vk::Extent2D,
+    ) -> Result<(), PipelineError> {
+        let device = &instance.device;
+        let (vertex_module, fragment_module) =
+            Self::create_shader_modules(device, vertex_code, fragment_code)?;
+        let pipeline = match Self::create_graphics_pipeline(
+            instance,
+            vertex_module,
+            fragment_module,
+            binding_desc,
+            attrib_desc,
+            surface_resolution,
+            self.pipeline_layout,
+        ) {
+            Ok(pipeline) => pipeline,
+            Err(err) => {
+                unsafe {
+                    device.destroy_shader_module(vertex_module, None);
+                    device.destroy_shader_module(fragment_module, None);
+                }
+                return Err(err.into());
+            }
+        };
+
+        unsafe {
+            device.destroy_shader_module(self.vertex_module, None);
+            device.destroy_shader_module(self.fragment_module, None);
+            device.destroy_pipeline(self.pipeline, None);
         }
-        .unwrap();
+        self.pipeline = pipeline;
+        self.vertex_module = vertex_module;
+        self.fragment_module = fragment_module;
+        Ok(())
+    }
 
+    fn create_shader_modules(
+        device: &ash::Device,
+        vertex_code: &[u32],
+        fragment_code: &[u32],
+    ) -> Result<(vk::ShaderModule, vk::ShaderModule), PipelineError> {
+        let vertex_module_info = vk::ShaderModuleCreateInfo::default().code(vertex_code);
+        let fragment_module_info = vk::ShaderModuleCreateInfo::default().code(fragment_code);
+
+        let vertex_module = unsafe { device.create_shader_module(&vertex_module_info, None) }?;
+        let fragment_module =
+            match unsafe { device.create_shader_module(&fragment_module_info, None) } {
+                Ok(module) => module,
+                Err(err) => {
+                    unsafe { device.destroy_shader_module(vertex_module, None) };
+                    return Err(err.into());
+                }
+            };
+        Ok((vertex_module, fragment_module))
+    }
+
+    fn create_graphics_pipeline(
+        instance: &VulkanInstance,
+        vertex_module: vk::ShaderModule,
+        fragment_module: vk::ShaderModule,
+        binding_desc: &[vk::VertexInputBindingDescription],
+        attrib_desc: &[vk::VertexInputAttributeDescription],
+        surface_resolution: vk::Extent2D,
+        pipeline_layout: vk::PipelineLayout,
+    ) -> Result<vk::Pipeline, vk::Result> {
         let shader_entry_name = unsafe { c"main" };
         let shader_stage_create_info = [
             vk::PipelineShaderStageCreateInfo {
@@ -150,15 +290,8 @@ impl VulkanPipeline {
                 None,
             )
         }
-        .expect("Unable to create graphics pipeline");
-
-        Self {
-            pipeline: graphics_pipelines[0],
-            descriptor_set_layout,
-            pipeline_layout,
-            vertex_module,
-            fragment_module,
-        }
+        .map_err(|(_, err)| err)?;
+        Ok(graphics_pipelines[0])
     }
 
     pub fn destroy(&mut self, device: &ash::Device) {
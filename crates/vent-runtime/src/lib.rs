@@ -1,7 +1,19 @@
 use project::VentApplicationProject;
-use render::{camera::camera_controller3d::CameraController3D, DefaultRuntimeRenderer};
+use render::{
+    camera::{
+        camera_controller3d::{update_camera_3d, CameraController3D},
+        from_dimension, ActiveCamera,
+    },
+    gui::debug_gui::RenderData,
+    DefaultRuntimeRenderer,
+};
 
-use util::input_handler::InputHandler;
+use util::{input_handler::InputHandler, time::DeltaTime};
+use vent_ecs::{
+    schedule::{IntoSystemConfig, Schedule, Stage},
+    transform::propagate_transforms,
+    world::World,
+};
 use vent_window::{Window, WindowEvent};
 
 pub mod project;
@@ -11,52 +23,99 @@ pub mod util;
 #[derive(Default)]
 pub struct VentApplication {
     project: VentApplicationProject,
+    world: World,
+    schedule: Schedule,
 }
 
 impl VentApplication {
     pub fn new(project: VentApplicationProject) -> Self {
-        Self { project }
+        Self {
+            project,
+            world: World::new(),
+            schedule: Schedule::new(),
+        }
+    }
+
+    /// Adds a gameplay system, all systems run once per frame before rendering.
+    pub fn add_system<M>(&mut self, stage: Stage, system: impl IntoSystemConfig<M>) -> &mut Self {
+        self.schedule.add_system(stage, system);
+        self
     }
 
     pub fn start(self) {
         let project = self.project;
+        let mut world = self.world;
+        let mut schedule = self.schedule;
         let app_window = Window::new(project.window_settings.clone());
 
+        let window_size = app_window.size();
+        let mut camera = from_dimension(
+            window_size.0 as f32 / window_size.1 as f32,
+            &project.render_settings.dimension,
+        );
         // TODO
-        let mut renderer = DefaultRuntimeRenderer::new(&project, &app_window);
-
-        let mut input_handler = InputHandler::default();
+        let mut renderer = DefaultRuntimeRenderer::new(&project, &app_window, camera.as_mut());
 
-        let mut controller = CameraController3D::new(5.0, 1.0);
-        let mut delta_time = 0.0;
+        world.insert_resource(ActiveCamera(camera));
+        world.insert_resource(InputHandler::default());
+        world.insert_resource(CameraController3D::new(5.0, 1.0));
+        world.insert_resource(DeltaTime::default());
+        world.insert_resource(RenderData::default());
+        schedule.add_system(Stage::PreUpdate, update_camera_3d);
+        schedule.add_system(Stage::PostUpdate, propagate_transforms);
 
         // TODO, Handle scale factor change
         app_window.poll(move |event| {
-            controller.process_keyboard(
-                renderer.camera.downcast_mut().expect("TODO"),
-                &input_handler,
-                delta_time,
-            );
             renderer.progress_event(&event);
             match event {
                 WindowEvent::Close => {} // Closes automaticly
-                WindowEvent::Key { key, state } => input_handler.set_key(key, state),
+                WindowEvent::Key { key, state } => world
+                    .resource_mut::<InputHandler>()
+                    .expect("Missing InputHandler")
+                    .set_key(key, state),
                 WindowEvent::MouseButton { button, state } => {
-                    controller.process_mouse_input(&button, &state);
+                    world
+                        .resource_mut::<CameraController3D>()
+                        .expect("Missing CameraController3D")
+                        .process_mouse_input(&button, &state);
                 }
                 WindowEvent::Resize {
                     new_width,
                     new_height,
                 } => {
-                    renderer.resize((new_width, new_height));
+                    let mut camera = world
+                        .resource_mut::<ActiveCamera>()
+                        .expect("Missing ActiveCamera");
+                    renderer.resize((new_width, new_height), camera.0.as_mut());
+                }
+                WindowEvent::Draw => {
+                    if let Err(err) = schedule.run(&mut world) {
+                        log::error!("Failed to run systems: {}", err);
+                    }
+                    let delta_time = {
+                        let mut camera = world
+                            .resource_mut::<ActiveCamera>()
+                            .expect("Missing ActiveCamera");
+                        renderer.render(camera.0.as_mut())
+                    };
+                    world.insert_resource(DeltaTime(delta_time));
+                    world.insert_resource(renderer.render_data());
+                    // Changes made this frame have been rendered
+                    world.clear_trackers();
+                    world.update_events();
+                }
+                WindowEvent::MouseMotion { x, y } => {
+                    let delta_time = world.resource::<DeltaTime>().expect("Missing DeltaTime").0;
+                    let mut camera = world
+                        .resource_mut::<ActiveCamera>()
+                        .expect("Missing ActiveCamera");
+                    if let Some(camera) = camera.0.downcast_mut() {
+                        world
+                            .resource_mut::<CameraController3D>()
+                            .expect("Missing CameraController3D")
+                            .process_mouse_movement(camera, x, y, delta_time);
+                    }
                 }
-                WindowEvent::Draw => delta_time = renderer.render(),
-                WindowEvent::MouseMotion { x, y } => controller.process_mouse_movement(
-                    renderer.camera.downcast_mut().expect("TODO"),
-                    x,
-                    y,
-                    delta_time,
-                ), // Default,
             }
         });
     }
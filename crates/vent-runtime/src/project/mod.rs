@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::fs::{File, OpenOptions};
+use vent_assets::AssetIoConfig;
 use vent_window::WindowAttribs;
 
 use crate::{render::Dimension, util::version::Version};
@@ -15,6 +16,9 @@ pub struct VentApplicationProject {
     pub window_settings: WindowAttribs,
     // Inital Render settings, can be changed later
     pub render_settings: RenderSettings,
+    // Where assets are read from, a directory during development and an archive when shipped
+    #[serde(default)]
+    pub asset_settings: AssetIoConfig,
 }
 
 impl Default for VentApplicationProject {
@@ -27,6 +31,7 @@ impl Default for VentApplicationProject {
                 dimension: Dimension::D3,
                 vsync: false,
             },
+            asset_settings: AssetIoConfig::default(),
         }
     }
 }
@@ -1,9 +1,10 @@
+use vent_ecs::resource::{Res, ResMut, Resource};
 use vent_math::vec::{vec2::Vec2, vec3::Vec3};
 use vent_window::keyboard::Key;
 
-use crate::util::input_handler::InputHandler;
+use crate::util::{input_handler::InputHandler, time::DeltaTime};
 
-use super::Camera3D;
+use super::{ActiveCamera, Camera3D};
 
 pub struct CameraController3D {
     speed: f32,
@@ -15,6 +16,20 @@ pub struct CameraController3D {
     mouse_left_down: bool,
 }
 
+impl Resource for CameraController3D {}
+
+/// Moves the active camera with the keyboard, does nothing if it is not a [`Camera3D`].
+pub fn update_camera_3d(
+    controller: Res<CameraController3D>,
+    mut camera: ResMut<ActiveCamera>,
+    input_handler: Res<InputHandler>,
+    delta_time: Res<DeltaTime>,
+) {
+    if let Some(camera) = camera.0.downcast_mut() {
+        controller.process_keyboard(camera, &input_handler, delta_time.0);
+    }
+}
+
 impl CameraController3D {
     #[inline]
     #[must_use]
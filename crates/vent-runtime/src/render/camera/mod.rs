@@ -1,5 +1,6 @@
 use ash::vk;
 use downcast_rs::{impl_downcast, Downcast};
+use vent_ecs::resource::Resource;
 use vent_math::{
     scalar::{mat4::Mat4, quat::Quat},
     vec::{vec2::Vec2, vec3::Vec3},
@@ -10,7 +11,7 @@ use super::{d3::Camera3DData, Dimension};
 
 pub mod camera_controller3d;
 
-pub trait Camera: Downcast {
+pub trait Camera: Downcast + Send + Sync {
     fn new(aspect_ratio: f32) -> Self
     where
         Self: Sized;
@@ -19,6 +20,11 @@ pub trait Camera: Downcast {
 }
 impl_downcast!(Camera);
 
+/// The camera the renderer draws with, stored as a world resource.
+pub struct ActiveCamera(pub Box<dyn Camera>);
+
+impl Resource for ActiveCamera {}
+
 pub fn from_dimension(aspect_ratio: f32, dimension: &Dimension) -> Box<dyn Camera> {
     match dimension {
         Dimension::D2 => Box::new(Camera2D::new(aspect_ratio)),
@@ -11,6 +11,7 @@ impl Renderer for Renderer2D {
     fn init(
         _instance: &mut vent_rendering::instance::VulkanInstance,
         _camera: &mut dyn Camera,
+        _assets: vent_assets::AssetServer,
     ) -> Self
     where
         Self: Sized,
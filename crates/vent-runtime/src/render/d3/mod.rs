@@ -1,11 +1,16 @@
-use std::mem::size_of;
+use std::{
+    collections::HashMap,
+    mem::{self, size_of},
+    path::{Path, PathBuf},
+};
 
 use ash::vk;
-use pollster::FutureExt;
 
 use skybox_renderer::SkyBoxRenderer;
-use vent_assets::io::file::FileAsset;
-use vent_ecs::world::World;
+use vent_assets::{
+    image::Image, model::ModelData, shader::Shader, AssetServer, Handle, HandleId, Model3D,
+};
+use vent_ecs::{entity::Entity, world::World};
 use vent_math::{
     scalar::mat4::Mat4,
     vec::{vec3::Vec3, vec4::Vec4},
@@ -56,108 +61,66 @@ pub struct Renderer3D {
     pipeline_layout: vk::PipelineLayout,
     descriptor_set_layout: vk::DescriptorSetLayout,
 
-    material_ubos: Vec<VulkanBuffer>,
+    material_ubos: HashMap<Entity, Vec<VulkanBuffer>>,
     light_ubos: Vec<VulkanBuffer>,
-}
-
-impl Renderer for Renderer3D {
-    fn init(instance: &mut VulkanInstance, _camera: &mut dyn Camera) -> Self
-    where
-        Self: Sized,
-    {
-        //  let _camera: &Camera3D = camera.downcast_ref().unwrap();
-
-        let skybox_renderer = SkyBoxRenderer::new(
-            instance,
-            SkyBoxImages {
-                right: FileAsset::new("assets/textures/skybox/right.jpg")
-                    .root_path()
-                    .clone(),
-                left: FileAsset::new("assets/textures/skybox/left.jpg")
-                    .root_path()
-                    .clone(),
-                top: FileAsset::new("assets/textures/skybox/top.jpg")
-                    .root_path()
-                    .clone(),
-                bottom: FileAsset::new("assets/textures/skybox/bottom.jpg")
-                    .root_path()
-                    .clone(),
-                front: FileAsset::new("assets/textures/skybox/front.jpg")
-                    .root_path()
-                    .clone(),
-                back: FileAsset::new("assets/textures/skybox/back.jpg")
-                    .root_path()
-                    .clone(),
-            },
-        );
-
-        let push_constant_range = vk::PushConstantRange::default()
-            .size(size_of::<Camera3DData>() as u32)
-            .stage_flags(vk::ShaderStageFlags::VERTEX);
-
-        let desc_layout_bindings = [
-            // Fragment
-            vk::DescriptorSetLayoutBinding {
-                binding: 0,
-                descriptor_type: vk::DescriptorType::COMBINED_IMAGE_SAMPLER,
-                descriptor_count: 1,
-                stage_flags: vk::ShaderStageFlags::FRAGMENT,
-                ..Default::default()
-            },
-            vk::DescriptorSetLayoutBinding {
-                binding: 1,
-                descriptor_type: vk::DescriptorType::UNIFORM_BUFFER,
-                descriptor_count: 1,
-                stage_flags: vk::ShaderStageFlags::FRAGMENT,
-                ..Default::default()
-            },
-            // vk::DescriptorSetLayoutBinding {
-            //     binding: 2,
-            //     descriptor_type: vk::DescriptorType::UNIFORM_BUFFER,
-            //     descriptor_count: 1,
-            //     stage_flags: vk::ShaderStageFlags::FRAGMENT,
-            //     ..Default::default()
-            // },
-        ];
-        let info = vk::DescriptorSetLayoutCreateInfo::default().bindings(&desc_layout_bindings);
-
-        let descriptor_set_layout =
-            unsafe { instance.device.create_descriptor_set_layout(&info, None) }.unwrap();
-
-        let pipeline_layout =
-            instance.create_pipeline_layout(&[push_constant_range], &[descriptor_set_layout]);
-
-        let mut mesh_renderer = ModelRenderer3D::default();
-
-        // // -------------- DEMO -------------------
-        let mut world = World::new();
 
-        let model = FileAsset::new("assets/models/test/Sponza-GLTF/Sponza.gltf");
+    assets: AssetServer,
+    vertex_shader: Handle<Shader>,
+    fragment_shader: Handle<Shader>,
+    /// The entity of every uploaded model, so a reloaded model replaces the previous one
+    models: HashMap<HandleId, Entity>,
+    // -------------- DEMO -------------------
+    world: World,
+    /// Keeps the model loaded, dropping the handle frees it
+    model: Handle<ModelData>,
+}
 
-        // Sponza-GLTF/Sponza.gltf
-        // bistro_outside.glb
+impl Renderer3D {
+    /// Adds an uploaded model to the scene, or replaces the previous version if it was
+    /// reloaded.
+    fn add_model(&mut self, instance: &VulkanInstance, id: HandleId, mut model: Model3D) {
+        let mut material_ubos = self.create_material_descriptor_sets(instance, &mut model);
+        if let Some(&entity) = self.models.get(&id) {
+            // The previous version may still be in use, the new one was never submitted
+            if let Err(err) = unsafe { instance.device.device_wait_idle() } {
+                log::error!("Failed to replace reloaded model: {}", err);
+                model.destroy(&instance.device);
+                material_ubos
+                    .iter_mut()
+                    .for_each(|ubo| ubo.destroy(&instance.device));
+                return;
+            }
+            if let Some(mesh) = self.mesh_renderer.get_mut(entity) {
+                mem::replace(&mut mesh.model, model).destroy(&instance.device);
+            }
+            for mut ubo in self
+                .material_ubos
+                .insert(entity, material_ubos)
+                .unwrap_or_default()
+            {
+                ubo.destroy(&instance.device);
+            }
+            log::info!("Replaced reloaded model");
+        } else {
+            let entity = self.world.create_entity();
+            self.mesh_renderer.insert(entity, Entity3D::new(model));
+            self.material_ubos.insert(entity, material_ubos);
+            self.models.insert(id, entity);
+        }
+    }
 
+    /// Creates the descriptor sets of every material and returns their uniform buffers.
+    fn create_material_descriptor_sets(
+        &self,
+        instance: &VulkanInstance,
+        model: &mut Model3D,
+    ) -> Vec<VulkanBuffer> {
         let mut material_ubos = vec![];
-        let light_ubos = vec![];
-
-        let vertex_shader = FileAsset::new("assets/shaders/app/3D/shader.vert.spv");
-        let fragment_shader = FileAsset::new("assets/shaders/app/3D/shader.frag.spv");
-
-        let mut mesh = Entity3D::new(
-            vent_assets::Model3D::load(
-                instance,
-                vertex_shader.root_path(),
-                fragment_shader.root_path(),
-                pipeline_layout,
-                model.root_path(),
-            )
-            .block_on(),
-        );
-        for material in mesh.model.materials.iter_mut() {
+        for material in model.materials.iter_mut() {
             let descriptor_sets = VulkanInstance::allocate_descriptor_sets(
                 &instance.device,
-                mesh.model.descriptor_pool,
-                descriptor_set_layout,
+                model.descriptor_pool,
+                self.descriptor_set_layout,
                 instance.swapchain_images.len(),
             );
 
@@ -241,24 +204,196 @@ impl Renderer for Renderer3D {
             material.descriptor_set = Some(descriptor_sets);
         }
 
-        mesh_renderer.insert(world.create_entity(), mesh);
+        material_ubos
+    }
+
+    /// Rebuilds everything which was created from the reloaded `shaders` and `images` or from
+    /// one of the `changed` files, which are relative to the asset root.
+    fn reload_changed(
+        &mut self,
+        instance: &VulkanInstance,
+        shaders: &[HandleId],
+        images: &[HandleId],
+        changed: &[PathBuf],
+    ) {
+        if self.skybox_renderer.needs_reload(shaders, images) {
+            // Nothing may be in use while it is replaced
+            if let Err(err) = unsafe { instance.device.device_wait_idle() } {
+                log::error!("Failed to reload changed assets: {}", err);
+                return;
+            }
+            self.skybox_renderer
+                .reload(instance, &self.assets, shaders, images);
+        }
+
+        // Model pipelines and textures are created while uploading, so the model is loaded
+        // and uploaded again when its shaders or the files next to it change. The model file
+        // itself is reloaded by the asset server. If the upload fails the previous version is
+        // kept.
+        let Some(model_path) = self.assets.path(&self.model).map(Path::to_path_buf) else {
+            return;
+        };
+        let shader_changed = shaders.contains(&self.vertex_shader.id())
+            || shaders.contains(&self.fragment_shader.id());
+        let directory = model_path.parent().unwrap_or(Path::new(""));
+        let dependency_changed = changed
+            .iter()
+            .any(|path| *path != model_path && path.starts_with(directory));
+        if shader_changed || dependency_changed {
+            self.assets.reload(&model_path);
+        }
+    }
+}
+
+impl Renderer for Renderer3D {
+    fn init(
+        instance: &mut VulkanInstance,
+        _camera: &mut dyn Camera,
+        mut assets: AssetServer,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        //  let _camera: &Camera3D = camera.downcast_ref().unwrap();
+
+        // All paths are relative to the asset root, see `vent_assets::AssetIo`
+        let skybox_renderer = SkyBoxRenderer::new(
+            instance,
+            &mut assets,
+            SkyBoxImages {
+                right: "textures/skybox/right.jpg".into(),
+                left: "textures/skybox/left.jpg".into(),
+                top: "textures/skybox/top.jpg".into(),
+                bottom: "textures/skybox/bottom.jpg".into(),
+                front: "textures/skybox/front.jpg".into(),
+                back: "textures/skybox/back.jpg".into(),
+            },
+        );
+
+        let push_constant_range = vk::PushConstantRange::default()
+            .size(size_of::<Camera3DData>() as u32)
+            .stage_flags(vk::ShaderStageFlags::VERTEX);
+
+        let desc_layout_bindings = [
+            // Fragment
+            vk::DescriptorSetLayoutBinding {
+                binding: 0,
+                descriptor_type: vk::DescriptorType::COMBINED_IMAGE_SAMPLER,
+                descriptor_count: 1,
+                stage_flags: vk::ShaderStageFlags::FRAGMENT,
+                ..Default::default()
+            },
+            vk::DescriptorSetLayoutBinding {
+                binding: 1,
+                descriptor_type: vk::DescriptorType::UNIFORM_BUFFER,
+                descriptor_count: 1,
+                stage_flags: vk::ShaderStageFlags::FRAGMENT,
+                ..Default::default()
+            },
+            // vk::DescriptorSetLayoutBinding {
+            //     binding: 2,
+            //     descriptor_type: vk::DescriptorType::UNIFORM_BUFFER,
+            //     descriptor_count: 1,
+            //     stage_flags: vk::ShaderStageFlags::FRAGMENT,
+            //     ..Default::default()
+            // },
+        ];
+        let info = vk::DescriptorSetLayoutCreateInfo::default().bindings(&desc_layout_bindings);
+
+        let descriptor_set_layout =
+            unsafe { instance.device.create_descriptor_set_layout(&info, None) }.unwrap();
+
+        let pipeline_layout =
+            instance.create_pipeline_layout(&[push_constant_range], &[descriptor_set_layout]);
+
+        // -------------- DEMO -------------------
+        // The model is parsed on the asset workers and uploaded in `update` once it is ready
+        if let Err(err) = assets.watch_for_changes() {
+            log::warn!("Assets are not reloaded on change: {}", err);
+        }
+        let vertex_shader: Handle<Shader> = assets.load("shaders/app/3D/shader.vert.spv");
+        let fragment_shader: Handle<Shader> = assets.load("shaders/app/3D/shader.frag.spv");
+        // Uploading the model needs the shaders
+        assets.wait_for(&vertex_shader);
+        assets.wait_for(&fragment_shader);
+        // Everything loaded so far is in use already, only later reloads have to be handled
+        assets.drain_loaded::<Shader>();
+        assets.drain_loaded::<Image>();
+        let model = assets.load::<ModelData, _>("models/test/Sponza-GLTF/Sponza.gltf");
+
+        // Sponza-GLTF/Sponza.gltf
+        // bistro_outside.glb
 
         let tmp_light_mesh = create_simple_cube(instance);
         //  let light_renderer = LightRenderer::new(instance);
 
         Self {
-            mesh_renderer,
+            mesh_renderer: ModelRenderer3D::default(),
             skybox_renderer,
             descriptor_set_layout,
             //   light_renderer,
             tmp_light_mesh,
             pipeline_layout,
-            material_ubos,
-            light_ubos,
+            material_ubos: HashMap::new(),
+            light_ubos: vec![],
+            assets,
+            vertex_shader,
+            fragment_shader,
+            models: HashMap::new(),
+            world: World::new(),
+            model,
             // pipeline_wire,
         }
     }
 
+    fn update(&mut self, instance: &mut VulkanInstance) {
+        self.assets.update();
+        let shaders: Vec<HandleId> = self
+            .assets
+            .drain_loaded::<Shader>()
+            .iter()
+            .map(Handle::id)
+            .collect();
+        let images: Vec<HandleId> = self
+            .assets
+            .drain_loaded::<Image>()
+            .iter()
+            .map(Handle::id)
+            .collect();
+        let changed = self.assets.drain_changed();
+        self.reload_changed(instance, &shaders, &images, &changed);
+        for handle in self.assets.drain_loaded::<ModelData>() {
+            let Some(data) = self.assets.take(&handle) else {
+                continue;
+            };
+            // Both shaders were loaded in `init`, reloads keep the previous version on failure
+            let (Some(vertex_shader), Some(fragment_shader)) = (
+                self.assets.get(&self.vertex_shader),
+                self.assets.get(&self.fragment_shader),
+            ) else {
+                log::error!("Failed to upload model, its shaders are not loaded");
+                continue;
+            };
+            match Model3D::upload(
+                instance,
+                &vertex_shader.code,
+                &fragment_shader.code,
+                self.pipeline_layout,
+                data,
+            ) {
+                Ok(model) => self.add_model(instance, handle.id(), model),
+                Err(err) => log::error!(
+                    "Failed to upload model {}, keeping the previous version: {}",
+                    self.assets
+                        .path(&handle)
+                        .unwrap_or(Path::new("<added>"))
+                        .display(),
+                    err
+                ),
+            }
+        }
+    }
+
     fn resize(
         &mut self,
         _instance: &mut VulkanInstance,
@@ -325,7 +460,8 @@ impl Renderer for Renderer3D {
         };
         //self.light_renderer.destroy(&instance.device);
         self.material_ubos
-            .drain(..)
+            .drain()
+            .flat_map(|(_, ubos)| ubos)
             .for_each(|mut ubo| ubo.destroy(&instance.device));
         self.light_ubos
             .drain(..)
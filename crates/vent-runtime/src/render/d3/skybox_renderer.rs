@@ -1,8 +1,8 @@
-use std::mem::size_of;
+use std::mem::{self, size_of};
 
 use ash::vk;
 use image::GenericImageView;
-use vent_assets::io::file::FileAsset;
+use vent_assets::{image::Image, shader::Shader, AssetServer, Handle, HandleId};
 use vent_math::scalar::mat4::Mat4;
 use vent_rendering::{
     any_as_u8_slice,
@@ -23,6 +23,11 @@ pub struct SkyBoxRenderer {
     push_constants: SkyBoxUBO,
     descriptor_sets: Vec<vk::DescriptorSet>,
     cube: Mesh3D,
+
+    vertex_shader: Handle<Shader>,
+    fragment_shader: Handle<Shader>,
+    /// Right, left, top, bottom, front and back, the order of the cubemap layers
+    images: [Handle<Image>; 6],
 }
 
 #[repr(C)]
@@ -32,10 +37,26 @@ pub struct SkyBoxUBO {
 }
 
 impl SkyBoxRenderer {
-    pub fn new(instance: &VulkanInstance, images: SkyBoxImages) -> Self {
+    /// Creates the skybox from the asset paths in `images`, blocks until its shaders and
+    /// images are loaded.
+    pub fn new(instance: &VulkanInstance, assets: &mut AssetServer, images: SkyBoxImages) -> Self {
         log::debug!("Creating skybox");
-        let vertex_shader = FileAsset::new("assets/shaders/app/3D/skybox.vert.spv");
-        let fragment_shader = FileAsset::new("assets/shaders/app/3D/skybox.frag.spv");
+        let vertex_shader: Handle<Shader> = assets.load("shaders/app/3D/skybox.vert.spv");
+        let fragment_shader: Handle<Shader> = assets.load("shaders/app/3D/skybox.frag.spv");
+        let images = [
+            images.right,
+            images.left,
+            images.top,
+            images.bottom,
+            images.front,
+            images.back,
+        ]
+        .map(|path| assets.load::<Image, _>(path));
+        assets.wait_for(&vertex_shader);
+        assets.wait_for(&fragment_shader);
+        for image in &images {
+            assets.wait_for(image);
+        }
 
         let desc_layout_bindings = [vk::DescriptorSetLayoutBinding {
             binding: 0,
@@ -49,10 +70,16 @@ impl SkyBoxRenderer {
             .size(size_of::<SkyBoxUBO>() as u32)
             .stage_flags(vk::ShaderStageFlags::VERTEX);
 
-        let pipeline = VulkanPipeline::create_simple_pipeline(
+        let pipeline = VulkanPipeline::create_simple_pipeline_from_code(
             instance,
-            vertex_shader.root_path(),
-            fragment_shader.root_path(),
+            &assets
+                .get(&vertex_shader)
+                .expect("Failed to load skybox shaders")
+                .code,
+            &assets
+                .get(&fragment_shader)
+                .expect("Failed to load skybox shaders")
+                .code,
             &[VertexPos3D::binding_description()],
             &VertexPos3D::input_descriptions(),
             instance.surface_resolution,
@@ -75,32 +102,55 @@ impl SkyBoxRenderer {
             instance.swapchain_images.len(),
         );
 
-        let images = [
-            image::open(images.right).unwrap(),
-            image::open(images.left).unwrap(),
-            image::open(images.top).unwrap(),
-            image::open(images.bottom).unwrap(),
-            image::open(images.front).unwrap(),
-            image::open(images.back).unwrap(),
-        ];
+        let image = Self::load_cubemap(instance, assets, &images).expect("Failed to load skybox");
+        Self::write_descriptor_sets(&instance.device, &descriptor_sets, &image);
+
+        Self {
+            pipeline,
+            cube,
+            image,
+            push_constants,
+            descriptor_pool,
+            descriptor_sets,
+            vertex_shader,
+            fragment_shader,
+            images,
+        }
+    }
+
+    /// Returns `None` if one of the images is not loaded.
+    fn load_cubemap(
+        instance: &VulkanInstance,
+        assets: &AssetServer,
+        handles: &[Handle<Image>; 6],
+    ) -> Option<VulkanImage> {
+        let mut images = Vec::with_capacity(handles.len());
+        for handle in handles {
+            images.push(assets.get(handle)?.image.clone());
+        }
+        let images: [image::DynamicImage; 6] = images.try_into().ok()?;
         let image_size = images[0].dimensions();
 
-        let image = VulkanImage::load_cubemap(
+        Some(VulkanImage::load_cubemap(
             instance,
             images,
             vk::Extent2D {
                 width: image_size.0,
                 height: image_size.1,
             },
-        );
+        ))
+    }
 
+    fn write_descriptor_sets(
+        device: &ash::Device,
+        descriptor_sets: &[vk::DescriptorSet],
+        image: &VulkanImage,
+    ) {
         for &descriptor_set in descriptor_sets.iter() {
-            let diffuse_texture = &image;
-
             let image_info = vk::DescriptorImageInfo::default()
                 .image_layout(vk::ImageLayout::SHADER_READ_ONLY_OPTIMAL)
-                .image_view(diffuse_texture.image_view)
-                .sampler(diffuse_texture.sampler);
+                .image_view(image.image_view)
+                .sampler(image.sampler);
 
             let desc_sets = [vk::WriteDescriptorSet {
                 dst_set: descriptor_set,
@@ -112,17 +162,63 @@ impl SkyBoxRenderer {
             }];
 
             unsafe {
-                instance.device.update_descriptor_sets(&desc_sets, &[]);
+                device.update_descriptor_sets(&desc_sets, &[]);
             }
         }
+    }
 
-        Self {
-            pipeline,
-            cube,
-            image,
-            push_constants,
-            descriptor_pool,
-            descriptor_sets,
+    /// Returns `true` if one of the shaders or images of the skybox is in `shaders` or
+    /// `images`, so [`SkyBoxRenderer::reload`] has to rebuild something.
+    pub fn needs_reload(&self, shaders: &[HandleId], images: &[HandleId]) -> bool {
+        self.shaders_reloaded(shaders) || self.images_reloaded(images)
+    }
+
+    fn shaders_reloaded(&self, shaders: &[HandleId]) -> bool {
+        shaders.contains(&self.vertex_shader.id()) || shaders.contains(&self.fragment_shader.id())
+    }
+
+    fn images_reloaded(&self, images: &[HandleId]) -> bool {
+        self.images.iter().any(|image| images.contains(&image.id()))
+    }
+
+    /// Rebuilds the pipeline or the cubemap if one of their assets is in `shaders` or
+    /// `images`, the assets reloaded since the last call. Keeps the previous version if that
+    /// fails. The GPU must be idle.
+    pub fn reload(
+        &mut self,
+        instance: &VulkanInstance,
+        assets: &AssetServer,
+        shaders: &[HandleId],
+        images: &[HandleId],
+    ) {
+        if self.shaders_reloaded(shaders) {
+            let vertex_shader = assets.get(&self.vertex_shader).unwrap();
+            let fragment_shader = assets.get(&self.fragment_shader).unwrap();
+            match self.pipeline.reload_shaders(
+                instance,
+                &vertex_shader.code,
+                &fragment_shader.code,
+                &[VertexPos3D::binding_description()],
+                &VertexPos3D::input_descriptions(),
+                instance.surface_resolution,
+            ) {
+                Ok(()) => log::info!("Reloaded skybox shaders"),
+                Err(err) => log::error!(
+                    "Failed to reload skybox shaders, keeping the previous version: {}",
+                    err
+                ),
+            }
+        }
+
+        if self.images_reloaded(images) {
+            match Self::load_cubemap(instance, assets, &self.images) {
+                Some(image) => {
+                    Self::write_descriptor_sets(&instance.device, &self.descriptor_sets, &image);
+                    mem::replace(&mut self.image, image).destroy(&instance.device);
+                    log::info!("Reloaded skybox images");
+                }
+                None => log::error!("Failed to reload skybox images, keeping the previous version"),
+            }
         }
     }
 
@@ -1,10 +1,14 @@
 use ash::vk;
+use vent_ecs::resource::Resource;
 
+#[derive(Clone, Copy)]
 pub struct RenderData {
     pub fps: u32,
     pub frame_time: f32,
 }
 
+impl Resource for RenderData {}
+
 impl Default for RenderData {
     fn default() -> Self {
         Self {
@@ -2,12 +2,13 @@ use std::time::{Duration, Instant};
 
 use ash::vk::{self};
 use serde::{Deserialize, Serialize};
+use vent_assets::AssetServer;
 use vent_rendering::instance::VulkanInstance;
 use vent_ui::renderer::GuiRenderer;
 
 use crate::project::VentApplicationProject;
 
-use self::camera::{from_dimension, Camera};
+use self::camera::Camera;
 use self::d2::Renderer2D;
 use self::d3::Renderer3D;
 use self::gui::debug_gui::RenderData;
@@ -24,11 +25,14 @@ mod d3;
 pub(crate) struct DefaultRuntimeRenderer {
     instance: VulkanInstance,
     runtime_renderer: RawRuntimeRenderer,
-    pub camera: Box<dyn Camera>,
 }
 
 impl DefaultRuntimeRenderer {
-    pub(crate) fn new(settings: &VentApplicationProject, window: &vent_window::Window) -> Self {
+    pub(crate) fn new(
+        settings: &VentApplicationProject,
+        window: &vent_window::Window,
+        camera: &mut dyn Camera,
+    ) -> Self {
         let mut instance = VulkanInstance::new(
             &settings.name,
             settings.version.parse(),
@@ -36,13 +40,19 @@ impl DefaultRuntimeRenderer {
             window,
         );
         let dimension = &settings.render_settings.dimension;
-        let window_size = window.size();
-        let mut camera = from_dimension(window_size.0 as f32 / window_size.1 as f32, dimension);
-        let runtime_renderer = RawRuntimeRenderer::new(dimension, &mut instance, camera.as_mut());
+        let asset_io = settings
+            .asset_settings
+            .create()
+            .expect("Failed to open assets");
+        let runtime_renderer = RawRuntimeRenderer::new(
+            dimension,
+            AssetServer::with_io(asset_io),
+            &mut instance,
+            camera,
+        );
         Self {
             instance,
             runtime_renderer,
-            camera,
         }
     }
 
@@ -50,22 +60,24 @@ impl DefaultRuntimeRenderer {
         self.runtime_renderer.progress_event(event);
     }
 
-    pub(crate) fn render(&mut self) -> f32 {
-        self.runtime_renderer
-            .render(&mut self.instance, self.camera.as_mut())
+    pub(crate) fn render(&mut self, camera: &mut dyn Camera) -> f32 {
+        self.runtime_renderer.render(&mut self.instance, camera)
+    }
+
+    pub(crate) fn render_data(&self) -> RenderData {
+        self.runtime_renderer.current_data
     }
 
-    pub(crate) fn resize(&mut self, new_size: (u32, u32)) {
+    pub(crate) fn resize(&mut self, new_size: (u32, u32), camera: &mut dyn Camera) {
         let old_size = self.instance.surface_resolution;
         if old_size.width == new_size.0 && old_size.height == new_size.1 {
             return;
         }
 
         log::debug!("Resizing to {:?} ", new_size);
-        self.camera
-            .recreate_projection(new_size.0 as f32 / new_size.1 as f32);
+        camera.recreate_projection(new_size.0 as f32 / new_size.1 as f32);
         self.runtime_renderer
-            .resize(&mut self.instance, new_size, self.camera.as_mut());
+            .resize(&mut self.instance, new_size, camera);
     }
 }
 
@@ -82,7 +94,7 @@ pub enum Dimension {
 }
 
 pub trait Renderer {
-    fn init(instance: &mut VulkanInstance, camera: &mut dyn Camera) -> Self
+    fn init(instance: &mut VulkanInstance, camera: &mut dyn Camera, assets: AssetServer) -> Self
     where
         Self: Sized;
 
@@ -93,6 +105,10 @@ pub trait Renderer {
         camera: &mut dyn Camera,
     );
 
+    /// Called once per frame before recording, finishes work which has to happen on the
+    /// render thread, e.g. uploading assets which were loaded in the background.
+    fn update(&mut self, _instance: &mut VulkanInstance) {}
+
     fn render(
         &mut self,
         instance: &VulkanInstance,
@@ -119,13 +135,14 @@ pub struct RawRuntimeRenderer {
 impl RawRuntimeRenderer {
     pub fn new(
         dimension: &Dimension,
+        assets: AssetServer,
         instance: &mut VulkanInstance,
         camera: &mut dyn Camera,
     ) -> Self {
         let gui_renderer = GuiRenderer::new(instance);
         let multi_renderer: Box<dyn Renderer> = match dimension {
-            Dimension::D2 => Box::new(Renderer2D::init(instance, camera)),
-            Dimension::D3 => Box::new(Renderer3D::init(instance, camera)),
+            Dimension::D2 => Box::new(Renderer2D::init(instance, camera, assets)),
+            Dimension::D3 => Box::new(Renderer3D::init(instance, camera, assets)),
         };
         //     // TODO
         //     .add_gui(Box::new(DebugGUI::new(unsafe {
@@ -147,6 +164,8 @@ impl RawRuntimeRenderer {
     pub fn render(&mut self, instance: &mut VulkanInstance, camera: &mut dyn Camera) -> f32 {
         let frame_start = Instant::now();
 
+        self.multi_renderer.update(instance);
+
         let image = instance.next_image();
 
         match image {
@@ -1,3 +1,4 @@
+use vent_ecs::resource::Resource;
 use vent_window::keyboard::{Key, KeyState};
 
 #[derive(Default)]
@@ -5,6 +6,8 @@ pub struct InputHandler {
     pressed_keys: Vec<Key>,
 }
 
+impl Resource for InputHandler {}
+
 impl InputHandler {
     pub fn set_key(&mut self, key: Key, state: KeyState) {
         if state == KeyState::Pressed {
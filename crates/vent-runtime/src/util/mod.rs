@@ -1,3 +1,4 @@
 pub mod crash;
 pub mod input_handler;
+pub mod time;
 pub mod version;
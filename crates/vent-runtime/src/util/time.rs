@@ -0,0 +1,7 @@
+use vent_ecs::resource::Resource;
+
+/// Time the last frame took to render in milliseconds, stored as a world resource.
+#[derive(Clone, Copy, Default)]
+pub struct DeltaTime(pub f32);
+
+impl Resource for DeltaTime {}